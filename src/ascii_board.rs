@@ -1,31 +1,232 @@
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+
 use super::{Board, Player};
 
-const ROW_REPARATOR: &str = "  +---+---+---+---+---+---+---+---+\n";
-const LETTERS: &str = "    A   B   C   D   E   F   G   H\n";
-
-/// Builds an ascii representation of a board.
-pub fn board_to_ascii(board: &Board) -> String {
-    let mut ascii = String::new();
-    ascii.push_str(LETTERS);
-    for y in 0..8 {
-        ascii.push_str(ROW_REPARATOR);
-        ascii.push_str(format!("{} ", y + 1).as_str());
-        for x in 0..8 {
+/// Characters used to draw a board in a terminal, see `board_to_themed_ascii`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BoardTheme {
+    pub name: &'static str,
+    pub black: char,
+    pub white: char,
+    pub empty: char,
+    /// Marks the empty squares given as hints (ie the legal moves).
+    pub hint: char,
+    /// Lines drawn around the squares, None for a character per square.
+    pub grid: Option<BoardGrid>,
+}
+
+/// Lines drawn around the squares of a board.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BoardGrid {
+    pub horizontal: char,
+    pub vertical: char,
+    /// Junctions of the top, inner and bottom lines : on the left, between
+    /// two squares, and on the right.
+    pub junctions: [[char; 3]; 3],
+}
+
+impl BoardTheme {
+    /// The grid of `+`, `-` and `|`, with `X` for Black and `O` for White.
+    pub const CLASSIC: BoardTheme = BoardTheme {
+        name: "classic",
+        black: 'X',
+        white: 'O',
+        empty: ' ',
+        hint: '*',
+        grid: Some(BoardGrid {
+            horizontal: '-',
+            vertical: '|',
+            junctions: [['+'; 3]; 3],
+        }),
+    };
+
+    /// A character per square, without grid, for small terminals.
+    pub const MINIMAL: BoardTheme = BoardTheme {
+        name: "minimal",
+        black: 'X',
+        white: 'O',
+        empty: '.',
+        hint: '*',
+        grid: None,
+    };
+
+    /// Box-drawing grid and discs, for terminals displaying unicode.
+    pub const UNICODE: BoardTheme = BoardTheme {
+        name: "unicode",
+        black: '●',
+        white: '○',
+        empty: ' ',
+        hint: '·',
+        grid: Some(BoardGrid {
+            horizontal: '─',
+            vertical: '│',
+            junctions: [['┌', '┬', '┐'], ['├', '┼', '┤'], ['└', '┴', '┘']],
+        }),
+    };
+
+    /// Sides told apart by the shape and the brightness of their discs (a
+    /// filled square for Black, a hollow circle for White) rather than by
+    /// colors, for color-blind players.
+    pub const COLORBLIND: BoardTheme = BoardTheme {
+        name: "colorblind",
+        black: '■',
+        white: '○',
+        empty: ' ',
+        hint: '+',
+        ..Self::UNICODE
+    };
+
+    /// All the themes, the classic one first.
+    pub const ALL: [BoardTheme; 4] = [
+        Self::CLASSIC,
+        Self::MINIMAL,
+        Self::UNICODE,
+        Self::COLORBLIND,
+    ];
+
+    /// Returns the theme of the given name, ignoring case.
+    pub fn by_name(name: &str) -> Option<BoardTheme> {
+        Self::ALL
+            .iter()
+            .find(|theme| theme.name.eq_ignore_ascii_case(name))
+            .copied()
+    }
+
+    fn cell(&self, piece: Option<Player>, hinted: bool) -> char {
+        match piece {
+            Some(Player::Black) => self.black,
+            Some(Player::White) => self.white,
+            None if hinted => self.hint,
+            None => self.empty,
+        }
+    }
+}
+
+impl Default for BoardTheme {
+    fn default() -> Self {
+        Self::CLASSIC
+    }
+}
+
+impl BoardGrid {
+    /// Builds the horizontal line at the given index : 0 above the board, 1
+    /// between two rows, 2 below. The line is shifted by the given margin,
+    /// the width of the rows numbers.
+    fn line(&self, index: usize, size: u8, margin: usize) -> String {
+        let [left, middle, right] = self.junctions[index];
+        let mut line = " ".repeat(margin);
+        line.push(left);
+        for x in 0..size {
+            if x > 0 {
+                line.push(middle);
+            }
+            (0..3).for_each(|_| line.push(self.horizontal));
+        }
+        line.push(right);
+        line.push('\n');
+        line
+    }
+}
+
+/// Builds an ascii representation of a board, the columns (A to H on a
+/// standard board) being labeled above and below it, and the rows (1 to 8)
+/// on both sides. The boards of any size are drawn, the rows numbers being
+/// right aligned from 10 rows.
+pub fn board_to_ascii<const N: usize>(board: &Board<N>) -> String {
+    board_to_ascii_with_hints(board, &[])
+}
+
+/// Builds an ascii representation of a board, labeled as with
+/// `board_to_ascii`, the given empty squares (ie the legal moves of the
+/// player) being marked with a `*`.
+pub fn board_to_ascii_with_hints<const N: usize>(board: &Board<N>, hints: &[(u8, u8)]) -> String {
+    board_to_themed_ascii(board, hints, &BoardTheme::CLASSIC)
+}
+
+/// Builds a representation of a board with the characters of the given
+/// theme, labeled as with `board_to_ascii`, the given empty squares being
+/// marked.
+pub fn board_to_themed_ascii<const N: usize>(
+    board: &Board<N>,
+    hints: &[(u8, u8)],
+    theme: &BoardTheme,
+) -> String {
+    let size = board.size();
+    let number_width = size.to_string().len();
+    // the rows numbers and the space following them.
+    let margin = number_width + 1;
+    let letters: Vec<String> = (0..size)
+        .map(|x| ((b'A' + x) as char).to_string())
+        .collect();
+    let letters = match theme.grid {
+        Some(_) => format!("{}  {}\n", " ".repeat(margin), letters.join("   ")),
+        None => format!("{}{}\n", " ".repeat(margin), letters.join(" ")),
+    };
+    let mut ascii = letters.clone();
+    for y in 0..size {
+        if let Some(grid) = &theme.grid {
+            ascii.push_str(&grid.line(if y == 0 { 0 } else { 1 }, size, margin));
+        }
+        ascii.push_str(format!("{:>width$}", y + 1, width = number_width).as_str());
+        for x in 0..size {
             let piece = board.get_piece(x, y).unwrap();
-            ascii.push_str(cell_to_ascii(piece));
+            let cell = theme.cell(piece, piece.is_none() && hints.contains(&(x, y)));
+            match &theme.grid {
+                Some(grid) => ascii.push_str(format!(" {} {}", grid.vertical, cell).as_str()),
+                None => ascii.push_str(format!(" {}", cell).as_str()),
+            }
+        }
+        if let Some(grid) = &theme.grid {
+            ascii.push_str(format!(" {}", grid.vertical).as_str());
         }
-        ascii.push_str("|\n")
+        ascii.push_str(format!(" {}\n", y + 1).as_str());
+    }
+    if let Some(grid) = &theme.grid {
+        ascii.push_str(&grid.line(2, size, margin));
     }
-    ascii.push_str(ROW_REPARATOR);
+    ascii.push_str(&letters);
 
     ascii
 }
 
-fn cell_to_ascii(piece: Option<Player>) -> &'static str {
-    match piece {
-        None => "|   ",
-        Some(Player::Black) => "| X ",
-        Some(Player::White) => "| O ",
+/// Parses a board from its ascii representation, as built by
+/// `board_to_ascii` (the hints being read as empty squares). Only the rows,
+/// starting with their number, are read, the number after the last border
+/// being optional.
+pub fn board_from_ascii(ascii: &str) -> Result<Board, String> {
+    let mut board = Board::new();
+    let mut rows_read = [false; 8];
+    for line in ascii.lines() {
+        let line = line.trim();
+        let y = match line.chars().next().and_then(|c| c.to_digit(10)) {
+            Some(row @ 1..=8) => row as u8 - 1,
+            _ => continue,
+        };
+        let cells: Vec<&str> = line.split('|').skip(1).map(str::trim).collect();
+        let labels = ["", &(y + 1).to_string()];
+        if cells.len() != 9 || !labels.contains(&cells[8]) {
+            return Err(format!("invalid row : {}", line));
+        }
+        for (x, cell) in cells[..8].iter().enumerate() {
+            let piece = match *cell {
+                "" | "*" => None,
+                "X" | "x" => Some(Player::Black),
+                "O" | "o" => Some(Player::White),
+                _ => return Err(format!("invalid cell in row {} : {}", y + 1, cell)),
+            };
+            board
+                .set_piece(x as u8, y, piece)
+                .map_err(|error| error.to_string())?;
+        }
+        rows_read[y as usize] = true;
+    }
+    match rows_read.iter().position(|read| !read) {
+        Some(y) => Err(format!("missing row {}", y + 1)),
+        None => Ok(board),
     }
 }
 
@@ -43,26 +244,120 @@ mod tests {
         // before the comparison.
         let expected = "    A   B   C   D   E   F   G   H\n\
                              . +---+---+---+---+---+---+---+---+\n\
-                             1 |   |   |   |   |   |   |   |   |\n\
+                             1 |   |   |   |   |   |   |   |   | 1\n\
+                             . +---+---+---+---+---+---+---+---+\n\
+                             2 |   |   |   |   |   |   |   |   | 2\n\
                              . +---+---+---+---+---+---+---+---+\n\
-                             2 |   |   |   |   |   |   |   |   |\n\
+                             3 |   |   |   |   |   |   |   |   | 3\n\
                              . +---+---+---+---+---+---+---+---+\n\
-                             3 |   |   |   |   |   |   |   |   |\n\
+                             4 |   |   |   | O | X |   |   |   | 4\n\
                              . +---+---+---+---+---+---+---+---+\n\
-                             4 |   |   |   | O | X |   |   |   |\n\
+                             5 |   |   |   | X | O |   |   |   | 5\n\
                              . +---+---+---+---+---+---+---+---+\n\
-                             5 |   |   |   | X | O |   |   |   |\n\
+                             6 |   |   |   |   |   |   |   |   | 6\n\
                              . +---+---+---+---+---+---+---+---+\n\
-                             6 |   |   |   |   |   |   |   |   |\n\
+                             7 |   |   |   |   |   |   |   |   | 7\n\
                              . +---+---+---+---+---+---+---+---+\n\
-                             7 |   |   |   |   |   |   |   |   |\n\
+                             8 |   |   |   |   |   |   |   |   | 8\n\
                              . +---+---+---+---+---+---+---+---+\n\
-                             8 |   |   |   |   |   |   |   |   |\n\
-                             . +---+---+---+---+---+---+---+---+\n";
+                             ....A   B   C   D   E   F   G   H\n";
 
         let expected = expected.replace(".", " ");
         let board = Board::new_start();
         let ascii = board_to_ascii(&board);
         assert_eq!(ascii, expected);
     }
+
+    #[test]
+    fn board_to_ascii_with_hints_marks_the_given_squares() {
+        let board = Board::new_start();
+        let ascii = board_to_ascii_with_hints(&board, &board.legal_moves(Player::Black));
+        assert_eq!(ascii.matches('*').count(), 4);
+        assert!(ascii.contains("3 |   |   |   | * |   |   |   |   | 3\n"));
+        assert!(ascii.contains("4 |   |   | * | O | X |   |   |   | 4\n"));
+    }
+
+    #[test]
+    fn minimal_theme_uses_a_character_per_square() {
+        let board = Board::new_start();
+        let hints = board.legal_moves(Player::Black);
+        let ascii = board_to_themed_ascii(&board, &hints, &BoardTheme::MINIMAL);
+        assert_eq!(ascii.lines().count(), 10);
+        assert_eq!(ascii.lines().next(), Some("  A B C D E F G H"));
+        assert_eq!(ascii.lines().last(), Some("  A B C D E F G H"));
+        assert!(ascii.contains("\n3 . . . * . . . . 3\n"));
+        assert!(ascii.contains("\n4 . . * O X . . . 4\n"));
+    }
+
+    #[test]
+    fn unicode_theme_draws_boxes_and_discs() {
+        let board = Board::new_start();
+        let hints = board.legal_moves(Player::Black);
+        let ascii = board_to_themed_ascii(&board, &hints, &BoardTheme::UNICODE);
+        assert!(ascii.contains("\n  ┌───┬───┬───┬───┬───┬───┬───┬───┐\n"));
+        assert!(ascii.contains("\n4 │   │   │ · │ ○ │ ● │   │   │   │ 4\n"));
+        assert!(ascii.contains("\n  └───┴───┴───┴───┴───┴───┴───┴───┘\n"));
+    }
+
+    #[test]
+    fn colorblind_theme_tells_the_sides_by_shape() {
+        let board = Board::new_start();
+        let ascii = board_to_themed_ascii(&board, &[(3, 2)], &BoardTheme::COLORBLIND);
+        assert!(ascii.contains("\n3 │   │   │   │ + │   │   │   │   │ 3\n"));
+        assert!(ascii.contains("\n4 │   │   │   │ ○ │ ■ │   │   │   │ 4\n"));
+    }
+
+    #[test]
+    fn boards_of_any_size_are_drawn() {
+        let board = Board::<6>::start();
+        let ascii = board_to_themed_ascii(&board, &[(2, 1)], &BoardTheme::MINIMAL);
+        assert_eq!(
+            ascii,
+            "  A B C D E F\n\
+             1 . . . . . . 1\n\
+             2 . . * . . . 2\n\
+             3 . . O X . . 3\n\
+             4 . . X O . . 4\n\
+             5 . . . . . . 5\n\
+             6 . . . . . . 6\n\
+             \x20 A B C D E F\n"
+        );
+
+        let ascii = board_to_ascii(&Board::<10>::start());
+        assert_eq!(ascii.lines().count(), 23);
+        assert_eq!(
+            ascii.lines().next(),
+            Some("     A   B   C   D   E   F   G   H   I   J")
+        );
+        assert!(ascii.contains("\n   +---+---+---+---+---+---+---+---+---+---+\n"));
+        assert!(ascii.contains("\n 5 |   |   |   |   | O | X |   |   |   |   | 5\n"));
+        assert!(ascii.contains("\n10 |   |   |   |   |   |   |   |   |   |   | 10\n"));
+    }
+
+    #[test]
+    fn themes_are_found_by_name() {
+        assert_eq!(BoardTheme::by_name("Unicode"), Some(BoardTheme::UNICODE));
+        assert_eq!(BoardTheme::by_name("classic"), Some(BoardTheme::default()));
+        assert_eq!(BoardTheme::by_name("fancy"), None);
+    }
+
+    #[test]
+    fn board_from_ascii_reads_the_ascii_representation() {
+        let board = Board::new_start()
+            .play(Player::Black, 5, 4)
+            .unwrap()
+            .unwrap();
+        let hints = board.legal_moves(Player::White);
+        let ascii = board_to_ascii_with_hints(&board, &hints);
+        assert_eq!(board_from_ascii(&ascii), Ok(board));
+        let unlabeled = ascii.replace("| 4\n", "|\n");
+        assert_eq!(board_from_ascii(&unlabeled), Ok(board));
+        let truncated: String = ascii.lines().take(10).collect::<Vec<_>>().join("\n");
+        assert_eq!(
+            board_from_ascii(&truncated),
+            Err("missing row 5".to_string())
+        );
+        let invalid = ascii.replacen("| X ", "| Y ", 1);
+        assert!(board_from_ascii(&invalid).is_err());
+    }
 }
@@ -0,0 +1,599 @@
+use std::{
+    fs,
+    io::{self, Read, Write},
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use rusthello::{
+    annotate_game, bench, board_from_ascii, board_to_ascii, perft_divide, AlphaBeta, AnnotatedMove,
+    Annotation, AnnotationThresholds, BenchResult, EndgameSolver, Game, GameError, Minimax, Move,
+    Pairing, Player, PlayerFactory, Position, TimeControl, Tournament, VirtualPlayer,
+};
+
+use crate::{config::Config, display_game_status, parse_color};
+
+/// A subcommand of the binary, given the arguments following its name.
+pub type Command = fn(&[String]) -> Result<(), CommandError>;
+
+/// Error of a subcommand, reported by `main` before exiting with an error
+/// code.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CommandError {
+    /// The command can't run, the usage being printed after the message.
+    Usage(String),
+    /// The command failed, what it already printed explaining why.
+    Failed(String),
+}
+
+impl From<String> for CommandError {
+    fn from(message: String) -> Self {
+        CommandError::Usage(message)
+    }
+}
+
+/// Returns the subcommand of the given name.
+pub fn find(name: &str) -> Option<Command> {
+    match name {
+        "bench" => Some(bench_command),
+        "perft" => Some(perft_command),
+        "tournament" => Some(tournament_command),
+        "analyze" => Some(analyze_command),
+        "solve" => Some(solve_command),
+        "play" => Some(play_command),
+        "replay" => Some(replay_command),
+        _ => None,
+    }
+}
+
+/// Depth of the searches of the bench, when not given.
+const DEFAULT_BENCH_DEPTH: u8 = 5;
+
+/// Depth of the engine evaluating the moves of an analysis, when not given.
+const DEFAULT_ANALYSIS_DEPTH: u8 = 4;
+
+/// Runs the search engines on the bench positions at a fixed depth (the
+/// first argument, if any), and prints the nodes searched, the time spent and
+/// the nodes per second, for each position and in total.
+pub fn bench_command(args: &[String]) -> Result<(), CommandError> {
+    let depth = match args {
+        [] => DEFAULT_BENCH_DEPTH,
+        [depth] => depth
+            .parse()
+            .ok()
+            .filter(|depth| (1..=10).contains(depth))
+            .ok_or_else(|| format!("invalid depth : {}", depth))?,
+        _ => return Err("bench only takes a depth".to_string().into()),
+    };
+    let engines: [Box<dyn VirtualPlayer>; 2] = [
+        Box::new(Minimax::new(depth)),
+        Box::new(AlphaBeta::new(depth)),
+    ];
+
+    println!(
+        "{:<10} {:>8} {:>12} {:>10} {:>12}",
+        "Engine", "Position", "Nodes", "Time (ms)", "NPS"
+    );
+    for engine in engines.iter() {
+        let results = bench(engine.as_ref());
+        for (index, result) in results.iter().enumerate() {
+            print_bench_line(&engine.name(), &(index + 1).to_string(), result);
+        }
+        let total = BenchResult {
+            nodes: results.iter().map(|result| result.nodes).sum(),
+            elapsed: results.iter().map(|result| result.elapsed).sum(),
+        };
+        print_bench_line(&engine.name(), "total", &total);
+    }
+
+    Ok(())
+}
+
+fn print_bench_line(engine: &str, position: &str, result: &BenchResult) {
+    println!(
+        "{:<10} {:>8} {:>12} {:>10} {:>12.0}",
+        engine,
+        position,
+        result.nodes,
+        result.elapsed.as_millis(),
+        result.nodes_per_second()
+    );
+}
+
+/// Counts the leaves of the game tree from a position (the start by
+/// default) up to a depth, and prints the count below each move (divide) and
+/// the total, to check the move generation against known counts. The
+/// arguments are the depth, then the position in its text format (see
+/// `Position`).
+pub fn perft_command(args: &[String]) -> Result<(), CommandError> {
+    let (depth, position) = match args {
+        [] => return Err("perft needs a depth".to_string().into()),
+        [depth, position @ ..] => (depth, position),
+    };
+    let depth: u8 = depth
+        .parse()
+        .map_err(|_| format!("invalid depth : {}", depth))?;
+    let position = if position.is_empty() {
+        Position::new_start()
+    } else {
+        position.join(" ").parse()?
+    };
+
+    let start = Instant::now();
+    let divide = perft_divide(&position.board, position.player, depth);
+    let elapsed = start.elapsed();
+    for (played, leaves) in divide.iter() {
+        println!("{:<5} {}", played.to_string(), leaves);
+    }
+    // the position itself is the only leaf at depth 0, or once the game is
+    // over.
+    let total: u64 = if divide.is_empty() {
+        1
+    } else {
+        divide.iter().map(|(_, leaves)| leaves).sum()
+    };
+    println!("Total {} ({} ms)", total, elapsed.as_millis());
+
+    Ok(())
+}
+
+/// Runs a tournament between the engines given by their descriptions (ie
+/// `alphabeta:6`, see `PlayerFactory`), and prints the cross-table with the
+/// ratings. The options come first :
+/// * `--games <count>` : games per pairing (and opening), 2 by default,
+/// * `--opening <line>` : a fixed opening (ie `f5d6c3`), repeatable,
+/// * `--time <seconds>[+<increment>]` : the time control of the games,
+/// * `--swiss <rounds>` : Swiss pairings instead of a round-robin,
+/// * `--adjudicate <empties>` : adjudication by the endgame solver.
+pub fn tournament_command(args: &[String]) -> Result<(), CommandError> {
+    let mut games = 2;
+    let mut openings = Vec::new();
+    let mut time_control = None;
+    let mut pairing = Pairing::RoundRobin;
+    let mut adjudication = None;
+    let mut args = args.iter();
+    let mut engines = Vec::new();
+    while let Some(arg) = args.next() {
+        if !arg.starts_with("--") {
+            engines.push(arg);
+            continue;
+        }
+        let value = args
+            .next()
+            .ok_or_else(|| format!("missing value for {}", arg))?;
+        let invalid = || format!("invalid value for {} : {}", arg, value);
+        match arg.as_str() {
+            "--games" => games = value.parse().map_err(|_| invalid())?,
+            "--opening" => openings.push(value),
+            "--time" => time_control = Some(parse_time_control(value).ok_or_else(invalid)?),
+            "--swiss" => {
+                pairing = Pairing::Swiss {
+                    rounds: value.parse().map_err(|_| invalid())?,
+                }
+            }
+            "--adjudicate" => adjudication = Some(value.parse().map_err(|_| invalid())?),
+            _ => return Err(format!("unknown option : {}", arg).into()),
+        }
+    }
+    if engines.len() < 2 {
+        return Err("a tournament needs at least two engines".to_string().into());
+    }
+
+    let mut tournament = Tournament::new(games).with_pairing(pairing);
+    if let Some(time_control) = time_control {
+        tournament = tournament.with_time_control(time_control);
+    }
+    if let Some(empties) = adjudication {
+        tournament = tournament.with_adjudication(empties);
+    }
+    for opening in openings {
+        tournament
+            .add_opening(opening)
+            .map_err(|error| format!("invalid opening {} : {}", opening, error))?;
+    }
+    let factory = PlayerFactory::standard();
+    for engine in engines {
+        tournament.add_player(factory.build(engine)?);
+    }
+
+    let result = tournament.run();
+    println!("{} games played.", result.games().len());
+    print!("{}", result);
+
+    Ok(())
+}
+
+/// Parses a time control given in seconds, with an optional increment (ie
+/// `60+1`).
+pub fn parse_time_control(s: &str) -> Option<TimeControl> {
+    let (main_time, increment) = match s.split_once('+') {
+        Some((main_time, increment)) => (main_time, increment),
+        None => (s, "0"),
+    };
+    let seconds = |s: &str| s.parse::<f64>().ok().filter(|seconds| *seconds >= 0.0);
+    Some(TimeControl::new(
+        Duration::from_secs_f64(seconds(main_time)?),
+        Duration::from_secs_f64(seconds(increment)?),
+    ))
+}
+
+/// Analyzes a game given by its transcript (ie `F5D6C3`, whitespaces being
+/// allowed) : each move is evaluated by the alpha-beta engine, at the depth
+/// given with `--depth <depth>`, and the inaccuracies, mistakes and blunders
+/// are printed with the better move.
+pub fn analyze_command(args: &[String]) -> Result<(), CommandError> {
+    let mut depth = DEFAULT_ANALYSIS_DEPTH;
+    let mut transcript = String::new();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        if arg == "--depth" {
+            depth = args
+                .next()
+                .and_then(|depth| depth.parse().ok())
+                .filter(|depth| (1..=10).contains(depth))
+                .ok_or_else(|| "invalid depth".to_string())?;
+        } else {
+            transcript.push_str(arg);
+        }
+    }
+    if transcript.is_empty() {
+        return Err("analyze needs a transcript".to_string().into());
+    }
+    let game = Game::from_transcript(&transcript).map_err(|error| error.to_string())?;
+
+    let engine = AlphaBeta::new(depth);
+    let analysis = annotate_game(&game, &engine, AnnotationThresholds::default())
+        .map_err(|error| error.to_string())?;
+    for annotated in analysis.moves() {
+        let entry = &annotated.entry;
+        if entry.played == Move::Pass {
+            println!("{:>3}. {} passes", entry.turn, entry.player);
+            continue;
+        }
+        let symbol = annotated.annotation.map_or("", Annotation::symbol);
+        let played = format!("{}{}", entry.played, symbol);
+        print!("{:>3}. {} {:<5}", entry.turn, entry.player, played);
+        if let Some(score) = annotated.played_score {
+            print!(" {:>12}", format_score(score));
+        }
+        if let Some(comment) = annotation_comment(annotated) {
+            print!("  {}", comment);
+        }
+        println!();
+    }
+
+    println!();
+    println!("{}", analysis);
+    for player in [Player::Black, Player::White] {
+        let count = |annotation| analysis.count(player, annotation);
+        println!(
+            "{} : {} inaccuracies, {} mistakes, {} blunders",
+            player,
+            count(Annotation::Inaccuracy),
+            count(Annotation::Mistake),
+            count(Annotation::Blunder)
+        );
+    }
+    if let Some(result) = game.result() {
+        println!("{}.", result);
+    }
+
+    Ok(())
+}
+
+/// Describes the annotation of a move with the better move, ie
+/// `inaccuracy, A3 was better (+4)`.
+fn annotation_comment(annotated: &AnnotatedMove) -> Option<String> {
+    let annotation = annotated.annotation?;
+    let (x, y) = annotated.best?;
+    let best_score = annotated.best_score.map_or(String::new(), format_score);
+    Some(format!(
+        "{}, {} was better ({})",
+        annotation,
+        Move::Place { x, y },
+        best_score
+    ))
+}
+
+/// Formats an evaluation from the point of view of the player, the proven
+/// results being spelled out.
+fn format_score(score: i32) -> String {
+    match score {
+        i32::MAX => "win".to_string(),
+        score if score == -i32::MAX => "loss".to_string(),
+        score => format!("{:+}", score),
+    }
+}
+
+/// Solves a position with the exact endgame solver, and prints the proven
+/// score and the best line. The position is given in its text format (see
+/// `Position`), or by a file holding it or an ascii board (see
+/// `board_from_ascii`), the player to move being then given with
+/// `--player <color>` (Black by default). With `--wld`, the solver only
+/// proves the win, loss or draw, which is much faster with more empties.
+pub fn solve_command(args: &[String]) -> Result<(), CommandError> {
+    let mut wld = false;
+    let mut player = Player::Black;
+    let mut position = Vec::new();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--wld" => wld = true,
+            "--player" => {
+                player = args
+                    .next()
+                    .and_then(|color| parse_color(color))
+                    .ok_or_else(|| "invalid player".to_string())?
+            }
+            _ => position.push(arg.as_str()),
+        }
+    }
+    let position = read_position(&position.join(" "), player)?;
+    let Position { board, player } = position;
+
+    println!("{}", board_to_ascii(&board));
+    println!("{} to move, {} empties.", player, board.empties());
+    if !board.has_legal_move(player) && !board.has_legal_move(player.opponent()) {
+        let (black, white) = board.count_pieces();
+        println!("The game is over : {} - {}.", black, white);
+        return Ok(());
+    }
+
+    let solver = EndgameSolver::new();
+    let start = Instant::now();
+    let solve = |player| {
+        let solution = if wld {
+            solver.solve_wld(&board, player)
+        } else {
+            solver.solve(&board, player)
+        };
+        solution.map(|(_, score)| score)
+    };
+    // a blocked player passes, the score of the opponent being proven.
+    let score = solve(player).unwrap_or_else(|| -solve(player.opponent()).unwrap_or(0));
+    let line = solver.best_line(&board, player, wld);
+    let elapsed = start.elapsed();
+
+    if wld {
+        let outcome = match score {
+            1 => "wins",
+            0 => "draws",
+            _ => "loses",
+        };
+        println!("{} {} with a perfect play.", player, outcome);
+    } else {
+        println!("Final disc difference for {} : {:+}.", player, score);
+    }
+    let line: Vec<String> = line.iter().map(Move::to_string).collect();
+    println!("Best line : {}", line.join(" "));
+    println!(
+        "{} nodes in {} ms.",
+        solver.move_count(),
+        elapsed.as_millis()
+    );
+
+    Ok(())
+}
+
+/// Reads a position given in its text format, or a file holding it or an
+/// ascii board, the given player moving first in the latter case.
+fn read_position(s: &str, player: Player) -> Result<Position, String> {
+    if s.is_empty() {
+        return Err("solve needs a position".to_string());
+    }
+    if !Path::new(s).is_file() {
+        return s.parse();
+    }
+    let content = fs::read_to_string(s).map_err(|error| format!("{} : {}", s, error))?;
+    match content.trim().parse() {
+        Ok(position) => Ok(position),
+        Err(_) => Ok(Position::new(board_from_ascii(&content)?, player)),
+    }
+}
+
+/// Plays the moves read from the given file, or from the standard input
+/// without file (or with `-`), without prompting, then prints the final board
+/// and the result. The moves are in standard notation (ie `F5 D6 C3` or
+/// `f5d6c3`), `#` starting a comment up to the end of the line. The passes
+/// are implicit, a `pass` being accepted after a move blocking the opponent.
+/// On an illegal move, the board is printed and the command fails. The user
+/// configuration isn't used, the output being the same for everyone.
+pub fn play_command(args: &[String]) -> Result<(), CommandError> {
+    let script = match args {
+        [] => read_stdin()?,
+        [path] if path == "-" => read_stdin()?,
+        [path] => fs::read_to_string(path).map_err(|error| format!("{} : {}", path, error))?,
+        _ => return Err("play only takes a file".to_string().into()),
+    };
+    let config = Config::default();
+
+    let mut game = Game::new();
+    let tokens = script
+        .lines()
+        .map(|line| line.split('#').next().unwrap_or_default())
+        .flat_map(|line| line.split(|c: char| c.is_whitespace() || c == ','))
+        .filter(|token| !token.is_empty());
+    for token in tokens {
+        // a token holds one or more moves, the passes not being counted.
+        let first_move = game.move_number();
+        let played = if token.eq_ignore_ascii_case("pass") {
+            if game.opponent_is_blocked() {
+                // the pass was played automatically.
+                continue;
+            }
+            game.play_notation(token)
+                .map_err(|error| (first_move, error))
+        } else {
+            game.play_transcript(token).map_err(|error| match error {
+                GameError::Transcript {
+                    move_index, error, ..
+                } => (first_move + move_index - 1, *error),
+                error => (first_move, error),
+            })
+        };
+        if let Err((move_number, error)) = played {
+            display_game_status(&game, false, &config);
+            return Err(CommandError::Failed(format!(
+                "Illegal move {} : {}",
+                move_number, error
+            )));
+        }
+    }
+    display_game_status(&game, false, &config);
+
+    Ok(())
+}
+
+fn read_stdin() -> Result<String, String> {
+    let mut script = String::new();
+    io::stdin()
+        .read_to_string(&mut script)
+        .map_err(|error| error.to_string())?;
+    Ok(script)
+}
+
+/// Steps through a game move by move, the board being drawn at each ply with
+/// the evaluation of the move by the alpha-beta engine (at the depth given
+/// with `--depth <depth>`). The game is given by a file saved during a game
+/// (with the `serde` feature) or holding a transcript, or by a transcript.
+/// Enter (or `n`) goes to the next ply, `p` to the previous one, `f` and `l`
+/// to the first and the last ones, a number to the given ply, `q` quits.
+pub fn replay_command(args: &[String]) -> Result<(), CommandError> {
+    let mut depth = DEFAULT_ANALYSIS_DEPTH;
+    let mut source = Vec::new();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        if arg == "--depth" {
+            depth = args
+                .next()
+                .and_then(|depth| depth.parse().ok())
+                .filter(|depth| (1..=10).contains(depth))
+                .ok_or_else(|| "invalid depth".to_string())?;
+        } else {
+            source.push(arg.as_str());
+        }
+    }
+    if source.is_empty() {
+        return Err("replay needs a file or a transcript".to_string().into());
+    }
+    let game = read_game(&source.join(" "))?;
+    let config = Config::load()?;
+
+    let analysis = annotate_game(
+        &game,
+        &AlphaBeta::new(depth),
+        AnnotationThresholds::default(),
+    )
+    .map_err(|error| error.to_string())?;
+    let replay = game.replay();
+    let mut boards = vec![*replay.board()];
+    boards.extend(replay.map(|(_, _, board)| board));
+    let last = boards.len() - 1;
+
+    let mut ply = 0;
+    loop {
+        let board = &boards[ply];
+        println!("------------------------------------------------------------");
+        println!("{}", config.render_board(board, &[]));
+        let (black, white) = board.count_pieces();
+        println!(
+            "{} {} - {} {}",
+            config.player_label(Player::Black),
+            black,
+            white,
+            config.player_label(Player::White)
+        );
+        match ply.checked_sub(1).map(|index| &analysis.moves()[index]) {
+            None => println!("Start of the game, {} plies.", last),
+            Some(annotated) => {
+                let entry = &annotated.entry;
+                let symbol = annotated.annotation.map_or("", Annotation::symbol);
+                print!(
+                    "Ply {} / {} : {} {}{}",
+                    ply, last, entry.player, entry.played, symbol
+                );
+                if let Some(score) = annotated.played_score {
+                    print!(", evaluation {}", format_score(score));
+                }
+                if let Some(comment) = annotation_comment(annotated) {
+                    print!(" ({})", comment);
+                }
+                println!();
+            }
+        }
+        if ply == last {
+            if let Some(result) = game.result() {
+                println!("{}.", result);
+            }
+        }
+
+        print!("(n)ext, (p)revious, (f)irst, (l)ast, ply number, (q)uit > ");
+        io::stdout().flush().map_err(|error| error.to_string())?;
+        let mut response = String::new();
+        let read = io::stdin()
+            .read_line(&mut response)
+            .map_err(|error| error.to_string())?;
+        ply = match response.trim().to_ascii_lowercase().as_str() {
+            _ if read == 0 => break,
+            "q" | "quit" => break,
+            "" | "n" | "next" => (ply + 1).min(last),
+            "p" | "prev" | "previous" => ply.saturating_sub(1),
+            "f" | "first" => 0,
+            "l" | "last" => last,
+            number => match number.parse::<usize>() {
+                Ok(number) => number.min(last),
+                Err(_) => ply,
+            },
+        };
+    }
+    println!();
+
+    Ok(())
+}
+
+/// Reads a game from a file saved during a game or holding a transcript, or
+/// from a transcript.
+fn read_game(s: &str) -> Result<Game, String> {
+    if !Path::new(s).is_file() {
+        return Game::from_transcript(s).map_err(|error| error.to_string());
+    }
+    if let Some(game) = load_saved_game(s) {
+        return Ok(game);
+    }
+    let content = fs::read_to_string(s).map_err(|error| format!("{} : {}", s, error))?;
+    Game::from_transcript(content.trim()).map_err(|error| format!("{} : {}", s, error))
+}
+
+#[cfg(feature = "serde")]
+fn load_saved_game(path: &str) -> Option<Game> {
+    Game::load(path).ok()
+}
+
+#[cfg(not(feature = "serde"))]
+fn load_saved_game(_path: &str) -> Option<Game> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn play_command_reports_the_illegal_move() {
+        let path = std::env::temp_dir().join(format!("rusthello-play-{}.txt", std::process::id()));
+        // A1 is the fourth move, in the second token.
+        fs::write(&path, "F5 # opening\nd6c3A1 pass").unwrap();
+        let result = play_command(&[path.to_string_lossy().into_owned()]);
+        fs::remove_file(&path).unwrap();
+        assert_eq!(
+            result,
+            Err(CommandError::Failed(
+                "Illegal move 4 : A1 does not capture any piece".to_string()
+            ))
+        );
+        assert!(matches!(
+            play_command(&["a".to_string(), "b".to_string()]),
+            Err(CommandError::Usage(_))
+        ));
+    }
+}
@@ -0,0 +1,299 @@
+use std::{
+    env,
+    ffi::OsString,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use rusthello::{board_to_themed_ascii, Board, BoardTheme, Player};
+use toml::{Table, Value};
+
+use crate::{
+    i18n::{Language, Messages},
+    parse_color,
+};
+
+/// Preferences of the player, read from `rusthello.toml` in the configuration
+/// directory of the user (`$XDG_CONFIG_HOME`, or `~/.config` by default) and
+/// overridden by the flags of the command line. The file is written in TOML,
+/// ie :
+///
+/// ```toml
+/// color = "black"        # color of the human, not asked anymore
+/// computer = "hard"      # level, depth or engine (ie 6, "pipeline:8"), not asked anymore
+/// hints = true           # shows the legal moves on the board
+/// eval = true            # shows an evaluation bar after each move
+/// quiet = true           # hides the progress of the searches of the computer
+/// teach = true           # explains the captures of each move
+/// theme = "unicode"      # characters of the board : classic, minimal, unicode, colorblind
+/// colors = "blue"        # board colors of the full-screen interface
+/// language = "fr"        # language of the messages, from LANG by default
+/// name = "Alice"         # name of the human, not asked anymore
+///
+/// [names]
+/// black = "Alice"
+/// white = "Bob"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Config {
+    pub color: Option<Player>,
+    pub computer: Option<String>,
+    pub hints: bool,
+    pub eval: bool,
+    pub quiet: bool,
+    pub teach: bool,
+    pub theme: BoardTheme,
+    pub colors: Colors,
+    pub language: Option<Language>,
+    pub name: Option<String>,
+    pub black_name: Option<String>,
+    pub white_name: Option<String>,
+}
+
+/// Colors of the board in the full-screen interface.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Colors {
+    #[default]
+    Green,
+    Blue,
+    Gray,
+}
+
+impl Config {
+    /// Reads the configuration file of the user, the defaults being used when
+    /// there is none.
+    pub fn load() -> Result<Config, String> {
+        let path = match Self::path() {
+            Some(path) => path,
+            None => return Ok(Config::default()),
+        };
+        match fs::read_to_string(&path) {
+            Ok(content) => {
+                Self::parse(&content).map_err(|error| format!("{} : {}", path.display(), error))
+            }
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Config::default()),
+            Err(error) => Err(format!("{} : {}", path.display(), error)),
+        }
+    }
+
+    /// Returns the configuration file of the user, in its configuration
+    /// directory.
+    pub fn path() -> Option<PathBuf> {
+        let directory = config_directory(env::var_os("XDG_CONFIG_HOME"), env::var_os("HOME"))?;
+        Some(directory.join("rusthello.toml"))
+    }
+
+    /// Parses the content of a configuration file, the unknown keys being
+    /// rejected to catch the typos.
+    pub fn parse(content: &str) -> Result<Config, String> {
+        let mut config = Config::default();
+        for (key, value) in parse_values(content)? {
+            let invalid = || format!("invalid value for {} : {:?}", key, value);
+            match key.as_str() {
+                "color" => {
+                    config.color = Some(value.as_str().and_then(parse_color).ok_or_else(invalid)?)
+                }
+                "computer" => {
+                    config.computer = Some(match &value {
+                        Value::Integer(depth) => depth.to_string(),
+                        Value::String(computer) => computer.clone(),
+                        _ => return Err(invalid()),
+                    })
+                }
+                "hints" => config.hints = value.as_bool().ok_or_else(invalid)?,
+                "eval" => config.eval = value.as_bool().ok_or_else(invalid)?,
+                "quiet" => config.quiet = value.as_bool().ok_or_else(invalid)?,
+                "teach" => config.teach = value.as_bool().ok_or_else(invalid)?,
+                "theme" => config.theme = parse_theme(value.as_str().ok_or_else(invalid)?)?,
+                "colors" => config.colors = value.as_str().ok_or_else(invalid)?.parse()?,
+                "language" => {
+                    config.language = Some(
+                        value
+                            .as_str()
+                            .and_then(Language::parse)
+                            .ok_or_else(invalid)?,
+                    )
+                }
+                "name" => config.name = Some(value.as_str().ok_or_else(invalid)?.to_string()),
+                "names.black" => {
+                    config.black_name = Some(value.as_str().ok_or_else(invalid)?.to_string())
+                }
+                "names.white" => {
+                    config.white_name = Some(value.as_str().ok_or_else(invalid)?.to_string())
+                }
+                _ => return Err(format!("unknown key : {}", key)),
+            }
+        }
+        Ok(config)
+    }
+
+    /// Returns the messages in the chosen language, or the one of the locale.
+    pub fn messages(&self) -> &'static Messages {
+        self.language.unwrap_or_else(Language::from_env).messages()
+    }
+
+    /// Returns the color of the player, followed by its name if any (ie
+    /// `Black (Alice)`).
+    pub fn player_label(&self, player: Player) -> String {
+        let (color, name) = match player {
+            Player::Black => (self.messages().black, &self.black_name),
+            Player::White => (self.messages().white, &self.white_name),
+        };
+        match name {
+            Some(name) => format!("{} ({})", color, name),
+            None => color.to_string(),
+        }
+    }
+
+    /// Returns the configuration of a game where the human has the given
+    /// color, its name being given to this color unless the color is named.
+    pub fn for_human(&self, human: Player) -> Config {
+        let mut config = self.clone();
+        let color_name = match human {
+            Player::Black => &mut config.black_name,
+            Player::White => &mut config.white_name,
+        };
+        if color_name.is_none() {
+            color_name.clone_from(&self.name);
+        }
+        config
+    }
+
+    /// Draws the board with the chosen theme, the given squares being
+    /// marked.
+    pub fn render_board<const N: usize>(&self, board: &Board<N>, hints: &[(u8, u8)]) -> String {
+        board_to_themed_ascii(board, hints, &self.theme)
+    }
+}
+
+/// Returns the board theme of the given name, see `BoardTheme::ALL`.
+pub fn parse_theme(name: &str) -> Result<BoardTheme, String> {
+    BoardTheme::by_name(name).ok_or_else(|| {
+        let names: Vec<&str> = BoardTheme::ALL.iter().map(|theme| theme.name).collect();
+        format!("invalid theme : {} (one of {})", name, names.join(", "))
+    })
+}
+
+impl std::str::FromStr for Colors {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "green" => Ok(Colors::Green),
+            "blue" => Ok(Colors::Blue),
+            "gray" | "grey" => Ok(Colors::Gray),
+            _ => Err(format!("invalid colors : {}", s)),
+        }
+    }
+}
+
+/// Returns the configuration directory given by `XDG_CONFIG_HOME`, or the
+/// `.config` directory of the given home, a relative `XDG_CONFIG_HOME` being
+/// ignored as required by the XDG specification.
+fn config_directory(xdg_config_home: Option<OsString>, home: Option<OsString>) -> Option<PathBuf> {
+    match xdg_config_home {
+        Some(directory) if Path::new(&directory).is_absolute() => Some(PathBuf::from(directory)),
+        _ => Some(PathBuf::from(home?).join(".config")),
+    }
+}
+
+/// Reads the values of the file, the keys of a table being prefixed by its
+/// name (ie `names.black`).
+fn parse_values(content: &str) -> Result<Vec<(String, Value)>, String> {
+    let table: Table = content
+        .parse()
+        .map_err(|error: toml::de::Error| error.to_string())?;
+    let mut values = Vec::new();
+    flatten_table(String::new(), table, &mut values);
+    Ok(values)
+}
+
+fn flatten_table(prefix: String, table: Table, values: &mut Vec<(String, Value)>) {
+    for (key, value) in table {
+        let key = format!("{}{}", prefix, key);
+        match value {
+            Value::Table(table) => flatten_table(format!("{}.", key), table, values),
+            value => values.push((key, value)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_reads_the_preferences() {
+        let content = "# preferences\n\
+                       color = \"white\"\n\
+                       computer = 8 # deeper\n\
+                       hints = true\n\
+                       quiet = true\n\
+                       theme = \"minimal\"\n\
+                       colors = \"blue\"\n\
+                       language = \"en\"\n\
+                       \n\
+                       [names]\n\
+                       black = \"Alice # the first\"\n";
+        let config = Config::parse(content).unwrap();
+        assert_eq!(config.color, Some(Player::White));
+        assert_eq!(config.computer, Some("8".to_string()));
+        assert!(config.hints);
+        assert!(config.quiet);
+        assert_eq!(config.theme, BoardTheme::MINIMAL);
+        assert_eq!(config.colors, Colors::Blue);
+        assert_eq!(config.language, Some(Language::English));
+        assert_eq!(
+            config.player_label(Player::Black),
+            "Black (Alice # the first)"
+        );
+        assert_eq!(config.player_label(Player::White), "White");
+        let config = Config::parse("name = \"Bob\"\nlanguage = \"en\"")
+            .unwrap()
+            .for_human(Player::White);
+        assert_eq!(config.player_label(Player::White), "White (Bob)");
+        assert_eq!(Config::parse(""), Ok(Config::default()));
+    }
+
+    #[test]
+    fn parse_rejects_invalid_files() {
+        assert!(Config::parse("depht = 6").is_err());
+        assert!(Config::parse("hints = \"yes\"").is_err());
+        assert!(Config::parse("theme = \"fancy\"").is_err());
+        assert!(Config::parse("colors = \"pink\"").is_err());
+        assert!(Config::parse("language = \"klingon\"").is_err());
+        assert!(Config::parse("hints = true\nhints = false").is_err());
+        assert!(Config::parse("color = \"black").is_err());
+        assert!(Config::parse("[names\nblack = \"Alice\"").is_err());
+        assert!(Config::parse("names = \"Alice\"").is_err());
+        assert!(Config::parse("hints = [true]").is_err());
+    }
+
+    #[test]
+    fn parse_reads_any_toml_syntax() {
+        let content = "name = 'C:\\Alice'\n\
+                       names = { black = \"Bob \\\"B\\\"\", white = \"\\u00c9ve\" }\n";
+        let config = Config::parse(content).unwrap();
+        assert_eq!(config.name, Some("C:\\Alice".to_string()));
+        assert_eq!(config.black_name, Some("Bob \"B\"".to_string()));
+        assert_eq!(config.white_name, Some("Éve".to_string()));
+    }
+
+    #[test]
+    fn config_directory_prefers_xdg_config_home() {
+        let home = Some(OsString::from("/home/alice"));
+        assert_eq!(
+            config_directory(Some(OsString::from("/etc/alice")), home.clone()),
+            Some(PathBuf::from("/etc/alice"))
+        );
+        assert_eq!(
+            config_directory(None, home.clone()),
+            Some(PathBuf::from("/home/alice/.config"))
+        );
+        assert_eq!(
+            config_directory(Some(OsString::from("relative")), home),
+            Some(PathBuf::from("/home/alice/.config"))
+        );
+        assert_eq!(config_directory(None, None), None);
+    }
+}
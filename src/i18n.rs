@@ -0,0 +1,286 @@
+use std::env;
+
+/// Language of the messages of the interactive game.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Language {
+    #[default]
+    English,
+    French,
+}
+
+impl Language {
+    /// Returns the language of the locale of the user (`LC_ALL`,
+    /// `LC_MESSAGES` or `LANG`), English when it isn't supported.
+    pub fn from_env() -> Language {
+        ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .iter()
+            .filter_map(|name| env::var(name).ok())
+            .find(|locale| !locale.is_empty())
+            .and_then(|locale| Language::parse(&locale))
+            .unwrap_or_default()
+    }
+
+    /// Parses a language code (ie `fr`) or a locale (ie `fr_FR.UTF-8`).
+    pub fn parse(s: &str) -> Option<Language> {
+        let code = s.split(['_', '-', '.']).next()?;
+        match code.to_ascii_lowercase().as_str() {
+            "en" | "english" | "c" | "posix" => Some(Language::English),
+            "fr" | "french" | "français" => Some(Language::French),
+            _ => None,
+        }
+    }
+
+    /// Returns the catalog of the messages in this language.
+    pub fn messages(self) -> &'static Messages {
+        match self {
+            Language::English => &ENGLISH,
+            Language::French => &FRENCH,
+        }
+    }
+}
+
+/// Catalog of the messages of the interactive game, the `{}` being replaced
+/// by the arguments given to `fill`.
+pub struct Messages {
+    pub black: &'static str,
+    pub white: &'static str,
+    pub ask_color: &'static str,
+    pub ask_computer: &'static str,
+    pub ask_name: &'static str,
+    pub you: &'static str,
+    pub scoreboard: &'static str,
+    pub ask_rematch: &'static str,
+    pub invalid_response: &'static str,
+    pub try_again: &'static str,
+    pub ask_move: &'static str,
+    pub help: &'static str,
+    pub legal_moves: &'static str,
+    pub game_record: &'static str,
+    pub no_move_played: &'static str,
+    pub cannot_pass: &'static str,
+    pub invalid_move: &'static str,
+    pub directions: [&'static str; 8],
+    pub teach_captures: &'static str,
+    pub teach_no_capture: &'static str,
+    pub teach_captured: &'static str,
+    pub teach_edge: &'static str,
+    pub teach_empty: &'static str,
+    pub teach_own_piece: &'static str,
+    pub teach_not_closed: &'static str,
+    pub computer_thinking: &'static str,
+    pub search_progress: &'static str,
+    pub computer_played: &'static str,
+    pub computer_analysis: &'static str,
+    pub computer_resigns: &'static str,
+    pub game_over: &'static str,
+    pub final_score: &'static str,
+    pub empties_to_winner: &'static str,
+    pub empties_shared: &'static str,
+    pub wins_by: &'static str,
+    pub wins: &'static str,
+    pub draw: &'static str,
+    pub ended_blocked: &'static str,
+    pub ended_board_full: &'static str,
+    pub ended_resignation: &'static str,
+    pub ended_timeout: &'static str,
+    pub ended_adjudicated: &'static str,
+    pub ended_fewest_pieces: &'static str,
+    pub opponent_blocked: &'static str,
+    pub turn_of: &'static str,
+    pub low_time: &'static str,
+    pub hint: &'static str,
+    pub no_hint: &'static str,
+    pub game_saved: &'static str,
+    pub save_failed: &'static str,
+    pub game_loaded: &'static str,
+    pub load_failed: &'static str,
+}
+
+const ENGLISH: Messages = Messages {
+    black: "Black",
+    white: "White",
+    ask_color: "Which color do you play ? ('black' or 'white')",
+    ask_computer: "Which computer do you play against ? (level among {}, depth 4 .. 10, or one of {}, empty for depth {})",
+    ask_name: "What's your name ? (empty for none)",
+    you: "You",
+    scoreboard: "Session score, {} against {} : {} won, {} drawn, {} lost, disc difference {}",
+    ask_rematch: "Play again, with the colors swapped ? (Y : yes, S : same colors, N : no)",
+    invalid_response: "Previous response was invalid, let's try again.",
+    try_again: "{}, let's try again.",
+    ask_move: "What's your move ? (ie F5, or HELP for the commands)",
+    help: "Commands :
+  F5 (or f5, 5f) : plays a move
+  HINT           : suggests a move, with its evaluation
+  MOVES          : prints the moves played, to share the game
+  LEGAL          : lists the legal moves
+  H              : shows or hides the legal moves on the board
+  UNDO / REDO    : takes back (or plays again) your last move
+  SAVE / LOAD x  : saves the game to (or loads it from) x.json
+  PASS           : the passes are automatic, when no move is possible
+  R / RESIGN     : resigns
+  Q / QUIT       : quits",
+    legal_moves: "Legal moves : {}",
+    game_record: "Moves played :\n{}\nTranscript : {}",
+    no_move_played: "No move played yet.",
+    cannot_pass: "You can't pass while a move is possible.",
+    invalid_move: "Invalid move, {}. Let's try again.",
+    directions: [
+        "north",
+        "north-east",
+        "east",
+        "south-east",
+        "south",
+        "south-west",
+        "west",
+        "north-west",
+    ],
+    teach_captures: "{} captures :",
+    teach_no_capture: "{} captures nothing :",
+    teach_captured: "  towards the {} : {}, closed by {}",
+    teach_edge: "  towards the {} : the edge of the board",
+    teach_empty: "  towards the {} : an empty square",
+    teach_own_piece: "  towards the {} : {} of the same color, with nothing between",
+    teach_not_closed: "  towards the {} : {}, not closed by a disc of the same color",
+    computer_thinking: "Computer is thinking...",
+    search_progress: "  depth {}, {} nodes : {}",
+    computer_played: "Computer played at {}",
+    computer_analysis: "Computer analysis : {}",
+    computer_resigns: "Computer resigns.",
+    game_over: "The game is over !",
+    final_score: "Final score : {}",
+    empties_to_winner: "{} empty squares awarded to the winner",
+    empties_shared: "{} empty squares shared",
+    wins_by: "{} wins by {} : {}.",
+    wins: "{} wins : {}.",
+    draw: "Draw : {}.",
+    ended_blocked: "none of the players can move",
+    ended_board_full: "the board is full",
+    ended_resignation: "{} resigned",
+    ended_timeout: "{} lost on time",
+    ended_adjudicated: "adjudicated by the endgame solver",
+    ended_fewest_pieces: "the fewest pieces win",
+    opponent_blocked: "The turn does not change as {} can't move.",
+    turn_of: "It's the turn of {}.",
+    low_time: "Hurry up, only {} left !",
+    hint: "Hint : {}",
+    no_hint: "No move to suggest.",
+    game_saved: "Game saved to {}.",
+    save_failed: "Unable to save the game : {}",
+    game_loaded: "Game loaded from {}.",
+    load_failed: "Unable to load the game : {}",
+};
+
+const FRENCH: Messages = Messages {
+    black: "Noir",
+    white: "Blanc",
+    ask_color: "Quelle couleur jouez-vous ? ('noir' ou 'blanc')",
+    ask_computer: "Contre quel ordinateur jouez-vous ? (niveau parmi {}, profondeur 4 .. 10, ou l'un de {}, vide pour la profondeur {})",
+    ask_name: "Quel est votre nom ? (vide pour aucun)",
+    you: "Vous",
+    scoreboard: "Score de la session, {} contre {} : {} gagnées, {} nulles, {} perdues, différence de pions {}",
+    ask_rematch: "Rejouer, en échangeant les couleurs ? (O : oui, M : mêmes couleurs, N : non)",
+    invalid_response: "La réponse précédente est invalide, recommençons.",
+    try_again: "{}, recommençons.",
+    ask_move: "Quel est votre coup ? (ex : F5, ou HELP pour les commandes)",
+    help: "Commandes :
+  F5 (ou f5, 5f) : joue un coup
+  HINT           : suggère un coup, avec son évaluation
+  MOVES          : affiche les coups joués, pour partager la partie
+  LEGAL          : liste les coups possibles
+  H              : affiche ou cache les coups possibles sur le plateau
+  UNDO / REDO    : annule (ou rejoue) votre dernier coup
+  SAVE / LOAD x  : sauvegarde la partie dans (ou la charge depuis) x.json
+  PASS           : les passes sont automatiques, quand aucun coup n'est possible
+  R / RESIGN     : abandonne
+  Q / QUIT       : quitte",
+    legal_moves: "Coups possibles : {}",
+    game_record: "Coups joués :\n{}\nTranscription : {}",
+    no_move_played: "Aucun coup joué pour l'instant.",
+    cannot_pass: "Vous ne pouvez pas passer quand un coup est possible.",
+    invalid_move: "Coup invalide, {}. Recommençons.",
+    directions: [
+        "le nord",
+        "le nord-est",
+        "l'est",
+        "le sud-est",
+        "le sud",
+        "le sud-ouest",
+        "l'ouest",
+        "le nord-ouest",
+    ],
+    teach_captures: "{} capture :",
+    teach_no_capture: "{} ne capture rien :",
+    teach_captured: "  vers {} : {}, encadré par {}",
+    teach_edge: "  vers {} : le bord du plateau",
+    teach_empty: "  vers {} : une case vide",
+    teach_own_piece: "  vers {} : {} de la même couleur, sans rien entre les deux",
+    teach_not_closed: "  vers {} : {}, non encadré par un pion de la même couleur",
+    computer_thinking: "L'ordinateur réfléchit...",
+    search_progress: "  profondeur {}, {} nœuds : {}",
+    computer_played: "L'ordinateur a joué en {}",
+    computer_analysis: "Analyse de l'ordinateur : {}",
+    computer_resigns: "L'ordinateur abandonne.",
+    game_over: "La partie est terminée !",
+    final_score: "Score final : {}",
+    empties_to_winner: "{} cases vides attribuées au gagnant",
+    empties_shared: "{} cases vides partagées",
+    wins_by: "{} gagne de {} : {}.",
+    wins: "{} gagne : {}.",
+    draw: "Match nul : {}.",
+    ended_blocked: "aucun joueur ne peut jouer",
+    ended_board_full: "le plateau est plein",
+    ended_resignation: "{} a abandonné",
+    ended_timeout: "{} a dépassé son temps",
+    ended_adjudicated: "arbitrée par le solveur de finales",
+    ended_fewest_pieces: "le moins de pions gagne",
+    opponent_blocked: "Le tour ne change pas car {} ne peut pas jouer.",
+    turn_of: "C'est au tour de {}.",
+    low_time: "Dépêchez-vous, il ne reste que {} !",
+    hint: "Suggestion : {}",
+    no_hint: "Aucun coup à suggérer.",
+    game_saved: "Partie sauvegardée dans {}.",
+    save_failed: "Impossible de sauvegarder la partie : {}",
+    game_loaded: "Partie chargée depuis {}.",
+    load_failed: "Impossible de charger la partie : {}",
+};
+
+/// Replaces the `{}` of a message by the given arguments, in order.
+pub fn fill(message: &str, args: &[&dyn std::fmt::Display]) -> String {
+    let mut filled = String::new();
+    let mut args = args.iter();
+    let mut parts = message.split("{}");
+    if let Some(first) = parts.next() {
+        filled.push_str(first);
+    }
+    for part in parts {
+        if let Some(arg) = args.next() {
+            filled.push_str(&arg.to_string());
+        }
+        filled.push_str(part);
+    }
+    filled
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_reads_codes_and_locales() {
+        assert_eq!(Language::parse("fr_FR.UTF-8"), Some(Language::French));
+        assert_eq!(Language::parse("en"), Some(Language::English));
+        assert_eq!(Language::parse("C.UTF-8"), Some(Language::English));
+        assert_eq!(Language::parse("de_DE"), None);
+    }
+
+    #[test]
+    fn fill_replaces_the_placeholders_in_order() {
+        let messages = Language::French.messages();
+        assert_eq!(
+            fill(messages.opponent_blocked, &[&messages.white]),
+            "Le tour ne change pas car Blanc ne peut pas jouer."
+        );
+        assert_eq!(fill("{} - {}", &[&1, &2]), "1 - 2");
+        assert_eq!(fill("no placeholder", &[&1]), "no placeholder");
+    }
+}
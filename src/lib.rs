@@ -1,3 +1,7 @@
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
 mod ascii_board;
 mod rusthello;
 
@@ -1,104 +1,864 @@
-use rusthello::{AlphaBeta, Game, Player, VirtualPlayer, board_to_ascii};
+use rusthello::{
+    AlphaBeta, Board, Clock, Game, GameResult, GameSession, HumanPlayer, Level, LineExplanation,
+    LineOutcome, Move, Player, PlayerFactory, SessionEvent, Square, TimeControl, VirtualPlayer,
+};
 use std::{
     char, env,
     io::{self, Write},
+    path::PathBuf,
     process,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc, Arc, Mutex,
+    },
+    thread::{self, JoinHandle},
+    time::{Duration, Instant},
 };
 
+mod commands;
+mod config;
+mod i18n;
+mod scoreboard;
+#[cfg(feature = "tui")]
+mod tui;
+
+use commands::CommandError;
+use config::{parse_theme, Config};
+use i18n::{fill, Language};
+use scoreboard::Scoreboard;
+
+/// Depth of the computer when the human doesn't choose it.
+const DEFAULT_DEPTH: u8 = 6;
+
+/// Delay between the moves of the computers watched, in milliseconds.
+const DEFAULT_WATCH_DELAY: u64 = 1000;
+
+/// Depth of the quick search suggesting a move to the human.
+const HINT_DEPTH: u8 = 4;
+
+/// Depth of the quick search giving the evaluation bar after each move.
+const EVALUATION_DEPTH: u8 = 3;
+
+/// Evaluation filling the whole bar for one side, and the cells of the bar.
+const EVALUATION_SCALE: i32 = 50;
+const EVALUATION_BAR_WIDTH: i32 = 20;
+
+/// A clock is running low when its remaining time is under this part of its
+/// main time.
+const LOW_TIME_DIVISOR: u32 = 10;
+
+/// Delay between the refreshes of the clocks while the computer thinks.
+const CLOCK_REFRESH_DELAY: Duration = Duration::from_millis(200);
+
+#[derive(Debug, PartialEq)]
 enum Choice {
     Quit,
-    Move { x: u8, y: u8 },
+    Resign,
+    Help,
+    ToggleHints,
+    Hint,
+    Moves,
+    LegalMoves,
+    Pass,
+    Undo,
+    Redo,
+    Save(String),
+    Load(String),
+    Move(Square),
 }
 
 fn main() {
-    let (human, depth) = parge_args();
-    let computer = Box::new(AlphaBeta::new(depth)) as Box<dyn VirtualPlayer>;
-
-    let mut game = Game::new();
-    while !game.game_over() {
-        if game.player().unwrap() == human {
-            let mut valid_move = false;
-            while !valid_move {
-                match get_choice_from_player(&game) {
-                    Choice::Quit => return,
-                    Choice::Move { x, y } => {
-                        if let Ok(_) = game.play(game.player().unwrap(), x, y) {
-                            valid_move = true
-                        }
+    let mut args: Vec<String> = env::args().collect();
+    let command = args.get(1).and_then(|name| commands::find(name));
+    if let Some(command) = command {
+        match command(&args[2..]) {
+            Ok(()) => (),
+            Err(CommandError::Usage(error)) => {
+                println!("Error : {}", error);
+                print_usage_and_exit();
+            }
+            Err(CommandError::Failed(error)) => {
+                eprintln!("Error : {}", error);
+                process::exit(1);
+            }
+        }
+        return;
+    }
+    let mut config = Config::load().unwrap_or_else(|error| {
+        println!("Error : {}", error);
+        process::exit(1);
+    });
+    let full_screen = take_flag(&mut args, "--tui");
+    if take_flag(&mut args, "--hints") {
+        config.hints = true;
+    }
+    if take_flag(&mut args, "--no-hints") {
+        config.hints = false;
+    }
+    if take_flag(&mut args, "--eval") {
+        config.eval = true;
+    }
+    if take_flag(&mut args, "--no-eval") {
+        config.eval = false;
+    }
+    if take_flag(&mut args, "--quiet") {
+        config.quiet = true;
+    }
+    if take_flag(&mut args, "--teach") {
+        config.teach = true;
+    }
+    if let Some(level) = take_option(&mut args, "--level") {
+        match Level::by_name(&level) {
+            Some(level) => config.computer = Some(level.name().to_string()),
+            None => {
+                println!("Invalid level : {}", level);
+                print_usage_and_exit();
+            }
+        }
+    }
+    if let Some(name) = take_option(&mut args, "--name") {
+        config.name = Some(name);
+    }
+    if let Some(theme) = take_option(&mut args, "--theme") {
+        config.theme = parse_theme(&theme).unwrap_or_else(|error| {
+            println!("{}", error);
+            print_usage_and_exit();
+        });
+    }
+    if let Some(language) = take_option(&mut args, "--lang") {
+        config.language = Some(Language::parse(&language).unwrap_or_else(|| {
+            println!("Invalid language : {}", language);
+            print_usage_and_exit();
+        }));
+    }
+    if let Some(colors) = take_option(&mut args, "--colors") {
+        config.colors = colors.parse().unwrap_or_else(|error| {
+            println!("{}", error);
+            print_usage_and_exit();
+        });
+    }
+    if args.get(1).map(String::as_str) == Some("--watch") {
+        watch(&args[2..], &config);
+        return;
+    }
+    let time_control = take_option(&mut args, "--time").map(|time| {
+        commands::parse_time_control(&time).unwrap_or_else(|| {
+            println!("Invalid time control : {}", time);
+            print_usage_and_exit();
+        })
+    });
+    let games = take_option(&mut args, "--games").map_or(1, |games| {
+        games
+            .parse()
+            .ok()
+            .filter(|&games| games > 0)
+            .unwrap_or_else(|| {
+                println!("Invalid count of games : {}", games);
+                print_usage_and_exit();
+            })
+    });
+    let (human, computer) = parge_args(&args, &mut config);
+    let new_computer = || {
+        parse_computer(&computer, &config).expect("Unexpected invalid computer after its check.")
+    };
+    if full_screen {
+        run_tui(human, new_computer(), &config.for_human(human));
+        return;
+    }
+
+    // the games are played with the same settings until the human doesn't
+    // want a rematch anymore, the score of the session being kept.
+    let mut human = human;
+    let mut scoreboard = Scoreboard::default();
+    let messages = config.messages();
+    let opponent = new_computer().name();
+    let mut games_left = games;
+    loop {
+        if let Some(result) = play_game(human, new_computer(), &config, time_control) {
+            scoreboard.record(&result, human);
+        }
+        games_left -= 1;
+        let you = config.name.as_deref().unwrap_or(messages.you);
+        println!("{}", scoreboard.summary(you, &opponent, messages));
+        if games_left == 0 {
+            match ask_rematch(&config) {
+                Rematch::Swap => human = human.opponent(),
+                Rematch::Same => (),
+                Rematch::Quit => break,
+            }
+            games_left = 1;
+        }
+    }
+}
+
+/// Answer of the human offered to play again.
+#[derive(Debug, PartialEq)]
+enum Rematch {
+    Swap,
+    Same,
+    Quit,
+}
+
+/// Offers the human to play again, with the colors swapped by default.
+fn ask_rematch(config: &Config) -> Rematch {
+    let messages = config.messages();
+    loop {
+        println!("{}", messages.ask_rematch);
+        match parse_rematch(&prompt()) {
+            Some(rematch) => return rematch,
+            None => println!("{}", messages.invalid_response),
+        }
+    }
+}
+
+fn parse_rematch(s: &str) -> Option<Rematch> {
+    match s.trim().to_lowercase().as_str() {
+        "" | "y" | "yes" | "o" | "oui" | "swap" => Some(Rematch::Swap),
+        "s" | "same" | "m" | "mêmes" | "memes" => Some(Rematch::Same),
+        "n" | "no" | "non" | "q" | "quit" => Some(Rematch::Quit),
+        _ => None,
+    }
+}
+
+/// Plays a game between the human, with the given color, and the computer,
+/// with clocks for the given time control. Returns the result, None if the
+/// game was stopped before its end.
+fn play_game(
+    human: Player,
+    computer: Box<dyn VirtualPlayer>,
+    config: &Config,
+    time_control: Option<TimeControl>,
+) -> Option<GameResult> {
+    let config = config.for_human(human);
+    let mut hints = config.hints;
+
+    // the moves of the human are read by the loop below, where the game can be
+    // changed (undo, redo), then given to the session through a channel.
+    let (moves, human_moves) = mpsc::channel();
+    let human_player = Box::new(HumanPlayer::new(move |_, _| {
+        human_moves.recv().ok().flatten()
+    })) as Box<dyn VirtualPlayer>;
+    let (black, white) = match human {
+        Player::Black => (human_player, computer),
+        Player::White => (computer, human_player),
+    };
+
+    let game = match time_control {
+        Some(control) => Game::new().with_clocks(control, control),
+        None => Game::new(),
+    };
+    let mut session = GameSession::new(game, black, white);
+    let listener_config = config.clone();
+    let messages = config.messages();
+    let mut ticker: Option<ClockTicker> = None;
+    // the board before the move, to explain its captures.
+    let mut before = *session.game().board();
+    session.add_listener(move |game, event| {
+        if let Some(ticker) = ticker.take() {
+            ticker.stop();
+        }
+        match event {
+            SessionEvent::Thinking { player } if *player != human => {
+                display_game_status(game, false, &listener_config);
+                match game_clocks(game) {
+                    Some(clocks) => {
+                        ticker = Some(ClockTicker::start(clocks, *player, &listener_config))
                     }
+                    None => println!("{}", messages.computer_thinking),
                 }
             }
-        } else {
-            display_game_status(&game);
-            println!("Computer is thinking...");
-            let (x, y) = computer
-                .compute_move(&game.board(), human.opponent())
-                .expect("The computer can't produce a move.");
-            game.play(human.opponent(), x, y).unwrap();
-            println!("Computer played at {}", readable_coordinates(x, y));
+            SessionEvent::Played { player, analysis } if *player != human => {
+                let (x, y) = analysis.position;
+                let coordinates = readable_coordinates(x, y);
+                println!("{}", fill(messages.computer_played, &[&coordinates]));
+                if analysis.score.is_some() {
+                    println!("{}", fill(messages.computer_analysis, &[analysis]));
+                }
+            }
+            SessionEvent::Resigned { player } if *player != human => {
+                println!("{}", messages.computer_resigns)
+            }
+            SessionEvent::GameOver { .. } => display_game_status(game, false, &listener_config),
+            _ => (),
+        }
+        if listener_config.teach {
+            match event {
+                SessionEvent::Thinking { .. } => before = *game.board(),
+                SessionEvent::Played { player, analysis } => {
+                    let (x, y) = analysis.position;
+                    if let Some(explanation) =
+                        explain_move(&before, *player, x, y, &listener_config)
+                    {
+                        println!("{}", explanation);
+                    }
+                }
+                _ => (),
+            }
+        }
+    });
+
+    loop {
+        let game = session.game();
+        // the session only asks the human for real moves.
+        if game.player() == Some(human) && !game.must_pass() && !game.placement_phase() {
+            display_game_status(game, hints, &config);
+            session.start_thinking();
+            let game = session.game();
+            let human_move = match get_move_from_player(game, &mut hints, &config) {
+                Choice::Move(square) => Some(square.coordinates()),
+                Choice::Undo => {
+                    if let Err(error) = session.takeback(human) {
+                        println!("{}", error);
+                    }
+                    continue;
+                }
+                Choice::Redo => {
+                    if let Err(error) = session.redo(human) {
+                        println!("{}", error);
+                    }
+                    continue;
+                }
+                Choice::Load(name) => {
+                    match load_game(&name) {
+                        Ok(game) => {
+                            session.replace_game(game);
+                            let path = game_path(&name);
+                            println!("{}", fill(messages.game_loaded, &[&path.display()]));
+                        }
+                        Err(error) => println!("{}", fill(messages.load_failed, &[&error])),
+                    }
+                    continue;
+                }
+                _ => None,
+            };
+            moves
+                .send(human_move)
+                .expect("Unexpected end of the session.");
+        }
+        if !session.play_turn() {
+            break;
         }
     }
-    display_game_status(&game);
+    session.game().result()
 }
 
-fn parge_args() -> (Player, u8) {
-    let args: Vec<String> = env::args().collect();
-    if args.len() != 3 {
+/// Plays a game between the two computers given (Black then White), each
+/// move being displayed after an optional delay in milliseconds.
+fn watch(args: &[String], config: &Config) {
+    if !(2..=3).contains(&args.len()) {
         print_usage_and_exit();
     }
+    let computer = |spec: &str| {
+        parse_computer(spec, config).unwrap_or_else(|error| {
+            println!("{}", error);
+            print_usage_and_exit();
+        })
+    };
+    let black = computer(&args[0]);
+    let white = computer(&args[1]);
+    let delay = match args.get(2).map(|delay| delay.parse()) {
+        Some(Ok(delay)) => Duration::from_millis(delay),
+        Some(Err(_)) => print_usage_and_exit(),
+        None => Duration::from_millis(DEFAULT_WATCH_DELAY),
+    };
+    let names = [black.name(), white.name()];
+    let name = move |player: Player| match player {
+        Player::Black => names[0].clone(),
+        Player::White => names[1].clone(),
+    };
+
+    let config = config.clone();
+    let mut session = GameSession::new(Game::new(), black, white);
+    session.add_listener(move |game, event| match event {
+        SessionEvent::Played { player, analysis } => {
+            thread::sleep(delay);
+            display_game_status(game, false, &config);
+            println!("{} ({}) played {}", player, name(*player), analysis);
+        }
+        SessionEvent::Passed { player } => println!("{} ({}) passes.", player, name(*player)),
+        SessionEvent::Resigned { player } => println!("{} ({}) resigns.", player, name(*player)),
+        SessionEvent::GameOver { result } => {
+            println!(
+                "{} ({}) - {} ({}) : {}.",
+                Player::Black,
+                name(Player::Black),
+                Player::White,
+                name(Player::White),
+                result
+            )
+        }
+        _ => (),
+    });
+    session.run();
+}
+
+#[cfg(feature = "tui")]
+fn run_tui(human: Player, computer: Box<dyn VirtualPlayer>, config: &Config) {
+    tui::run(human, computer, config);
+}
+
+#[cfg(not(feature = "tui"))]
+fn run_tui(_human: Player, _computer: Box<dyn VirtualPlayer>, _config: &Config) {
+    println!("The full-screen interface needs the `tui` feature.");
+    process::exit(1);
+}
+
+/// Removes the given flag from the arguments, returning if it was there.
+fn take_flag(args: &mut Vec<String>, flag: &str) -> bool {
+    let count = args.len();
+    args.retain(|arg| arg != flag);
+    args.len() != count
+}
+
+/// Removes the given option and its value from the arguments, returning the
+/// value.
+fn take_option(args: &mut Vec<String>, option: &str) -> Option<String> {
+    let index = args.iter().position(|arg| arg == option)?;
+    if index + 1 >= args.len() {
+        print_usage_and_exit();
+    }
+    let value = args.remove(index + 1);
+    args.remove(index);
+    Some(value)
+}
 
-    let player_str = args[1].trim().to_ascii_lowercase();
-    let player = match player_str.as_str() {
-        "black" => Player::Black,
-        "white" => Player::White,
+/// Returns the color of the human and the computer to play against, from
+/// the arguments or asked, the computer being checked. The computer may be
+/// omitted after the color when it's configured (ie by `--level`).
+fn parge_args(args: &[String], config: &mut Config) -> (Player, String) {
+    if args.len() == 1 {
+        return ask_settings(config);
+    }
+    let computer = match (args.len(), &config.computer) {
+        (3, _) => args[2].clone(),
+        (2, Some(computer)) => computer.clone(),
         _ => print_usage_and_exit(),
     };
 
-    match args[2].parse::<u8>() {
-        Ok(depth) => {
-            if depth < 4 || depth > 10 {
-                print_usage_and_exit();
-            }
-            return (player, depth);
-        }
-        Err(_) => {
+    let player = match parse_color(&args[1]) {
+        Some(player) => player,
+        None => print_usage_and_exit(),
+    };
+
+    match parse_computer(&computer, config) {
+        Ok(_) => (player, computer),
+        Err(error) => {
+            println!("{}", error);
             print_usage_and_exit();
         }
-    };
+    }
+}
+
+/// Asks the color of the human, the computer to play against and the name
+/// of the human, when the program is started without arguments and they
+/// aren't configured.
+fn ask_settings(config: &mut Config) -> (Player, String) {
+    if let Some(computer) = &config.computer {
+        if let Err(error) = parse_computer(computer, config) {
+            println!("Error : {} in the configuration.", error);
+            process::exit(1);
+        }
+    }
+    let messages = config.messages();
+    let player = config.color.unwrap_or_else(|| loop {
+        println!("{}", messages.ask_color);
+        match parse_color(&prompt()) {
+            Some(player) => break player,
+            None => println!("{}", messages.invalid_response),
+        }
+    });
+
+    let computer = config.computer.clone().unwrap_or_else(|| loop {
+        let names = PlayerFactory::standard().names().join(", ");
+        println!(
+            "{}",
+            fill(
+                messages.ask_computer,
+                &[&level_names(), &names, &DEFAULT_DEPTH]
+            )
+        );
+        let response = prompt();
+        let response = if response.is_empty() {
+            DEFAULT_DEPTH.to_string()
+        } else {
+            response
+        };
+        match parse_computer(&response, config) {
+            Ok(_) => break response,
+            Err(error) => println!("{}", fill(messages.try_again, &[&error])),
+        }
+    });
+
+    if config.name.is_none() {
+        println!("{}", messages.ask_name);
+        let name = prompt();
+        if !name.is_empty() {
+            config.name = Some(name);
+        }
+    }
+
+    (player, computer)
+}
+
+fn parse_color(s: &str) -> Option<Player> {
+    match s.trim().to_ascii_lowercase().as_str() {
+        "black" | "b" | "noir" | "n" => Some(Player::Black),
+        "white" | "w" | "blanc" => Some(Player::White),
+        _ => None,
+    }
+}
+
+fn level_names() -> String {
+    let names: Vec<&str> = Level::ALL.iter().map(|level| level.name()).collect();
+    names.join(", ")
+}
+
+/// Builds the computer from a difficulty level, a depth of the default
+/// engine, or the description of an engine of the standard factory. The
+/// levels come first, `beginner` being the level and not the engine. The
+/// default engine prints the progress of its searches, unless quiet.
+fn parse_computer(s: &str, config: &Config) -> Result<Box<dyn VirtualPlayer>, String> {
+    if let Some(level) = Level::by_name(s) {
+        return Ok(level.build());
+    }
+    match s.parse::<u8>() {
+        Ok(depth) if (4..=10).contains(&depth) => {
+            let mut computer = AlphaBeta::new(depth).with_resign_threshold(i32::MAX);
+            if !config.quiet {
+                let messages = config.messages();
+                computer = computer.with_progress(move |progress| {
+                    let line = fill(
+                        messages.search_progress,
+                        &[&progress.depth, &progress.nodes, &progress.best],
+                    );
+                    // printed above the running clocks of timed games.
+                    print_above_status(&line);
+                });
+            }
+            Ok(Box::new(computer))
+        }
+        Ok(depth) => Err(format!("Invalid depth : {}", depth)),
+        Err(_) => PlayerFactory::standard()
+            .build(s)
+            .map_err(|error| format!("Invalid engine : {}", error)),
+    }
 }
 
 fn print_usage_and_exit() -> ! {
-    println!("Usage : {} color depth", env::args().nth(0).unwrap());
-    println!("  color : 'black' or 'white'");
-    println!("  depth : 4 .. 10 (more than 8 could be slow)");
+    println!(
+        "Usage : {} [--tui] [--hints] [--eval] [--quiet] [--teach] [--theme theme] [--colors colors] [--lang lang] [--time s[+inc]] [--level level] [--name name] [--games n] [color [level|depth|engine]]",
+        env::args().next().unwrap()
+    );
+    println!("  color  : 'black' or 'white'");
+    println!(
+        "  level  : {} (random, greedy, shallow minimax, alphabeta, alphabeta with book and solver)",
+        level_names()
+    );
+    println!("  depth  : 4 .. 10 (more than 8 could be slow)");
+    println!(
+        "  engine : {} (ie 'minimax:5', 'alphabeta:2000ms' for a time per move, 'greedy')",
+        PlayerFactory::standard().names().join(", ")
+    );
+    println!("  --tui  : full-screen interface, if built with the `tui` feature");
+    println!("  --hints: show the legal moves on the board, H toggling them in game (--no-hints)");
+    println!("  --eval : show an evaluation bar after each move (--no-eval)");
+    println!("  --quiet: hide the progress of the searches of the computer");
+    println!(
+        "  --teach: explain the captures of each move, and why the invalid moves capture nothing"
+    );
+    println!(
+        "  theme  : 'classic', 'minimal', 'unicode' or 'colorblind', the characters of the board"
+    );
+    println!("  colors : 'green', 'blue' or 'gray', the colors of the full-screen board");
+    println!("  lang   : 'en' or 'fr', the language of the messages (from LANG by default)");
+    println!("  --level: the computer to play against, the color alone being then enough");
+    println!("  name   : the name of the human, asked with the other settings otherwise");
+    println!("  games  : the count of games to play in a row before offering a rematch");
+    println!("  --time : plays with clocks (not in the full-screen interface), the main time in seconds and the increment per move");
+    println!(
+        "Without arguments, the color and the computer are asked, unless set in {}.",
+        Config::path().map_or("~/.config/rusthello.toml".to_string(), |path| path
+            .display()
+            .to_string())
+    );
+    println!(
+        "Watching two computers : {} [options] --watch black_computer white_computer [delay_ms]",
+        env::args().next().unwrap()
+    );
+    println!("Commands :");
+    println!("  bench [depth] : measures the nodes per second of the search engines");
+    println!("  perft depth [position] : counts the leaves of the game tree, by move");
+    println!(
+        "  tournament [--games n] [--opening line] [--time s[+inc]] [--swiss rounds] [--adjudicate empties] engine engine... : plays a tournament"
+    );
+    println!("  analyze [--depth depth] transcript : annotates the mistakes of a game");
+    println!("  solve [--wld] [--player color] position|file : solves an endgame");
+    println!("  play [file] : plays the moves of the file (or stdin), without prompting");
+    println!(
+        "  replay [--depth depth] file|transcript : steps through a game, with the evaluations"
+    );
     process::exit(1);
 }
 
-fn display_game_status(game: &Game) {
+/// Displays the board and the score, with the legal moves of the player to
+/// move when hints are asked.
+fn display_game_status(game: &Game, hints: bool, config: &Config) {
     println!("------------------------------------------------------------");
-    println!("{}", board_to_ascii(game.board()));
+    let hinted = if hints { game.legal_moves() } else { &[] };
+    println!("{}", config.render_board(game.board(), hinted));
     let (black_pieces, white_pieces) = game.count_pieces();
-    println!("Black {} - {} White", black_pieces, white_pieces);
+    println!(
+        "{} {} - {} {}",
+        config.player_label(Player::Black),
+        black_pieces,
+        white_pieces,
+        config.player_label(Player::White)
+    );
+    let clocks = game_clocks(game);
+    if let Some(clocks) = &clocks {
+        println!("{}", clocks_line(clocks, None, config));
+    }
 
-    if game.game_over() {
-        println!("The game is over !");
-        if let Some(winner) = game.winner() {
-            println!("And the winner is : {}.", winner);
-        } else {
-            println!("The game ends in a draw.");
+    let messages = config.messages();
+    if config.eval {
+        if let Some(score) = evaluate_for_black(game) {
+            println!("{}", evaluation_bar(score, config));
         }
+    }
+    if let Some(result) = game.result() {
+        println!("{}", messages.game_over);
+        println!("{}", final_summary(game, &result, config));
         return;
     }
 
     let player = game.player().expect("Unexpected None player");
     if game.opponent_is_blocked() {
-        println!(
-            "The turn does not change as {} can't move.",
-            player.opponent()
+        let opponent = config.player_label(player.opponent());
+        println!("{}", fill(messages.opponent_blocked, &[&opponent]));
+    }
+
+    println!(
+        "{}",
+        fill(messages.turn_of, &[&config.player_label(player)])
+    );
+    if let Some(clock) = game.clock(player) {
+        if is_low(clock, clock.remaining()) {
+            let remaining = format_remaining(clock.remaining());
+            println!("{}", fill(messages.low_time, &[&remaining]));
+        }
+    }
+}
+
+/// Describes the end of the game : the official score, the empty squares
+/// going to the winner (or being shared for a draw), then the winner with its
+/// margin and the reason why the game ended.
+fn final_summary(game: &Game, result: &GameResult, config: &Config) -> String {
+    let messages = config.messages();
+    let mut lines = Vec::new();
+    if let Some((black, white)) = result.score() {
+        let mut score = format!(
+            "{} {} - {} {}",
+            config.player_label(Player::Black),
+            black,
+            white,
+            config.player_label(Player::White)
         );
+        let empties = game.board().empties();
+        if let GameResult::BothBlocked { .. } = result {
+            let awarded = match result.winner() {
+                Some(_) => messages.empties_to_winner,
+                None => messages.empties_shared,
+            };
+            score = format!("{}, {}", score, fill(awarded, &[&empties]));
+        }
+        lines.push(fill(messages.final_score, &[&score]));
+    }
+    let reason = match *result {
+        GameResult::BothBlocked { .. } => messages.ended_blocked.to_string(),
+        GameResult::BoardFull { .. } => messages.ended_board_full.to_string(),
+        GameResult::Resignation { player } => {
+            fill(messages.ended_resignation, &[&config.player_label(player)])
+        }
+        GameResult::Timeout { player } => {
+            fill(messages.ended_timeout, &[&config.player_label(player)])
+        }
+        GameResult::Adjudicated { .. } => messages.ended_adjudicated.to_string(),
+        GameResult::FewestPieces { .. } => messages.ended_fewest_pieces.to_string(),
+    };
+    lines.push(match (result.winner(), result.margin()) {
+        (Some(winner), Some(margin)) => fill(
+            messages.wins_by,
+            &[&config.player_label(winner), &margin, &reason],
+        ),
+        (Some(winner), None) => fill(messages.wins, &[&config.player_label(winner), &reason]),
+        (None, _) => fill(messages.draw, &[&reason]),
+    });
+    lines.join("\n")
+}
+
+/// Returns the clocks of Black and White, None in untimed games.
+fn game_clocks(game: &Game) -> Option<[Clock; 2]> {
+    Some([*game.clock(Player::Black)?, *game.clock(Player::White)?])
+}
+
+/// Draws the remaining times of the clocks, the clocks running low being
+/// marked, ie `Black 4:59 - 0:08.4 ! White`. The player thinking for the
+/// given time has it taken from its clock.
+fn clocks_line(
+    clocks: &[Clock; 2],
+    thinking: Option<(Player, Duration)>,
+    config: &Config,
+) -> String {
+    let time = |player: Player, clock: &Clock| {
+        let spent = match thinking {
+            Some((thinking, spent)) if thinking == player => spent,
+            _ => Duration::ZERO,
+        };
+        let remaining = clock.remaining().saturating_sub(spent);
+        if is_low(clock, remaining) {
+            format!("{} !", format_remaining(remaining))
+        } else {
+            format_remaining(remaining)
+        }
+    };
+    let messages = config.messages();
+    format!(
+        "{} {} - {} {}",
+        messages.black,
+        time(Player::Black, &clocks[0]),
+        time(Player::White, &clocks[1]),
+        messages.white
+    )
+}
+
+fn is_low(clock: &Clock, remaining: Duration) -> bool {
+    remaining * LOW_TIME_DIVISOR <= clock.control().main_time()
+}
+
+/// Formats a remaining time in minutes and seconds, with the tenths of
+/// seconds under ten seconds (ie `4:59` or `0:08.4`).
+fn format_remaining(remaining: Duration) -> String {
+    let seconds = remaining.as_secs();
+    if seconds < 10 {
+        let tenths = remaining.subsec_millis() / 100;
+        format!("0:{:02}.{}", seconds, tenths)
+    } else {
+        format!("{}:{:02}", seconds / 60, seconds % 60)
+    }
+}
+
+/// Refreshes the status line with the running clocks while the computer
+/// thinks, until stopped.
+struct ClockTicker {
+    running: Arc<AtomicBool>,
+    thread: JoinHandle<()>,
+}
+
+impl ClockTicker {
+    fn start(clocks: [Clock; 2], player: Player, config: &Config) -> ClockTicker {
+        let running = Arc::new(AtomicBool::new(true));
+        let config = config.clone();
+        let thread = {
+            let running = Arc::clone(&running);
+            thread::spawn(move || {
+                let start = Instant::now();
+                let thinking = config.messages().computer_thinking;
+                while running.load(Ordering::Relaxed) {
+                    let line = clocks_line(&clocks, Some((player, start.elapsed())), &config);
+                    set_status(&format!("{} {}", thinking, line));
+                    thread::sleep(CLOCK_REFRESH_DELAY);
+                }
+            })
+        };
+        ClockTicker { running, thread }
+    }
+
+    /// Stops the refresh, the status line being ended.
+    fn stop(self) {
+        self.running.store(false, Ordering::Relaxed);
+        self.thread
+            .join()
+            .expect("Unexpected end of the clocks thread.");
+        end_status();
     }
+}
+
+/// The status line rewritten in place at the bottom of the terminal (ie the
+/// running clocks), empty when there is none. The lines printed while it's
+/// shown go through `print_above_status`, the terminal being written by a
+/// single thread at a time.
+static STATUS_LINE: Mutex<String> = Mutex::new(String::new());
+
+/// Replaces the status line by the given one.
+fn set_status(line: &str) {
+    let mut status = STATUS_LINE.lock().unwrap();
+    let mut stdout = io::stdout().lock();
+    // the spaces erase the end of a longer previous line.
+    let width = status.chars().count();
+    write!(stdout, "\r{:<width$}", line, width = width).unwrap();
+    stdout.flush().unwrap();
+    *status = line.to_string();
+}
 
-    println!("It's the turn of {}.", player);
+/// Prints the given line, the status line being erased and drawn again
+/// below it.
+fn print_above_status(line: &str) {
+    let status = STATUS_LINE.lock().unwrap();
+    let mut stdout = io::stdout().lock();
+    let width = status.chars().count();
+    writeln!(stdout, "\r{:<width$}", line, width = width).unwrap();
+    write!(stdout, "{}", status).unwrap();
+    stdout.flush().unwrap();
+}
+
+/// Ends the status line, which stays as it was last drawn.
+fn end_status() {
+    let mut status = STATUS_LINE.lock().unwrap();
+    if !status.is_empty() {
+        println!();
+        status.clear();
+    }
+}
+
+/// Evaluates the position with a quick search, positive scores being good
+/// for Black. None when the game is over or during the placement phase.
+fn evaluate_for_black(game: &Game) -> Option<i32> {
+    let player = game.player()?;
+    if game.placement_phase() {
+        return None;
+    }
+    let analysis = AlphaBeta::new(EVALUATION_DEPTH).compute_move_full(game.board(), player)?;
+    let score = analysis.score?;
+    Some(match player {
+        Player::Black => score,
+        Player::White => -score,
+    })
+}
+
+/// Draws the evaluation as a bar filled by the discs of Black from the left
+/// and by the ones of White from the right, followed by the score (`∞` for a
+/// proven result), ie `Black [XXXXXXXXXXXXOOOOOOOO] White +12`.
+fn evaluation_bar(score: i32, config: &Config) -> String {
+    let black_cells = (score.clamp(-EVALUATION_SCALE, EVALUATION_SCALE) + EVALUATION_SCALE)
+        * EVALUATION_BAR_WIDTH
+        / (2 * EVALUATION_SCALE);
+    let theme = &config.theme;
+    let bar: String = (0..EVALUATION_BAR_WIDTH)
+        .map(|cell| {
+            if cell < black_cells {
+                theme.black
+            } else {
+                theme.white
+            }
+        })
+        .collect();
+    let score = match score {
+        i32::MAX => "+∞".to_string(),
+        score if score == -i32::MAX => "-∞".to_string(),
+        score => format!("{:+}", score),
+    };
+    let messages = config.messages();
+    format!("{} [{}] {} {}", messages.black, bar, messages.white, score)
 }
 
 fn readable_coordinates(x: u8, y: u8) -> String {
@@ -108,58 +868,232 @@ fn readable_coordinates(x: u8, y: u8) -> String {
     format!("({}, {})", letter, digit)
 }
 
-fn get_choice_from_player(game: &Game) -> Choice {
-    let mut choice: Option<Choice> = None;
-    let mut bad_response = false;
-    while choice.is_none() || bad_response {
-        display_game_status(&game);
-        if bad_response {
-            println!("Previous response was invalid, let try again.")
+/// Explains a move on the board before it, to teach the rules : the
+/// captures of a valid move, or the outcome of each direction for a move
+/// capturing nothing. None for a move on an occupied square.
+fn explain_move(board: &Board, player: Player, x: u8, y: u8, config: &Config) -> Option<String> {
+    let messages = config.messages();
+    let explanations = board.explain_move(player, x, y).ok()?;
+    let captures: Vec<&LineExplanation> = explanations
+        .iter()
+        .filter(|explanation| matches!(explanation.outcome, LineOutcome::Captures { .. }))
+        .collect();
+    let (header, explained) = if captures.is_empty() {
+        (messages.teach_no_capture, explanations.iter().collect())
+    } else {
+        (messages.teach_captures, captures)
+    };
+    let mut lines = vec![fill(header, &[&Move::Place { x, y }])];
+    lines.extend(
+        explained
+            .into_iter()
+            .map(|explanation| explain_line(explanation, config)),
+    );
+    Some(lines.join("\n"))
+}
+
+fn explain_line(explanation: &LineExplanation, config: &Config) -> String {
+    let messages = config.messages();
+    let index = match explanation.direction {
+        (0, -1) => 0,
+        (1, -1) => 1,
+        (1, 0) => 2,
+        (1, 1) => 3,
+        (0, 1) => 4,
+        (-1, 1) => 5,
+        (-1, 0) => 6,
+        _ => 7,
+    };
+    let direction = messages.directions[index];
+    let square = |&(x, y): &(u8, u8)| Move::Place { x, y }.to_string();
+    let squares = |pieces: &[(u8, u8)]| pieces.iter().map(square).collect::<Vec<_>>().join(" ");
+    match &explanation.outcome {
+        LineOutcome::Captures { pieces, closed_by } => fill(
+            messages.teach_captured,
+            &[&direction, &squares(pieces), &square(closed_by)],
+        ),
+        LineOutcome::Edge => fill(messages.teach_edge, &[&direction]),
+        LineOutcome::Empty => fill(messages.teach_empty, &[&direction]),
+        LineOutcome::OwnPiece { position } => {
+            fill(messages.teach_own_piece, &[&direction, &square(position)])
+        }
+        LineOutcome::NotClosed { pieces } => {
+            fill(messages.teach_not_closed, &[&direction, &squares(pieces)])
         }
-        choice = read_choice();
-        bad_response = choice.is_none();
     }
-
-    choice.unwrap()
 }
 
-fn read_choice() -> Option<Choice> {
-    println!("What's you're move ? (ex : A1 ou Q to quit)");
-    print!("> ");
-    io::stdout().flush().unwrap();
-    let response = read_string();
-
-    parse_response(response)
+/// Reads the choices of the human until it plays a valid move, resigns, or
+/// wants to undo or redo its moves, the other choices being handled here.
+fn get_move_from_player(game: &Game, hints: &mut bool, config: &Config) -> Choice {
+    let board = game.board();
+    let player = game.player().expect("Unexpected None player");
+    let messages = config.messages();
+    loop {
+        match read_choice(config) {
+            Some(Choice::Quit) => process::exit(0),
+            Some(Choice::Help) => println!("{}", messages.help),
+            Some(Choice::Moves) if game.history().is_empty() => {
+                println!("{}", messages.no_move_played)
+            }
+            Some(Choice::Moves) => {
+                let transcript = game.to_transcript();
+                println!(
+                    "{}",
+                    fill(messages.game_record, &[&game_record(game), &transcript])
+                );
+            }
+            Some(Choice::LegalMoves) => {
+                let moves: Vec<String> = game
+                    .legal_moves()
+                    .iter()
+                    .map(|&(x, y)| Move::Place { x, y }.to_string())
+                    .collect();
+                println!("{}", fill(messages.legal_moves, &[&moves.join(" ")]));
+            }
+            // the human is only asked to move when it can.
+            Some(Choice::Pass) => println!("{}", messages.cannot_pass),
+            Some(Choice::ToggleHints) => {
+                *hints = !*hints;
+                let hinted = if *hints { game.legal_moves() } else { &[] };
+                println!("{}", config.render_board(board, hinted));
+            }
+            Some(Choice::Hint) => match AlphaBeta::new(HINT_DEPTH).compute_move_full(board, player)
+            {
+                Some(analysis) => println!("{}", fill(messages.hint, &[&analysis])),
+                None => println!("{}", messages.no_hint),
+            },
+            Some(Choice::Save(name)) => match save_game(game, &name) {
+                Ok(()) => {
+                    let path = game_path(&name);
+                    println!("{}", fill(messages.game_saved, &[&path.display()]));
+                }
+                Err(error) => println!("{}", fill(messages.save_failed, &[&error])),
+            },
+            Some(Choice::Move(square)) => {
+                let (x, y) = square.coordinates();
+                match board.check_move(player, x, y) {
+                    Ok(()) => return Choice::Move(square),
+                    Err(error) => {
+                        println!("{}", fill(messages.invalid_move, &[&error]));
+                        if config.teach {
+                            if let Some(explanation) = explain_move(board, player, x, y, config) {
+                                println!("{}", explanation);
+                            }
+                        }
+                    }
+                }
+            }
+            Some(choice) => return choice,
+            None => println!("{}", messages.invalid_response),
+        }
+    }
 }
 
-fn parse_response(s: String) -> Option<Choice> {
-    let s = s.to_uppercase();
-    if s == "Q" {
-        return Some(Choice::Quit);
+/// Numbers the moves of the game by pairs, Black then White, one pair per
+/// line (ie `  1. F5 D6`), the passes being written.
+fn game_record(game: &Game) -> String {
+    let history = game.history();
+    let mut moves: Vec<String> = Vec::new();
+    if history.first().map(|entry| entry.player) == Some(Player::White) {
+        moves.push("...".to_string());
     }
+    moves.extend(history.iter().map(|entry| entry.played.to_string()));
+    moves
+        .chunks(2)
+        .enumerate()
+        .map(|(index, pair)| format!("{:>3}. {}", index + 1, pair.join(" ")))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
 
-    if s.len() != 2 {
-        return None;
+/// Returns the file of a saved game, the `.json` extension being added to
+/// names without extension.
+fn game_path(name: &str) -> PathBuf {
+    let mut path = PathBuf::from(name);
+    if path.extension().is_none() {
+        path.set_extension("json");
     }
-    let mut s_chars = s.chars();
-    let x = s_chars.next().unwrap() as i8 - 65; // 'A' = 65
-    let y = s_chars.next().unwrap() as i8 - 49; // '1' = 49
+    path
+}
 
-    if x < 0 || x > 7 || y < 0 || y > 7 {
-        return None;
-    }
+#[cfg(feature = "serde")]
+fn save_game(game: &Game, name: &str) -> Result<(), String> {
+    game.save(game_path(name))
+}
 
-    Some(Choice::Move {
-        x: x as u8,
-        y: y as u8,
-    })
+#[cfg(feature = "serde")]
+fn load_game(name: &str) -> Result<Game, String> {
+    Game::load(game_path(name))
+}
+
+#[cfg(not(feature = "serde"))]
+fn save_game(_game: &Game, _name: &str) -> Result<(), String> {
+    Err("saving games needs the `serde` feature".to_string())
+}
+
+#[cfg(not(feature = "serde"))]
+fn load_game(_name: &str) -> Result<Game, String> {
+    Err("loading games needs the `serde` feature".to_string())
+}
+
+fn read_choice(config: &Config) -> Option<Choice> {
+    println!("{}", config.messages().ask_move);
+    parse_response(prompt())
+}
+
+fn prompt() -> String {
+    print!("> ");
+    io::stdout().flush().unwrap();
+    read_string()
+}
+
+/// Parses a response of the human, ignoring case : a command, or a move given
+/// by its column and row in any order (ie `F5`, `f5` or `5f`).
+fn parse_response(response: String) -> Option<Choice> {
+    let response = response.trim();
+    let s = response.to_lowercase();
+    let choice = match s.as_str() {
+        "q" | "quit" | "exit" => Choice::Quit,
+        "r" | "resign" => Choice::Resign,
+        "help" | "?" => Choice::Help,
+        "h" => Choice::ToggleHints,
+        "hint" => Choice::Hint,
+        "moves" => Choice::Moves,
+        "legal" => Choice::LegalMoves,
+        "pass" => Choice::Pass,
+        "undo" => Choice::Undo,
+        "redo" => Choice::Redo,
+        _ => {
+            if let Some((command, name)) = response.split_once(char::is_whitespace) {
+                let name = name.trim().to_string();
+                match command.to_lowercase().as_str() {
+                    "save" => return Some(Choice::Save(name)),
+                    "load" => return Some(Choice::Load(name)),
+                    _ => return None,
+                }
+            }
+            let square = Square::from_notation(&s).or_else(|error| {
+                // the row first, as in `5f`.
+                let column_at = s.find(|c: char| !c.is_ascii_digit()).ok_or(error)?;
+                let (row, column) = s.split_at(column_at);
+                Square::from_notation(&format!("{}{}", column, row))
+            });
+            return square.ok().map(Choice::Move);
+        }
+    };
+    Some(choice)
 }
 
 fn read_string() -> String {
     let mut s = String::new();
-    io::stdin()
+    let read = io::stdin()
         .read_line(&mut s)
         .expect("Unable to read user input.");
+    // the input is closed, as if the human quit.
+    if read == 0 {
+        process::exit(0);
+    }
     trim_newline(&mut s);
 
     s.trim().to_string()
@@ -170,3 +1104,142 @@ fn trim_newline(s: &mut String) {
         s.pop();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn english_config() -> Config {
+        Config {
+            language: Some(Language::English),
+            ..Config::default()
+        }
+    }
+
+    fn parse(s: &str) -> Option<Choice> {
+        parse_response(s.to_string())
+    }
+
+    #[test]
+    fn parse_response_reads_moves_in_any_order_and_case() {
+        let f5 = Some(Choice::Move(Square::new(5, 4).unwrap()));
+        for response in ["F5", "f5", "5f", "5F", " f5 "] {
+            assert_eq!(parse(response), f5, "{}", response);
+        }
+        for response in ["", "f", "55", "f5f", "0f", "Z9"] {
+            assert_eq!(parse(response), None, "{}", response);
+        }
+    }
+
+    #[test]
+    fn parse_response_reads_the_commands() {
+        assert_eq!(parse("QUIT"), Some(Choice::Quit));
+        assert_eq!(parse("q"), Some(Choice::Quit));
+        assert_eq!(parse("Resign"), Some(Choice::Resign));
+        assert_eq!(parse("help"), Some(Choice::Help));
+        assert_eq!(parse("h"), Some(Choice::ToggleHints));
+        assert_eq!(parse("hint"), Some(Choice::Hint));
+        assert_eq!(parse("moves"), Some(Choice::Moves));
+        assert_eq!(parse("Legal"), Some(Choice::LegalMoves));
+        assert_eq!(parse("pass"), Some(Choice::Pass));
+        assert_eq!(
+            parse("SAVE My Game"),
+            Some(Choice::Save("My Game".to_string()))
+        );
+        assert_eq!(parse("unknown command"), None);
+    }
+
+    #[test]
+    fn parse_rematch_swaps_the_colors_by_default() {
+        assert_eq!(parse_rematch(""), Some(Rematch::Swap));
+        assert_eq!(parse_rematch("Oui"), Some(Rematch::Swap));
+        assert_eq!(parse_rematch("same"), Some(Rematch::Same));
+        assert_eq!(parse_rematch("N"), Some(Rematch::Quit));
+        assert_eq!(parse_rematch("maybe"), None);
+    }
+
+    #[test]
+    fn evaluation_bar_is_filled_by_the_leading_side() {
+        let config = english_config();
+        assert_eq!(
+            evaluation_bar(0, &config),
+            "Black [XXXXXXXXXXOOOOOOOOOO] White +0"
+        );
+        assert_eq!(
+            evaluation_bar(-25, &config),
+            "Black [XXXXXOOOOOOOOOOOOOOO] White -25"
+        );
+        assert_eq!(
+            evaluation_bar(i32::MAX, &config),
+            "Black [XXXXXXXXXXXXXXXXXXXX] White +∞"
+        );
+    }
+
+    #[test]
+    fn clocks_line_marks_the_low_clocks() {
+        let control = TimeControl::new(Duration::from_secs(300), Duration::ZERO);
+        let clocks = [Clock::new(control), Clock::new(control)];
+        let config = english_config();
+        assert_eq!(
+            clocks_line(&clocks, None, &config),
+            "Black 5:00 - 5:00 White"
+        );
+        let thinking = Some((Player::White, Duration::from_millis(291_600)));
+        assert_eq!(
+            clocks_line(&clocks, thinking, &config),
+            "Black 5:00 - 0:08.4 ! White"
+        );
+    }
+
+    #[test]
+    fn game_record_numbers_the_moves_by_pairs() {
+        let game = Game::from_transcript("F5D6C3").unwrap();
+        assert_eq!(game_record(&game), "  1. F5 D6\n  2. C3");
+        assert_eq!(game_record(&Game::new()), "");
+    }
+
+    #[test]
+    fn final_summary_gives_the_official_score() {
+        let config = english_config();
+        // the shortest game, White being wiped out with 51 empty squares.
+        let game = Game::from_transcript("F5D6C5F4E7F6G5E6E3").unwrap();
+        assert_eq!(game.count_pieces(), (13, 0));
+        let result = game.result().unwrap();
+        assert_eq!(
+            result,
+            GameResult::BothBlocked {
+                black: 64,
+                white: 0
+            }
+        );
+        assert_eq!(
+            final_summary(&game, &result, &config),
+            "Final score : Black 64 - 0 White, 51 empty squares awarded to the winner
+\
+             Black wins by 64 : none of the players can move."
+        );
+        let mut game = Game::new();
+        game.resign(Player::White).unwrap();
+        let result = game.result().unwrap();
+        assert_eq!(
+            final_summary(&game, &result, &config),
+            "Black wins : White resigned."
+        );
+    }
+
+    #[test]
+    fn explain_move_tells_the_captures() {
+        let config = english_config();
+        let board = Board::new_start();
+        assert_eq!(
+            explain_move(&board, Player::Black, 5, 4, &config).unwrap(),
+            "F5 captures :\n  towards the west : E5, closed by D5"
+        );
+        let rejected = explain_move(&board, Player::Black, 5, 5, &config).unwrap();
+        assert!(
+            rejected.starts_with("F6 captures nothing :\n  towards the north : an empty square")
+        );
+        assert!(rejected.contains("towards the north-west : E5 D4, not closed"));
+        assert_eq!(explain_move(&board, Player::Black, 3, 3, &config), None);
+    }
+}
@@ -1,8 +1,97 @@
+#[cfg(feature = "std")]
+mod bench;
+mod beginner_player;
+mod bitboard;
 mod board;
+mod endgame_solver;
+#[cfg(feature = "std")]
+mod engine_protocol;
+#[cfg(feature = "std")]
+mod external_player;
 mod game;
+mod game_analysis;
+mod game_builder;
+mod game_observer;
+mod game_result;
+#[cfg(feature = "std")]
+mod game_session;
+mod game_statistics;
 mod game_status;
+mod greedy_player;
+#[cfg(feature = "std")]
+mod human_player;
+#[cfg(feature = "std")]
+mod level;
+mod moves;
+#[cfg(feature = "std")]
+mod network_player;
+#[cfg(feature = "std")]
+mod opening_book;
+mod perft;
+#[cfg(feature = "std")]
+mod pipeline_player;
+#[cfg(feature = "std")]
+mod player_factory;
+mod position;
+mod random;
+mod random_player;
+mod rules;
+#[cfg(feature = "std")]
+mod sprt;
+mod square;
+mod time_control;
+mod timed_player;
+#[cfg(feature = "std")]
+mod tournament;
 mod virtual_player;
+#[cfg(feature = "std")]
+mod xot;
 
+#[cfg(feature = "std")]
+pub use self::bench::*;
+pub use self::beginner_player::*;
+pub use self::bitboard::*;
 pub use self::board::*;
+pub use self::endgame_solver::*;
+#[cfg(feature = "std")]
+pub use self::engine_protocol::*;
+#[cfg(feature = "std")]
+pub use self::external_player::*;
 pub use self::game::*;
+pub use self::game_analysis::*;
+pub use self::game_builder::*;
+pub use self::game_observer::*;
+pub use self::game_result::*;
+#[cfg(feature = "std")]
+pub use self::game_session::*;
+pub use self::game_statistics::*;
+pub use self::game_status::*;
+pub use self::greedy_player::*;
+#[cfg(feature = "std")]
+pub use self::human_player::*;
+#[cfg(feature = "std")]
+pub use self::level::*;
+pub use self::moves::*;
+#[cfg(feature = "std")]
+pub use self::network_player::*;
+#[cfg(feature = "std")]
+pub use self::opening_book::*;
+pub use self::perft::*;
+#[cfg(feature = "std")]
+pub use self::pipeline_player::*;
+#[cfg(feature = "std")]
+pub use self::player_factory::*;
+pub use self::position::*;
+pub use self::random::*;
+pub use self::random_player::*;
+pub use self::rules::*;
+#[cfg(feature = "std")]
+pub use self::sprt::*;
+pub use self::square::*;
+pub use self::time_control::*;
+pub use self::timed_player::*;
+#[cfg(feature = "std")]
+pub use self::tournament::*;
 pub use self::virtual_player::*;
+#[cfg(feature = "std")]
+pub use self::xot::*;
@@ -0,0 +1,158 @@
+use alloc::string::{String, ToString};
+use core::cell::Cell;
+
+use super::board::*;
+use super::square::*;
+use super::virtual_player::*;
+
+/// A lightweight virtual player looking only one move ahead : it takes
+/// corners, avoids the cells next to a corner (X and C cells) while this
+/// corner is empty, and otherwise maximizes its mobility against the opponent
+/// one.
+/// It's fast and weak, suited to the easiest difficulty level.
+pub struct BeginnerPlayer {
+    move_count: Cell<u32>,
+}
+
+impl BeginnerPlayer {
+    // Scores according to the move position.
+    const SCORE_CORNER: i32 = 100;
+    const SCORE_X_CELL: i32 = -50;
+    const SCORE_C_CELL: i32 = -20;
+
+    /// Creates a new BeginnerPlayer.
+    pub fn new() -> Self {
+        Self {
+            move_count: Cell::new(0),
+        }
+    }
+
+    /// Scores a valid move, greater is better for the player.
+    fn score_move(board_after_move: &Board, me: Player, x: u8, y: u8) -> i32 {
+        let position_score = Self::position_score(board_after_move, x, y);
+        let mobility = Self::mobility(board_after_move, me) as i32
+            - Self::mobility(board_after_move, me.opponent()) as i32;
+
+        position_score + mobility
+    }
+
+    /// Scores the position of a move. The X and C cells are penalized only
+    /// while their corner is empty, as they can't give it to the opponent
+    /// once it's taken.
+    fn position_score(board_after_move: &Board, x: u8, y: u8) -> i32 {
+        let square = Square::<8>::new(x, y).expect("Unexpected move out of the board.");
+        let corner_is_empty = || Self::nearest_corner_is_empty(board_after_move, x, y);
+        match square.kind() {
+            SquareKind::Corner => Self::SCORE_CORNER,
+            SquareKind::XSquare if corner_is_empty() => Self::SCORE_X_CELL,
+            SquareKind::CSquare if corner_is_empty() => Self::SCORE_C_CELL,
+            _ => 0,
+        }
+    }
+
+    /// Checks if the corner closest to the given square is empty, the move
+    /// on an X or C cell leaving its corner as it was.
+    fn nearest_corner_is_empty(board: &Board, x: u8, y: u8) -> bool {
+        let last = board.size() - 1;
+        let nearest = |coordinate| {
+            if coordinate < board.size() / 2 {
+                0
+            } else {
+                last
+            }
+        };
+        board.get_piece(nearest(x), nearest(y)) == Ok(None)
+    }
+
+    fn mobility(board: &Board, player: Player) -> usize {
+        board.legal_moves_iter(player).count()
+    }
+}
+
+impl Default for BeginnerPlayer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EngineInfo for BeginnerPlayer {
+    fn name(&self) -> String {
+        "Beginner".to_string()
+    }
+}
+
+impl VirtualPlayer for BeginnerPlayer {
+    fn move_count(&self) -> u32 {
+        self.move_count.get()
+    }
+
+    fn compute_move(&self, board: &Board, me: Player) -> Option<(u8, u8)> {
+        let mut best_move: Option<((u8, u8), i32)> = None;
+        for (x, y) in GridIterator::new() {
+            let opt_board_after_move = board
+                .play(me, x, y)
+                .expect("Unexpected error while computing move.");
+            if let Some(board_after_move) = opt_board_after_move {
+                self.move_count.set(self.move_count.get() + 1);
+                let score = Self::score_move(&board_after_move, me, x, y);
+                match best_move {
+                    Some((_, best_score)) if best_score >= score => (),
+                    _ => best_move = Some(((x, y), score)),
+                }
+            }
+        }
+
+        best_move.map(|(position, _)| position)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn beginner_player_takes_corners() {
+        // Black can play at (0, 0), or flip more pieces at (4, 1).
+        let mut board = Board::new();
+        board.set_piece(1, 1, Some(Player::White)).unwrap();
+        board.set_piece(2, 2, Some(Player::Black)).unwrap();
+        board.set_piece(4, 2, Some(Player::White)).unwrap();
+        board.set_piece(4, 3, Some(Player::White)).unwrap();
+        board.set_piece(4, 4, Some(Player::Black)).unwrap();
+        let player = BeginnerPlayer::new();
+        assert_eq!(player.compute_move(&board, Player::Black), Some((0, 0)));
+    }
+
+    #[test]
+    fn beginner_player_avoids_x_cells() {
+        // Black can play at (1, 1) next to an empty corner, or at (5, 2).
+        let mut board = Board::new();
+        board.set_piece(2, 2, Some(Player::White)).unwrap();
+        board.set_piece(3, 3, Some(Player::Black)).unwrap();
+        board.set_piece(4, 2, Some(Player::White)).unwrap();
+        board.set_piece(3, 2, Some(Player::Black)).unwrap();
+        let player = BeginnerPlayer::new();
+        let (x, y) = player.compute_move(&board, Player::Black).unwrap();
+        assert_ne!((x, y), (1, 1));
+    }
+
+    #[test]
+    fn x_and_c_cells_are_penalized_only_next_to_empty_corners() {
+        let mut board = Board::new_start();
+        assert!(BeginnerPlayer::position_score(&board, 6, 6) < 0);
+        assert!(BeginnerPlayer::position_score(&board, 7, 6) < 0);
+        assert!(BeginnerPlayer::position_score(&board, 1, 0) < 0);
+        board.set_piece(7, 7, Some(Player::White)).unwrap();
+        assert_eq!(BeginnerPlayer::position_score(&board, 6, 6), 0);
+        assert_eq!(BeginnerPlayer::position_score(&board, 7, 6), 0);
+        assert!(BeginnerPlayer::position_score(&board, 1, 0) < 0);
+    }
+
+    #[test]
+    fn beginner_player_returns_none_if_it_cant_move() {
+        let mut board = Board::new();
+        board.set_piece(0, 0, Some(Player::Black)).unwrap();
+        let player = BeginnerPlayer::new();
+        assert_eq!(player.compute_move(&board, Player::White), None);
+    }
+}
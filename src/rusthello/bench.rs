@@ -0,0 +1,84 @@
+use std::time::{Duration, Instant};
+
+use super::game::*;
+use super::virtual_player::*;
+
+/// Positions searched by `bench`, given as transcripts from the start of a
+/// game : openings, middle games and an endgame.
+pub const BENCH_TRANSCRIPTS: [&str; 6] = [
+    "",
+    "D3C3B3E3F3E2F6C5",
+    "D3C3B3E3F3E2F6C5E1F4B6C4G3D2D1C2",
+    "D3C3B3E3F3E2F6C5E1F4B6C4G3D2D1C2C1E6F5G4H4F2F1H3",
+    "D3C3B3E3F3E2F6C5E1F4B6C4G3D2D1C2C1E6F5G4H4F2F1H3H2B4A4B5D6D7E7F7F8G5C6A5",
+    "D3C3B3E3F3E2F6C5E1F4B6C4G3D2D1C2C1E6F5G4H4F2F1H3H2B4A4B5D6D7E7F7F8G5C6A5A6E8D8A3A2G6H5H6H7C8B8G8",
+];
+
+/// Search of one bench position by an engine.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BenchResult {
+    /// The moves explored by the engine, see `VirtualPlayer::move_count`.
+    pub nodes: u64,
+    pub elapsed: Duration,
+}
+
+impl BenchResult {
+    /// Returns the count of nodes explored per second.
+    pub fn nodes_per_second(&self) -> f64 {
+        let seconds = self.elapsed.as_secs_f64();
+        if seconds > 0.0 {
+            self.nodes as f64 / seconds
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Runs the given engine on each bench position (see `BENCH_TRANSCRIPTS`)
+/// and measures its searches, to follow the performances of the engines.
+/// The results are given in the order of the positions.
+pub fn bench(engine: &dyn VirtualPlayer) -> Vec<BenchResult> {
+    BENCH_TRANSCRIPTS
+        .iter()
+        .map(|transcript| {
+            let game =
+                Game::from_transcript(transcript).expect("Unexpected invalid bench transcript.");
+            let player = game
+                .player()
+                .expect("Unexpected finished game among the bench positions.");
+            let nodes_before = engine.move_count();
+            let start = Instant::now();
+            engine.compute_move(game.board(), player);
+            BenchResult {
+                nodes: engine.move_count().wrapping_sub(nodes_before) as u64,
+                elapsed: start.elapsed(),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bench_searches_each_position() {
+        let results = bench(&AlphaBeta::new(3));
+        assert_eq!(results.len(), BENCH_TRANSCRIPTS.len());
+        assert!(results.iter().all(|result| result.nodes > 0));
+    }
+
+    #[test]
+    fn nodes_per_second_handle_instant_searches() {
+        let result = BenchResult {
+            nodes: 1500,
+            elapsed: Duration::from_millis(500),
+        };
+        assert_eq!(result.nodes_per_second(), 3000.0);
+        let instant = BenchResult {
+            nodes: 1,
+            elapsed: Duration::ZERO,
+        };
+        assert_eq!(instant.nodes_per_second(), 0.0);
+    }
+}
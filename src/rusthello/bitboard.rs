@@ -0,0 +1,316 @@
+use alloc::vec::Vec;
+
+use super::board::*;
+
+/// A compact Othello board using one bit per cell for each player, the cell
+/// (x, y) being the bit x + 8 * y. Moves are generated and played with shifts
+/// and masks on all cells at once, which is much faster than `Board`. It's
+/// suited to deep searches, `Board` remaining the general purpose one.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BitBoard {
+    black: u64,
+    white: u64,
+}
+
+impl BitBoard {
+    /// All possible directions to capture opponent pieces.
+    const ALL_DIRECTIONS: [(i8, i8); 8] = [
+        (0, -1),
+        (1, -1),
+        (1, 0),
+        (1, 1),
+        (0, 1),
+        (-1, 1),
+        (-1, 0),
+        (-1, -1),
+    ];
+
+    // Masks removing the cells of the first (A) and last (H) columns.
+    const NOT_A_FILE: u64 = 0xfefe_fefe_fefe_fefe;
+    const NOT_H_FILE: u64 = 0x7f7f_7f7f_7f7f_7f7f;
+
+    /// Creates a BitBoard from the pieces of each player. A cell must not be
+    /// used by both players.
+    pub fn new(black: u64, white: u64) -> Result<Self, BoardError> {
+        if black & white != 0 {
+            return Err(BoardError::OverlappingPieces {
+                cells: black & white,
+            });
+        }
+        Ok(Self { black, white })
+    }
+
+    /// Creates a new board ready to start a game.
+    pub fn new_start() -> Self {
+        Self::from(&Board::new_start())
+    }
+
+    /// Returns the pieces of the given player.
+    pub fn pieces(&self, player: Player) -> u64 {
+        match player {
+            Player::Black => self.black,
+            Player::White => self.white,
+        }
+    }
+
+    /// Returns the empty cells.
+    pub fn empty_cells(&self) -> u64 {
+        !(self.black | self.white)
+    }
+
+    /// Gets the content of a board cell.
+    pub fn get_piece(&self, x: u8, y: u8) -> Result<Option<Player>, BoardError> {
+        let mask = Self::mask(x, y)?;
+        if self.black & mask != 0 {
+            Ok(Some(Player::Black))
+        } else if self.white & mask != 0 {
+            Ok(Some(Player::White))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Returns the cells where the given player can move.
+    pub fn legal_moves_mask(&self, player: Player) -> u64 {
+        let own = self.pieces(player);
+        let opponent = self.pieces(player.opponent());
+        let empty = self.empty_cells();
+        let mut moves = 0;
+        for &direction in Self::ALL_DIRECTIONS.iter() {
+            // opponent pieces reached from own pieces, the empty cell next to
+            // them being a move.
+            let captured = Self::fill(own, opponent, direction) & opponent;
+            moves |= Self::shift(captured, direction) & empty;
+        }
+
+        moves
+    }
+
+    /// Returns the positions where the given player can move, in grid order.
+    pub fn legal_moves(&self, player: Player) -> Vec<(u8, u8)> {
+        Self::positions(self.legal_moves_mask(player))
+    }
+
+    /// Checks if a given player can move in at least one position.
+    pub fn has_legal_move(&self, player: Player) -> bool {
+        self.legal_moves_mask(player) != 0
+    }
+
+    /// Returns the pieces flipped if the given player moves at the given
+    /// position, none if the move is invalid.
+    pub fn flips(&self, player: Player, x: u8, y: u8) -> Result<u64, BoardError> {
+        let mask = Self::mask(x, y)?;
+        if self.empty_cells() & mask == 0 {
+            return Ok(0);
+        }
+
+        let own = self.pieces(player);
+        let opponent = self.pieces(player.opponent());
+        let mut flips = 0;
+        for &direction in Self::ALL_DIRECTIONS.iter() {
+            // opponent pieces reached from the move, flipped if an own piece
+            // ends the line.
+            let line = Self::fill(mask, opponent, direction);
+            if Self::shift(line, direction) & own != 0 {
+                flips |= line & opponent;
+            }
+        }
+
+        Ok(flips)
+    }
+
+    /// Plays at the given position for the given player.
+    /// If the move is valid a new BitBoard is returned, else None.
+    pub fn play(&self, player: Player, x: u8, y: u8) -> Result<Option<BitBoard>, BoardError> {
+        let flips = self.flips(player, x, y)?;
+        if flips == 0 {
+            return Ok(None);
+        }
+
+        let played = flips | Self::mask(x, y)?;
+        let new_board = match player {
+            Player::Black => Self {
+                black: self.black | played,
+                white: self.white & !flips,
+            },
+            Player::White => Self {
+                black: self.black & !flips,
+                white: self.white | played,
+            },
+        };
+        Ok(Some(new_board))
+    }
+
+    /// Count the pieces on the board.
+    /// It returns a tuple with black pieces count as the first item,
+    /// and white pieces count as the second.
+    pub fn count_pieces(&self) -> (u8, u8) {
+        (self.black.count_ones() as u8, self.white.count_ones() as u8)
+    }
+
+    /// Returns the positions of the given cells, in grid order.
+    pub fn positions(cells: u64) -> Vec<(u8, u8)> {
+        GridIterator::new()
+            .filter(|&(x, y)| cells & (1 << (y * 8 + x)) != 0)
+            .collect()
+    }
+
+    fn mask(x: u8, y: u8) -> Result<u64, BoardError> {
+        if x > 7 || y > 7 {
+            Err(BoardError::OutOfRange { x, y })
+        } else {
+            Ok(1 << (y * 8 + x))
+        }
+    }
+
+    /// Moves all the given cells one step in the given direction, cells
+    /// leaving the board being lost.
+    fn shift(cells: u64, direction: (i8, i8)) -> u64 {
+        Self::raw_shift(cells, direction, 1) & Self::destination_mask(direction)
+    }
+
+    /// Moves all the given cells the given count of steps in the given
+    /// direction, without removing the cells wrapping around the board.
+    fn raw_shift(cells: u64, direction: (i8, i8), steps: i8) -> u64 {
+        let (dx, dy) = direction;
+        let offset = (dx + 8 * dy) * steps;
+        if offset > 0 {
+            cells << offset
+        } else {
+            cells >> -offset
+        }
+    }
+
+    /// Returns the cells which can be reached by a one step move in the
+    /// given direction without wrapping around the board.
+    fn destination_mask(direction: (i8, i8)) -> u64 {
+        match direction.0 {
+            1 => Self::NOT_A_FILE,
+            -1 => Self::NOT_H_FILE,
+            _ => !0,
+        }
+    }
+
+    /// Kogge-Stone occluded fill : returns the given cells, and all the cells
+    /// reached from them in the given direction while staying on the
+    /// propagator cells. It uses 3 steps of doubling length instead of a
+    /// loop over the 6 possible intermediate cells.
+    fn fill(cells: u64, propagator: u64, direction: (i8, i8)) -> u64 {
+        let mut cells = cells;
+        // propagator cells reached by wrapping around the board are removed,
+        // stopping the fill.
+        let mut propagator = propagator & Self::destination_mask(direction);
+        cells |= propagator & Self::raw_shift(cells, direction, 1);
+        propagator &= Self::raw_shift(propagator, direction, 1);
+        cells |= propagator & Self::raw_shift(cells, direction, 2);
+        propagator &= Self::raw_shift(propagator, direction, 2);
+        cells |= propagator & Self::raw_shift(cells, direction, 4);
+        cells
+    }
+}
+
+impl From<&Board> for BitBoard {
+    fn from(board: &Board) -> Self {
+        let (black, white, _) = board.bit_masks();
+        Self { black, white }
+    }
+}
+
+impl From<&BitBoard> for Board {
+    fn from(bit_board: &BitBoard) -> Self {
+        let mut board = Board::new();
+        for (x, y) in GridIterator::new() {
+            let piece = bit_board.get_piece(x, y).unwrap();
+            board.set_piece(x, y, piece).unwrap();
+        }
+        board
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::random::*;
+    use super::*;
+
+    #[test]
+    fn new_rejects_cells_used_by_both_players() {
+        assert!(BitBoard::new(0b11, 0b10).is_err());
+        assert!(BitBoard::new(0b01, 0b10).is_ok());
+    }
+
+    #[test]
+    fn conversions_keep_the_pieces() {
+        let board = Board::new_start();
+        let bit_board = BitBoard::from(&board);
+        assert_eq!(bit_board.get_piece(3, 4).unwrap(), Some(Player::Black));
+        assert_eq!(bit_board.get_piece(3, 3).unwrap(), Some(Player::White));
+        assert_eq!(bit_board.get_piece(0, 0).unwrap(), None);
+        assert!(bit_board.get_piece(8, 0).is_err());
+        assert_eq!(Board::from(&bit_board), board);
+    }
+
+    #[test]
+    fn legal_moves_do_not_wrap_around_the_board() {
+        // Black at H1 and White at A2 : no capture is possible.
+        let mut board = Board::new();
+        board.set_piece(7, 0, Some(Player::Black)).unwrap();
+        board.set_piece(0, 1, Some(Player::White)).unwrap();
+        let bit_board = BitBoard::from(&board);
+        assert!(!bit_board.has_legal_move(Player::Black));
+        assert!(!bit_board.has_legal_move(Player::White));
+    }
+
+    #[test]
+    fn bit_board_plays_as_the_board_does() {
+        // plays random games, comparing both boards at each move.
+        let mut rng = XorShiftRng::new(42);
+        for _ in 0..20 {
+            let mut board = Board::new_start();
+            let mut bit_board = BitBoard::new_start();
+            let mut player = Player::Black;
+            loop {
+                // moves found by probing each cell.
+                let moves: Vec<(u8, u8)> = GridIterator::new()
+                    .filter(|&(x, y)| board.is_move_valid(player, x, y).unwrap())
+                    .collect();
+                assert_eq!(bit_board.legal_moves(player), moves);
+                assert_eq!(bit_board.count_pieces(), board.count_pieces());
+                if moves.is_empty() {
+                    if !bit_board.has_legal_move(player.opponent()) {
+                        break;
+                    }
+                    player = player.opponent();
+                    continue;
+                }
+                let (x, y) = moves[rng.next_below(moves.len())];
+                board = board.play(player, x, y).unwrap().unwrap();
+                bit_board = bit_board.play(player, x, y).unwrap().unwrap();
+                assert_eq!(Board::from(&bit_board), board);
+                player = player.opponent();
+            }
+        }
+    }
+
+    #[test]
+    fn flips_captures_long_lines() {
+        // Black at A1, White from B1 to G1 : Black captures 6 pieces at H1.
+        let mut board = Board::new();
+        board.set_piece(0, 0, Some(Player::Black)).unwrap();
+        for x in 1..=6 {
+            board.set_piece(x, 0, Some(Player::White)).unwrap();
+        }
+        let bit_board = BitBoard::from(&board);
+        assert_eq!(bit_board.legal_moves(Player::Black), vec![(7, 0)]);
+        assert_eq!(bit_board.flips(Player::Black, 7, 0).unwrap(), 0x7e);
+        assert!(bit_board.legal_moves(Player::White).is_empty());
+    }
+
+    #[test]
+    fn play_returns_none_for_invalid_moves() {
+        let bit_board = BitBoard::new_start();
+        assert_eq!(bit_board.play(Player::Black, 0, 0).unwrap(), None);
+        assert_eq!(bit_board.play(Player::Black, 3, 3).unwrap(), None);
+        assert_eq!(bit_board.flips(Player::Black, 5, 4).unwrap(), 1 << 36);
+    }
+}
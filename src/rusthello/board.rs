@@ -1,7 +1,13 @@
-use std::fmt;
+use alloc::{string::String, vec::Vec};
+use core::{error, fmt, mem};
+
+use super::bitboard::*;
+use super::moves::*;
+use super::square::*;
 
 /// Othello players.
-#[derive(Copy, Clone, Debug, PartialEq)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Player {
     Black,
     White,
@@ -27,87 +33,243 @@ impl fmt::Display for Player {
     }
 }
 
-/// An Othello board, implementing moves.
+/// Errors of the board operations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BoardError {
+    /// The coordinates are out of the board.
+    OutOfRange { x: u8, y: u8 },
+    /// The move targets a square already holding a piece.
+    SquareOccupied { x: u8, y: u8 },
+    /// The move does not capture any piece, in any direction.
+    NoCapture { x: u8, y: u8 },
+    /// A BitBoard cell is used by both players.
+    OverlappingPieces { cells: u64 },
+    /// The text does not name a square of the board.
+    InvalidNotation(String),
+}
+
+impl fmt::Display for BoardError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BoardError::OutOfRange { x, y } => {
+                write!(f, "the given coordinates are out of range : ({}, {})", x, y)
+            }
+            BoardError::SquareOccupied { x, y } => {
+                write!(f, "{} is already occupied", Move::Place { x: *x, y: *y })
+            }
+            BoardError::NoCapture { x, y } => {
+                write!(
+                    f,
+                    "{} does not capture any piece",
+                    Move::Place { x: *x, y: *y }
+                )
+            }
+            BoardError::OverlappingPieces { cells } => {
+                write!(f, "some cells are used by both players : {:#018x}", cells)
+            }
+            BoardError::InvalidNotation(notation) => {
+                write!(f, "invalid square notation : {}", notation)
+            }
+        }
+    }
+}
+
+impl error::Error for BoardError {}
+
+/// A board after a move, with the positions of the pieces flipped by the move.
+pub type BoardWithFlips<const N: usize = 8> = (Board<N>, Vec<(u8, u8)>);
+
+/// What a move does in one direction, see `Board::explain_move`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LineOutcome {
+    /// The opponent pieces are captured, being closed by the piece of the
+    /// player at the given position.
+    Captures {
+        pieces: Vec<(u8, u8)>,
+        closed_by: (u8, u8),
+    },
+    /// The next square is out of the board.
+    Edge,
+    /// The next square is empty.
+    Empty,
+    /// The next square holds a piece of the player, there is nothing to
+    /// capture.
+    OwnPiece { position: (u8, u8) },
+    /// The opponent pieces are followed by an empty square or the edge of
+    /// the board, and are not closed by a piece of the player.
+    NotClosed { pieces: Vec<(u8, u8)> },
+}
+
+/// The outcome of a move in one direction, given as a step on the x and y
+/// axes (ie `(-1, 0)` towards the column A).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LineExplanation {
+    pub direction: (i8, i8),
+    pub outcome: LineOutcome,
+}
+
+/// All possible directions to capture opponent pieces.
+const ALL_DIRECTIONS: [(i8, i8); 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+/// An Othello board of N x N cells, implementing moves. The standard 8x8
+/// board is the default, other even sizes from 4 to 14 are Othello variants
+/// using the same rules.
 /// Board does not implement game workflow.
-#[derive(Debug, Copy, Clone)]
-pub struct Board {
-    cells: [[Option<Player>; 8]; 8],
+/// Boards are equal if their cells contents are the same, and could be used
+/// as keys in hash maps and sets.
+/// The counts of pieces are kept up to date as the cells change, instead of
+/// being counted again each time they are needed.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(from = "cells_serde::BoardCells<N>"))]
+pub struct Board<const N: usize = 8> {
+    #[cfg_attr(feature = "serde", serde(with = "cells_serde"))]
+    cells: [[Option<Player>; N]; N],
+    #[cfg_attr(feature = "serde", serde(skip))]
+    black_pieces: u8,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    white_pieces: u8,
 }
 
 impl Board {
-    /// Creates an empty board.
+    /// Creates an empty 8x8 board.
     pub fn new() -> Board {
+        Self::empty()
+    }
+
+    /// Creates a new 8x8 board ready to start a game.
+    pub fn new_start() -> Board {
+        Self::start()
+    }
+}
+
+impl<const N: usize> Board<N> {
+    /// Fails the build for unsupported sizes : the size must be even (the
+    /// start position being in the center), and the count of cells must fit
+    /// in a u8 as the counts of pieces.
+    const SIZE_CHECK: () = assert!(
+        N >= 4 && N <= 14 && N.is_multiple_of(2),
+        "the board size must be even, from 4 to 14"
+    );
+
+    /// Creates an empty board.
+    pub fn empty() -> Self {
+        let () = Self::SIZE_CHECK;
         Board {
-            cells: [[None; 8]; 8],
+            cells: [[None; N]; N],
+            black_pieces: 0,
+            white_pieces: 0,
         }
     }
 
-    /// Creates a new board ready to start a game.
-    pub fn new_start() -> Board {
-        let mut board = Self::new();
-        board.set_piece(3, 3, Some(Player::White)).unwrap();
-        board.set_piece(4, 4, Some(Player::White)).unwrap();
-        board.set_piece(3, 4, Some(Player::Black)).unwrap();
-        board.set_piece(4, 3, Some(Player::Black)).unwrap();
+    /// Creates a new board ready to start a game, the 4 first pieces being
+    /// in the center.
+    pub fn start() -> Self {
+        let mut board = Self::empty();
+        let (low, high) = (N as u8 / 2 - 1, N as u8 / 2);
+        board.set_piece(low, low, Some(Player::White)).unwrap();
+        board.set_piece(high, high, Some(Player::White)).unwrap();
+        board.set_piece(low, high, Some(Player::Black)).unwrap();
+        board.set_piece(high, low, Some(Player::Black)).unwrap();
         board
     }
 
+    /// Returns the count of cells of a side of the board.
+    pub fn size(&self) -> u8 {
+        N as u8
+    }
+
     /// Sets the content of a board cell.
-    pub fn set_piece(&mut self, x: u8, y: u8, piece: Option<Player>) -> Result<(), String> {
+    pub fn set_piece(&mut self, x: u8, y: u8, piece: Option<Player>) -> Result<(), BoardError> {
         Self::check_coordinates(x, y)?;
-        self.cells[x as usize][y as usize] = piece;
+        self.put(x, y, piece);
         Ok(())
     }
 
     //// Gets the content of a board cell.
-    pub fn get_piece(&self, x: u8, y: u8) -> Result<Option<Player>, String> {
+    pub fn get_piece(&self, x: u8, y: u8) -> Result<Option<Player>, BoardError> {
         Self::check_coordinates(x, y)?;
         Ok(self.cells[x as usize][y as usize])
     }
 
-    fn check_coordinates(x: u8, y: u8) -> Result<(), String> {
-        if x > 7 || y > 7 {
-            Err(format!(
-                "the given coordinates are out of range : ({}, {})",
-                x, y
-            ))
-        } else {
-            Ok(())
+    /// Sets the content of a board square.
+    pub fn set_piece_at(&mut self, square: Square<N>, piece: Option<Player>) {
+        let (x, y) = square.coordinates();
+        self.put(x, y, piece);
+    }
+
+    /// Sets the content of a cell, keeping the counts of pieces up to date.
+    /// The coordinates must be valid.
+    fn put(&mut self, x: u8, y: u8, piece: Option<Player>) {
+        let previous = mem::replace(&mut self.cells[x as usize][y as usize], piece);
+        if let Some(player) = previous {
+            *self.pieces_count_mut(player) -= 1;
+        }
+        if let Some(player) = piece {
+            *self.pieces_count_mut(player) += 1;
+        }
+    }
+
+    /// Returns the count of pieces of the given player, to update it.
+    fn pieces_count_mut(&mut self, player: Player) -> &mut u8 {
+        match player {
+            Player::Black => &mut self.black_pieces,
+            Player::White => &mut self.white_pieces,
         }
     }
 
+    /// Gets the content of a board square.
+    pub fn piece_at(&self, square: Square<N>) -> Option<Player> {
+        let (x, y) = square.coordinates();
+        self.cells[x as usize][y as usize]
+    }
+
+    /// Returns the kind of the square at the given coordinates.
+    pub fn square_kind(&self, x: u8, y: u8) -> Result<SquareKind, BoardError> {
+        Ok(Square::<N>::new(x, y)?.kind())
+    }
+
+    fn check_coordinates(x: u8, y: u8) -> Result<(), BoardError> {
+        Square::<N>::new(x, y).map(|_| ())
+    }
+
     /// Returns an iterator on the board.
     /// The iterator will returns all cells positions and their contents.
-    pub fn iter(self: &Board) -> BoardIterator {
+    pub fn iter(&self) -> BoardIterator<'_, N> {
         BoardIterator::new(self)
     }
 
-    /// All possible directions to capture opponent pieces.
-    const ALL_DIRECTIONS: [(i8, i8); 8] = [
-        (0, -1),
-        (1, -1),
-        (1, 0),
-        (1, 1),
-        (0, 1),
-        (-1, 1),
-        (-1, 0),
-        (-1, -1),
-    ];
+    /// Returns an iterator over the squares holding the given player pieces,
+    /// in grid order.
+    pub fn pieces_of(&self, player: Player) -> impl Iterator<Item = Square<N>> + '_ {
+        self.iter()
+            .filter(move |&(_, _, piece)| piece == Some(player))
+            .map(|(x, y, _)| Square::new(x, y).expect("Unexpected cell out of the board."))
+    }
 
     /// Checks if the given player can move to the given coordinates.
     /// It's faster than play as it does just the bare minimum.
-    pub fn is_move_valid(&self, player: Player, x: u8, y: u8) -> Result<bool, String> {
+    pub fn is_move_valid(&self, player: Player, x: u8, y: u8) -> Result<bool, BoardError> {
         Self::check_coordinates(x, y)?;
 
         // Only moves targeting empty cells are valids.
-        if self.cells[x as usize][y as usize] != None {
+        if self.cells[x as usize][y as usize].is_some() {
             return Ok(false);
         }
 
         let other_player = player.opponent();
 
-        for direction in Self::ALL_DIRECTIONS.iter() {
-            if let Some(_) = self.can_capture(other_player, x, y, *direction) {
+        for direction in ALL_DIRECTIONS.iter() {
+            if self.can_capture(other_player, x, y, *direction).is_some() {
                 return Ok(true);
             }
         }
@@ -116,6 +278,117 @@ impl Board {
         Ok(false)
     }
 
+    /// Checks if the given player can move to the given coordinates, like
+    /// `is_move_valid`, giving the reason why the move is invalid as error.
+    pub fn check_move(&self, player: Player, x: u8, y: u8) -> Result<(), BoardError> {
+        if self.is_move_valid(player, x, y)? {
+            Ok(())
+        } else {
+            Err(self.invalid_move_error(x, y))
+        }
+    }
+
+    /// Returns the reason why a move at the given (valid) coordinates is
+    /// invalid.
+    fn invalid_move_error(&self, x: u8, y: u8) -> BoardError {
+        if self.cells[x as usize][y as usize].is_some() {
+            BoardError::SquareOccupied { x, y }
+        } else {
+            BoardError::NoCapture { x, y }
+        }
+    }
+
+    /// Checks if the given player can move to the given square, like
+    /// `is_move_valid`.
+    pub fn is_move_valid_at(&self, player: Player, square: Square<N>) -> bool {
+        let (x, y) = square.coordinates();
+        self.is_move_valid(player, x, y)
+            .expect("Unexpected square out of the board.")
+    }
+
+    /// Returns the count of pieces the given player would flip by moving to
+    /// the given coordinates, 0 if the move is invalid. Like
+    /// `is_move_valid`, it never builds a new board.
+    pub fn flip_count(&self, player: Player, x: u8, y: u8) -> Result<u8, BoardError> {
+        Self::check_coordinates(x, y)?;
+
+        // Only moves targeting empty cells are valids.
+        if self.cells[x as usize][y as usize].is_some() {
+            return Ok(0);
+        }
+
+        let other_player = player.opponent();
+        let mut count = 0;
+        for direction in ALL_DIRECTIONS.iter() {
+            if let Some(navigator) = self.can_capture(other_player, x, y, *direction) {
+                // going backward, the pieces up to the move position.
+                count += navigator.take_while(|&position| position != (x, y)).count() as u8;
+            }
+        }
+
+        Ok(count)
+    }
+
+    /// Explains a move direction by direction, ie to teach the rules : the
+    /// move is valid when at least one direction captures. The directions are
+    /// given clockwise, starting towards the row 1. A move on an occupied
+    /// square can't be explained, and is an error.
+    pub fn explain_move(
+        &self,
+        player: Player,
+        x: u8,
+        y: u8,
+    ) -> Result<Vec<LineExplanation>, BoardError> {
+        Self::check_coordinates(x, y)?;
+        if self.cells[x as usize][y as usize].is_some() {
+            return Err(BoardError::SquareOccupied { x, y });
+        }
+
+        Ok(ALL_DIRECTIONS
+            .iter()
+            .map(|&direction| LineExplanation {
+                direction,
+                outcome: self.explain_line(player, x, y, direction),
+            })
+            .collect())
+    }
+
+    /// Explains a move at the given (valid) coordinates in one direction.
+    fn explain_line(&self, player: Player, x: u8, y: u8, direction: (i8, i8)) -> LineOutcome {
+        let navigator = CellsNavigator::<N>::new((x, y), direction).unwrap();
+        let mut pieces = Vec::new();
+        for position in navigator {
+            match self.cells[position.0 as usize][position.1 as usize] {
+                Some(piece) if piece != player => pieces.push(position),
+                Some(_) if pieces.is_empty() => return LineOutcome::OwnPiece { position },
+                Some(_) => {
+                    return LineOutcome::Captures {
+                        pieces,
+                        closed_by: position,
+                    }
+                }
+                None if pieces.is_empty() => return LineOutcome::Empty,
+                None => return LineOutcome::NotClosed { pieces },
+            }
+        }
+        if pieces.is_empty() {
+            LineOutcome::Edge
+        } else {
+            LineOutcome::NotClosed { pieces }
+        }
+    }
+
+    /// Returns the positions where the given player can move, in grid order.
+    pub fn legal_moves(&self, player: Player) -> Vec<(u8, u8)> {
+        self.legal_moves_iter(player).collect()
+    }
+
+    /// Returns an iterator over the positions where the given player can
+    /// move, in grid order.
+    pub fn legal_moves_iter(&self, player: Player) -> LegalMovesIterator<N> {
+        LegalMovesIterator::new(self, player)
+    }
+
     /// Checks if a capture is possible for a given move and a given direction.
     /// Returns a CellsNavigator ready to capture all opponent pieces backward.
     fn can_capture(
@@ -124,7 +397,7 @@ impl Board {
         x: u8,
         y: u8,
         direction: (i8, i8),
-    ) -> Option<CellsNavigator> {
+    ) -> Option<CellsNavigator<N>> {
         let mut navigator = CellsNavigator::new((x, y), direction).unwrap();
         let mut found_other_on_path = false;
         let mut can_capture = false;
@@ -153,20 +426,101 @@ impl Board {
 
     /// Plays at the given position for the given player.
     /// If the move is valid a new Board is returned, else None.
-    pub fn play(&self, player: Player, x: u8, y: u8) -> Result<Option<Board>, String> {
+    pub fn play(&self, player: Player, x: u8, y: u8) -> Result<Option<Self>, BoardError> {
+        self.play_and_report(player, x, y, |_| ())
+    }
+
+    /// Plays at the given square for the given player, like `play`.
+    pub fn play_at(&self, player: Player, square: Square<N>) -> Option<Self> {
+        let (x, y) = square.coordinates();
+        self.play(player, x, y)
+            .expect("Unexpected square out of the board.")
+    }
+
+    /// Plays at the given position for the given player, like `play`.
+    /// If the move is valid the new Board is returned with the positions of
+    /// the flipped pieces, else None.
+    pub fn play_with_flips(
+        &self,
+        player: Player,
+        x: u8,
+        y: u8,
+    ) -> Result<Option<BoardWithFlips<N>>, BoardError> {
+        let mut flips = Vec::new();
+        let new_board = self.play_and_report(player, x, y, |position| flips.push(position))?;
+        Ok(new_board.map(|new_board| (new_board, flips)))
+    }
+
+    /// Plays in place at the given position for the given player, without
+    /// building a new board. Returns the information needed to undo the move,
+    /// or an error if the move is invalid (and the board is unchanged).
+    pub fn apply_move(&mut self, player: Player, x: u8, y: u8) -> Result<MoveUndo, BoardError> {
+        let mut flipped = [0; 8];
+        let valid_move = self.apply_and_report(player, x, y, |(flip_x, flip_y)| {
+            flipped[MoveUndo::direction_index((x, y), (flip_x, flip_y))] += 1
+        })?;
+        if valid_move {
+            Ok(MoveUndo {
+                player,
+                position: (x, y),
+                flipped,
+            })
+        } else {
+            Err(self.invalid_move_error(x, y))
+        }
+    }
+
+    /// Undoes a move applied with `apply_move`, restoring the board as it was
+    /// before. Moves must be undone in the reverse order of their application.
+    pub fn undo_move(&mut self, undo: MoveUndo) {
+        let (x, y) = undo.position;
+        self.put(x, y, None);
+        let opponent = undo.player.opponent();
+        for (flip_x, flip_y) in undo.flipped_cells() {
+            self.put(flip_x, flip_y, Some(opponent));
+        }
+    }
+
+    /// Implements `play`, reporting each flipped piece position to the given
+    /// function.
+    fn play_and_report<F: FnMut((u8, u8))>(
+        &self,
+        player: Player,
+        x: u8,
+        y: u8,
+        on_flip: F,
+    ) -> Result<Option<Self>, BoardError> {
+        let mut new_board = *self;
+        if new_board.apply_and_report(player, x, y, on_flip)? {
+            Ok(Some(new_board))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Plays in place, reporting each flipped piece position to the given
+    /// function. Returns false if the move is invalid, the board being
+    /// unchanged.
+    fn apply_and_report<F: FnMut((u8, u8))>(
+        &mut self,
+        player: Player,
+        x: u8,
+        y: u8,
+        mut on_flip: F,
+    ) -> Result<bool, BoardError> {
         Self::check_coordinates(x, y)?;
 
         // Only moves targeting empty cells are valids.
-        if self.cells[x as usize][y as usize] != None {
-            return Ok(None);
+        if self.cells[x as usize][y as usize].is_some() {
+            return Ok(false);
         }
 
         // Explores the 8 possible directions and try to capture opponent pieces.
-        // If at least one capture is possible, the move is valid.
-        let mut new_board = self.clone();
+        // If at least one capture is possible, the move is valid. Captures in a
+        // direction don't change the cells explored for other directions.
         let other_player = player.opponent();
         let mut valid_move = false;
-        for direction in Self::ALL_DIRECTIONS.iter() {
+        for direction in ALL_DIRECTIONS.iter() {
             if let Some(navigator) = self.can_capture(other_player, x, y, *direction) {
                 // Let's capture opponent's pieces going backward.
                 valid_move = true;
@@ -175,55 +529,260 @@ impl Board {
                     if position == (x, y) {
                         break;
                     }
-                    new_board.cells[position.0 as usize][position.1 as usize] = Some(player);
+                    self.put(position.0, position.1, Some(player));
+                    on_flip(position);
                 }
             }
         }
 
         if valid_move {
-            new_board.cells[x as usize][y as usize] = Some(player);
-            Ok(Some(new_board))
-        } else {
-            Ok(None)
+            self.put(x, y, Some(player));
         }
+        Ok(valid_move)
+    }
+
+    /// Checks if a given player can move in at least one position. It never
+    /// builds a new board.
+    pub fn has_legal_move(&self, player: Player) -> bool {
+        self.legal_moves_iter(player).next().is_some()
     }
 
     /// Cheks if a given player can move in at least one position.
+    /// Same as `has_legal_move`.
     pub fn can_player_move(&self, player: Player) -> bool {
-        for (x, y) in GridIterator::new() {
-            let can_move = self.is_move_valid(player, x, y).unwrap();
-            if can_move {
-                return true;
-            }
-        }
+        self.has_legal_move(player)
+    }
+
+    /// Returns the positions on the lines (row, column and diagonals) going
+    /// through the given position, the position itself being excluded.
+    pub(crate) fn lines_through(x: u8, y: u8) -> impl Iterator<Item = (u8, u8)> {
+        ALL_DIRECTIONS.iter().flat_map(move |&direction| {
+            CellsNavigator::<N>::new((x, y), direction)
+                .expect("Unexpected position out of the board.")
+        })
+    }
+
+    /// Returns the count of empty cells.
+    pub fn empties(&self) -> u8 {
+        (N * N) as u8 - self.black_pieces - self.white_pieces
+    }
 
-        false
+    /// Checks if all the cells hold a piece.
+    pub fn is_full(&self) -> bool {
+        self.empties() == 0
     }
 
     /// Count the pieces on the board.
     /// It returns a tuple with black pieces count as the first item,
     /// and white pieces count as the second.
     pub fn count_pieces(&self) -> (u8, u8) {
-        let mut black_pieces = 0;
-        let mut white_pieces = 0;
-        for (_, _, piece) in self.iter() {
+        (self.black_pieces, self.white_pieces)
+    }
+
+    /// Count the pieces on the board by player and by region, in a single
+    /// scan of the board.
+    pub fn piece_count(&self) -> PieceCount {
+        let mut count = PieceCount::default();
+        for (x, y, piece) in self.iter() {
+            let regions = match piece {
+                Some(Player::Black) => &mut count.black,
+                Some(Player::White) => &mut count.white,
+                None => continue,
+            };
+            let square = Square::<N>::new(x, y).expect("Unexpected cell out of the board.");
+            if square.is_corner() {
+                regions.corners += 1;
+            } else if square.is_on_edge() {
+                regions.edges += 1;
+            } else {
+                regions.interior += 1;
+            }
+        }
+
+        count
+    }
+
+    /// Returns the black pieces, the white pieces and the cells of the board
+    /// as BitBoard masks (the cell (x, y) being the bit x + 8 * y). Only
+    /// boards up to 8x8 fit, in the top left corner of the BitBoard.
+    pub(crate) fn bit_masks(&self) -> (u64, u64, u64) {
+        debug_assert!(N <= 8, "the board doesn't fit in a BitBoard");
+        let (mut black, mut white, mut cells) = (0, 0, 0);
+        for (x, y, piece) in self.iter() {
+            let mask = 1 << (y * 8 + x);
+            cells |= mask;
             match piece {
-                Some(Player::Black) => black_pieces += 1,
-                Some(Player::White) => white_pieces += 1,
-                _ => (),
+                Some(Player::Black) => black |= mask,
+                Some(Player::White) => white |= mask,
+                None => (),
             }
         }
+        (black, white, cells)
+    }
+}
+
+impl<const N: usize> Default for Board<N> {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+/// The 8 symmetries of the board : rotations, and mirrors over the axes and
+/// diagonals. Rotations are clockwise.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Symmetry {
+    Identity,
+    Rotate90,
+    Rotate180,
+    Rotate270,
+    MirrorHorizontal,
+    MirrorVertical,
+    Transpose,
+    AntiTranspose,
+}
+
+impl Symmetry {
+    /// All the symmetries, starting with the identity.
+    pub const ALL: [Symmetry; 8] = [
+        Symmetry::Identity,
+        Symmetry::Rotate90,
+        Symmetry::Rotate180,
+        Symmetry::Rotate270,
+        Symmetry::MirrorHorizontal,
+        Symmetry::MirrorVertical,
+        Symmetry::Transpose,
+        Symmetry::AntiTranspose,
+    ];
+
+    /// Returns the coordinates of the given cell of a 8x8 board once
+    /// transformed.
+    pub fn apply(self, x: u8, y: u8) -> (u8, u8) {
+        self.apply_for_size(8, x, y)
+    }
+
+    /// Returns the coordinates of the given cell of a board of the given
+    /// size once transformed.
+    pub fn apply_for_size(self, size: u8, x: u8, y: u8) -> (u8, u8) {
+        let last = size - 1;
+        match self {
+            Symmetry::Identity => (x, y),
+            Symmetry::Rotate90 => (last - y, x),
+            Symmetry::Rotate180 => (last - x, last - y),
+            Symmetry::Rotate270 => (y, last - x),
+            Symmetry::MirrorHorizontal => (last - x, y),
+            Symmetry::MirrorVertical => (x, last - y),
+            Symmetry::Transpose => (y, x),
+            Symmetry::AntiTranspose => (last - y, last - x),
+        }
+    }
+
+    /// Returns the symmetry reverting this one.
+    pub fn inverse(self) -> Symmetry {
+        match self {
+            Symmetry::Rotate90 => Symmetry::Rotate270,
+            Symmetry::Rotate270 => Symmetry::Rotate90,
+            other => other,
+        }
+    }
+}
+
+impl<const N: usize> Board<N> {
+    /// Returns the board transformed by the given symmetry.
+    pub fn transform(&self, symmetry: Symmetry) -> Self {
+        let mut board = Self::empty();
+        for (x, y, piece) in self.iter() {
+            let (new_x, new_y) = symmetry.apply_for_size(N as u8, x, y);
+            board.put(new_x, new_y, piece);
+        }
+        board
+    }
+
+    /// Returns the board rotated a quarter turn clockwise.
+    pub fn rotate90(&self) -> Self {
+        self.transform(Symmetry::Rotate90)
+    }
+
+    /// Returns the board rotated a half turn.
+    pub fn rotate180(&self) -> Self {
+        self.transform(Symmetry::Rotate180)
+    }
+
+    /// Returns the board rotated a quarter turn counterclockwise.
+    pub fn rotate270(&self) -> Self {
+        self.transform(Symmetry::Rotate270)
+    }
+
+    /// Returns the board mirrored left to right.
+    pub fn mirror_horizontal(&self) -> Self {
+        self.transform(Symmetry::MirrorHorizontal)
+    }
+
+    /// Returns the board mirrored top to bottom.
+    pub fn mirror_vertical(&self) -> Self {
+        self.transform(Symmetry::MirrorVertical)
+    }
+
+    /// Returns the board mirrored over the A1-H8 diagonal.
+    pub fn transpose(&self) -> Self {
+        self.transform(Symmetry::Transpose)
+    }
+
+    /// Returns the board mirrored over the H1-A8 diagonal.
+    pub fn anti_transpose(&self) -> Self {
+        self.transform(Symmetry::AntiTranspose)
+    }
 
-        (black_pieces, white_pieces)
+    /// Returns the cells contents in grid order, as numbers used to compare
+    /// boards.
+    fn cells_order(&self) -> impl Iterator<Item = u8> + '_ {
+        self.iter().map(|(_, _, piece)| match piece {
+            None => 0,
+            Some(Player::Black) => 1,
+            Some(Player::White) => 2,
+        })
+    }
+
+    /// Returns the canonical form of the board : the smallest of its 8
+    /// symmetric variants, comparing cells in grid order (empty cells being
+    /// smaller than black pieces, themselves smaller than white pieces).
+    /// Symmetric positions share the same canonical form.
+    pub fn canonical(&self) -> Self {
+        self.canonical_with_symmetry().0
+    }
+
+    /// Returns the canonical form of the board, and the symmetry giving it.
+    /// The inverse symmetry converts moves on the canonical board back to
+    /// this board.
+    pub fn canonical_with_symmetry(&self) -> (Self, Symmetry) {
+        Symmetry::ALL
+            .iter()
+            .map(|&symmetry| (self.transform(symmetry), symmetry))
+            .min_by(|(board_a, _), (board_b, _)| board_a.cells_order().cmp(board_b.cells_order()))
+            .expect("Unexpected empty symmetries list.")
     }
 }
 
-impl fmt::Display for Board {
+impl<const N: usize> fmt::Display for Board<N> {
     /// Builds an ascii representation of the board. Not a fancy one,
     /// just enough to see what it looks like.
+    /// The alternate flag (`{:#}`) adds the column letters and the row
+    /// numbers.
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        for y in 0..=7 {
-            for x in 0..=7 {
+        let labels = f.alternate();
+        // rows numbers are right aligned.
+        let number_width = if N < 10 { 1 } else { 2 };
+        if labels {
+            write!(f, "{:width$} ", "", width = number_width)?;
+            for x in 0..N as u8 {
+                write!(f, "{}", (b'A' + x) as char)?;
+            }
+            f.write_str("\n")?;
+        }
+        for y in 0..N as u8 {
+            if labels {
+                write!(f, "{:>width$} ", y + 1, width = number_width)?;
+            }
+            for x in 0..N as u8 {
                 let piece = self.get_piece(x, y).unwrap();
                 let piece_representation = match piece {
                     None => " ",
@@ -238,24 +797,76 @@ impl fmt::Display for Board {
     }
 }
 
+/// Serializes the cells as nested sequences, serde implementing arrays only
+/// for some sizes.
+#[cfg(feature = "serde")]
+mod cells_serde {
+    use alloc::{format, vec::Vec};
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    use super::{Board, Player};
+
+    /// The serialized content of a Board, the counts of pieces being
+    /// computed again when it's converted to a Board.
+    #[derive(Deserialize)]
+    #[serde(rename = "Board")]
+    pub struct BoardCells<const N: usize> {
+        #[serde(with = "self")]
+        cells: [[Option<Player>; N]; N],
+    }
+
+    impl<const N: usize> From<BoardCells<N>> for Board<N> {
+        fn from(board_cells: BoardCells<N>) -> Self {
+            let mut board = Board::empty();
+            for (x, column) in board_cells.cells.iter().enumerate() {
+                for (y, &piece) in column.iter().enumerate() {
+                    board.put(x as u8, y as u8, piece);
+                }
+            }
+            board
+        }
+    }
+
+    pub fn serialize<S: Serializer, const N: usize>(
+        cells: &[[Option<Player>; N]; N],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(cells.iter().map(|column| &column[..]))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>, const N: usize>(
+        deserializer: D,
+    ) -> Result<[[Option<Player>; N]; N], D::Error> {
+        let columns = Vec::<Vec<Option<Player>>>::deserialize(deserializer)?;
+        if columns.len() != N || columns.iter().any(|column| column.len() != N) {
+            return Err(D::Error::custom(format!("expected a {}x{} board", N, N)));
+        }
+        let mut cells = Board::<N>::empty().cells;
+        for (column, contents) in cells.iter_mut().zip(columns) {
+            column.copy_from_slice(&contents);
+        }
+        Ok(cells)
+    }
+}
+
 /// Implements an iterator on the board wich returns
 /// each position of the board and its content.
 #[derive(Debug)]
-pub struct BoardIterator<'a> {
-    board: &'a Board,
-    grid_iterator: GridIterator,
+pub struct BoardIterator<'a, const N: usize = 8> {
+    board: &'a Board<N>,
+    grid_iterator: GridIterator<N>,
 }
 
-impl<'a> BoardIterator<'a> {
-    fn new(board: &'a Board) -> Self {
+impl<'a, const N: usize> BoardIterator<'a, N> {
+    fn new(board: &'a Board<N>) -> Self {
         BoardIterator {
             board,
-            grid_iterator: GridIterator::new(),
+            grid_iterator: GridIterator::default(),
         }
     }
 }
 
-impl Iterator for BoardIterator<'_> {
+impl<const N: usize> Iterator for BoardIterator<'_, N> {
     type Item = (u8, u8, Option<Player>);
 
     fn next(&mut self) -> Option<Self::Item> {
@@ -267,30 +878,186 @@ impl Iterator for BoardIterator<'_> {
     }
 }
 
-/// An iterator over a 8x8 grid
+/// Pieces of a board counted by player and by region, as returned by
+/// `Board::piece_count`.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct PieceCount {
+    black: RegionCount,
+    white: RegionCount,
+}
+
+impl PieceCount {
+    /// Returns the counts of the given player pieces.
+    pub fn of(&self, player: Player) -> RegionCount {
+        match player {
+            Player::Black => self.black,
+            Player::White => self.white,
+        }
+    }
+
+    /// Returns the count of black pieces.
+    pub fn black(&self) -> u8 {
+        self.black.total()
+    }
+
+    /// Returns the count of white pieces.
+    pub fn white(&self) -> u8 {
+        self.white.total()
+    }
+
+    /// Returns the count of pieces of both players.
+    pub fn total(&self) -> u8 {
+        self.black() + self.white()
+    }
+}
+
+/// Pieces of a player counted by region : corners, edges (the border
+/// without the corners), and interior.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct RegionCount {
+    pub corners: u8,
+    pub edges: u8,
+    pub interior: u8,
+}
+
+impl RegionCount {
+    /// Returns the count of pieces of all regions.
+    pub fn total(&self) -> u8 {
+        self.corners + self.edges + self.interior
+    }
+}
+
+/// Information about a move applied in place with `Board::apply_move`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct MoveUndo {
+    player: Player,
+    position: (u8, u8),
+    // count of flipped pieces in each direction, following ALL_DIRECTIONS.
+    flipped: [u8; 8],
+}
+
+impl MoveUndo {
+    /// Returns the index in ALL_DIRECTIONS of the direction going from the
+    /// move to the given flipped piece.
+    fn direction_index(position: (u8, u8), flip: (u8, u8)) -> usize {
+        let direction = (
+            (flip.0 as i8 - position.0 as i8).signum(),
+            (flip.1 as i8 - position.1 as i8).signum(),
+        );
+        ALL_DIRECTIONS
+            .iter()
+            .position(|&other| other == direction)
+            .expect("Unexpected flipped piece out of the move lines.")
+    }
+
+    /// Returns the positions of the flipped pieces, direction by direction.
+    fn flipped_cells(&self) -> impl Iterator<Item = (u8, u8)> + '_ {
+        let (x, y) = (self.position.0 as i8, self.position.1 as i8);
+        ALL_DIRECTIONS
+            .iter()
+            .zip(self.flipped.iter())
+            .flat_map(move |(&(dx, dy), &count)| {
+                (1..=count as i8).map(move |step| ((x + dx * step) as u8, (y + dy * step) as u8))
+            })
+    }
+
+    /// Returns the player who moved.
+    pub fn player(&self) -> Player {
+        self.player
+    }
+
+    /// Returns the position of the move.
+    pub fn position(&self) -> (u8, u8) {
+        self.position
+    }
+
+    /// Returns the positions of the flipped pieces, in grid order.
+    pub fn flips(&self) -> Vec<(u8, u8)> {
+        let mut flips: Vec<(u8, u8)> = self.flipped_cells().collect();
+        flips.sort_by_key(|&(x, y)| (y, x));
+        flips
+    }
+}
+
+/// Implements an iterator on the board wich returns each position where a
+/// player can move. For boards up to 8x8 all moves are generated at once
+/// using a BitBoard, larger boards are probed cell by cell.
+#[derive(Debug)]
+pub struct LegalMovesIterator<const N: usize = 8> {
+    // remaining moves, one bit per cell as in a BitBoard.
+    moves: u64,
+    // board, player and remaining cells to probe, for larger boards.
+    probe: Option<(Board<N>, Player, GridIterator<N>)>,
+}
+
+impl<const N: usize> LegalMovesIterator<N> {
+    fn new(board: &Board<N>, player: Player) -> Self {
+        if N > 8 {
+            return LegalMovesIterator {
+                moves: 0,
+                probe: Some((*board, player, GridIterator::default())),
+            };
+        }
+
+        // cells out of a smaller board are empty for the BitBoard, moves
+        // found there are removed.
+        let (black, white, cells) = board.bit_masks();
+        let bit_board = BitBoard::new(black, white).expect("Unexpected cell used by both players.");
+        LegalMovesIterator {
+            moves: bit_board.legal_moves_mask(player) & cells,
+            probe: None,
+        }
+    }
+}
+
+impl<const N: usize> Iterator for LegalMovesIterator<N> {
+    type Item = (u8, u8);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some((board, player, cells)) = &mut self.probe {
+            return cells.find(|&(x, y)| board.is_move_valid(*player, x, y).unwrap());
+        }
+
+        if self.moves == 0 {
+            return None;
+        }
+
+        let index = self.moves.trailing_zeros() as u8;
+        self.moves &= self.moves - 1;
+        Some((index % 8, index / 8))
+    }
+}
+
+/// An iterator over a N x N grid, 8x8 by default.
 #[derive(Debug)]
-pub struct GridIterator {
+pub struct GridIterator<const N: usize = 8> {
     x: u8,
     y: u8,
 }
 
+impl<const N: usize> Default for GridIterator<N> {
+    fn default() -> Self {
+        GridIterator { x: 0, y: 0 }
+    }
+}
+
 impl GridIterator {
     pub fn new() -> Self {
-        GridIterator { x: 0, y: 0 }
+        Self::default()
     }
 }
 
-impl Iterator for GridIterator {
+impl<const N: usize> Iterator for GridIterator<N> {
     type Item = (u8, u8);
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.y > 7 {
+        if self.y as usize >= N {
             return None;
         }
 
         let item: Self::Item = (self.x, self.y);
         self.x += 1;
-        if self.x > 7 {
+        if self.x as usize >= N {
             self.x = 0;
             self.y += 1;
         }
@@ -304,28 +1071,28 @@ impl Iterator for GridIterator {
 /// The start position is excluded from the iteration.
 /// The iterator can be reversed to go backward.
 #[derive(Debug)]
-struct CellsNavigator {
+struct CellsNavigator<const N: usize = 8> {
     current_position: (i8, i8),
     direction: (i8, i8),
 }
 
-impl CellsNavigator {
-    fn new(start: (u8, u8), direction: (i8, i8)) -> Result<CellsNavigator, String> {
+impl<const N: usize> CellsNavigator<N> {
+    fn new(start: (u8, u8), direction: (i8, i8)) -> Result<Self, BoardError> {
         let (x, y) = start;
         let (dx, dy) = direction;
 
-        Board::check_coordinates(x, y)?;
+        Board::<N>::check_coordinates(x, y)?;
 
-        if !(-1..=1).contains(&dx) || !(-1..=1).contains(&dy) {
-            return Err(format!(
-                "the given direction is out of range : ({}, {})",
-                dx, dy
-            ));
-        }
+        debug_assert!(
+            (-1..=1).contains(&dx) && (-1..=1).contains(&dy),
+            "the given direction is out of range : ({}, {})",
+            dx,
+            dy
+        );
 
         Ok(CellsNavigator {
             current_position: (x as i8, y as i8),
-            direction: direction,
+            direction,
         })
     }
 
@@ -334,14 +1101,15 @@ impl CellsNavigator {
     }
 }
 
-impl Iterator for CellsNavigator {
+impl<const N: usize> Iterator for CellsNavigator<N> {
     type Item = (u8, u8);
 
     fn next(&mut self) -> Option<Self::Item> {
         let (x, y) = self.current_position;
         let (dx, dy) = self.direction;
         let (x, y) = (x + dx, y + dy);
-        if x < 0 || x > 7 || y < 0 || y > 7 {
+        let limit = N as i8;
+        if !(0..limit).contains(&x) || !(0..limit).contains(&y) {
             None
         } else {
             self.current_position = (x, y);
@@ -357,7 +1125,7 @@ mod tests {
     fn new_creates_empty_board() {
         let board = Board::new();
         board.cells.iter().flatten().for_each(|piece| {
-            assert_eq!(piece.is_none(), true);
+            assert!(piece.is_none());
         })
     }
 
@@ -366,8 +1134,8 @@ mod tests {
         let board = Board::new_start();
         for (x, columns) in board.cells.iter().enumerate() {
             for (y, piece) in columns.iter().enumerate() {
-                if x < 3 || x > 4 || y < 3 || y > 4 {
-                    assert_eq!(piece.is_none(), true);
+                if !(3..=4).contains(&x) || !(3..=4).contains(&y) {
+                    assert!(piece.is_none());
                 } else if x == y {
                     assert_eq!(*piece, Some(Player::White));
                 } else {
@@ -419,6 +1187,17 @@ mod tests {
         assert_eq!(count, 64);
     }
 
+    #[test]
+    fn pieces_of_returns_the_player_squares() {
+        let board = Board::new_start();
+        let squares: Vec<String> = board
+            .pieces_of(Player::Black)
+            .map(|square| square.to_string())
+            .collect();
+        assert_eq!(squares, vec!["E4", "D5"]);
+        assert_eq!(Board::new().pieces_of(Player::White).count(), 0);
+    }
+
     #[test]
     fn is_move_valid_returns_none_for_non_empty_cell() {
         let board = Board::new_start();
@@ -437,6 +1216,113 @@ mod tests {
         assert!(is_valid);
     }
 
+    #[test]
+    fn check_move_gives_the_reason_of_invalid_moves() {
+        let board = Board::new_start();
+        assert_eq!(board.check_move(Player::Black, 4, 5), Ok(()));
+        assert_eq!(
+            board.check_move(Player::Black, 3, 3),
+            Err(BoardError::SquareOccupied { x: 3, y: 3 })
+        );
+        assert_eq!(
+            board.check_move(Player::Black, 0, 0),
+            Err(BoardError::NoCapture { x: 0, y: 0 })
+        );
+        assert_eq!(
+            board.check_move(Player::Black, 8, 0),
+            Err(BoardError::OutOfRange { x: 8, y: 0 })
+        );
+        assert_eq!(
+            BoardError::NoCapture { x: 0, y: 0 }.to_string(),
+            "A1 does not capture any piece"
+        );
+    }
+
+    #[test]
+    fn explain_move_gives_the_outcome_of_each_direction() {
+        let board = Board::new_start();
+        let explanations = board.explain_move(Player::Black, 5, 4).unwrap();
+        assert_eq!(explanations.len(), 8);
+        let outcome = |direction| {
+            explanations
+                .iter()
+                .find(|explanation| explanation.direction == direction)
+                .map(|explanation| explanation.outcome.clone())
+                .unwrap()
+        };
+        assert_eq!(
+            outcome((-1, 0)),
+            LineOutcome::Captures {
+                pieces: vec![(4, 4)],
+                closed_by: (3, 4)
+            }
+        );
+        assert_eq!(
+            outcome((-1, -1)),
+            LineOutcome::OwnPiece { position: (4, 3) }
+        );
+        assert_eq!(outcome((1, 0)), LineOutcome::Empty);
+
+        let explanations = board.explain_move(Player::Black, 5, 5).unwrap();
+        assert!(explanations
+            .iter()
+            .all(|explanation| !matches!(explanation.outcome, LineOutcome::Captures { .. })));
+        assert!(explanations.iter().any(|explanation| explanation.outcome
+            == LineOutcome::NotClosed {
+                pieces: vec![(4, 4), (3, 3)]
+            }));
+        let corner = board.explain_move(Player::Black, 0, 0).unwrap();
+        assert_eq!(corner[0].outcome, LineOutcome::Edge);
+        assert_eq!(
+            board.explain_move(Player::Black, 3, 3),
+            Err(BoardError::SquareOccupied { x: 3, y: 3 })
+        );
+    }
+
+    #[test]
+    fn flip_count_counts_the_flipped_pieces() {
+        let board = Board::new_start();
+        assert_eq!(board.flip_count(Player::Black, 5, 4), Ok(1));
+        assert_eq!(board.flip_count(Player::Black, 0, 0), Ok(0));
+        assert_eq!(board.flip_count(Player::Black, 3, 3), Ok(0));
+        assert!(board.flip_count(Player::Black, 8, 0).is_err());
+        let board = [(5, 4), (5, 5), (4, 5), (3, 5)]
+            .iter()
+            .zip([Player::Black, Player::White].iter().cycle())
+            .fold(board, |board, (&(x, y), &player)| {
+                board.play(player, x, y).unwrap().unwrap()
+            });
+        for (x, y) in board.legal_moves(Player::Black) {
+            let (_, flips) = board.play_with_flips(Player::Black, x, y).unwrap().unwrap();
+            assert_eq!(board.flip_count(Player::Black, x, y), Ok(flips.len() as u8));
+        }
+    }
+
+    #[test]
+    fn legal_moves_returns_valid_positions_in_grid_order() {
+        let board = Board::new_start();
+        assert_eq!(
+            board.legal_moves(Player::Black),
+            vec![(3, 2), (2, 3), (5, 4), (4, 5)]
+        );
+        assert_eq!(
+            board.legal_moves_iter(Player::White).collect::<Vec<_>>(),
+            vec![(4, 2), (5, 3), (2, 4), (3, 5)]
+        );
+        assert!(Board::new().legal_moves(Player::Black).is_empty());
+    }
+
+    #[test]
+    fn has_legal_move_checks_if_the_player_can_move() {
+        let mut board = Board::new();
+        assert!(!board.has_legal_move(Player::Black));
+        board.set_piece(0, 0, Some(Player::Black)).unwrap();
+        board.set_piece(1, 0, Some(Player::White)).unwrap();
+        assert!(board.has_legal_move(Player::Black));
+        assert!(!board.has_legal_move(Player::White));
+        assert!(Board::new_start().has_legal_move(Player::White));
+    }
+
     #[test]
     fn play_invalid_move_if_cell_not_empty() {
         let board = Board::new_start();
@@ -499,6 +1385,152 @@ mod tests {
         }
     }
 
+    #[test]
+    fn play_with_flips_reports_flipped_pieces() {
+        let board = Board::new_start();
+        let (new_board, flips) = board.play_with_flips(Player::Black, 5, 4).unwrap().unwrap();
+        assert_eq!(flips, vec![(4, 4)]);
+        assert_eq!(new_board.get_piece(4, 4).unwrap(), Some(Player::Black));
+        assert_eq!(new_board, board.play(Player::Black, 5, 4).unwrap().unwrap());
+        assert!(board
+            .play_with_flips(Player::Black, 0, 0)
+            .unwrap()
+            .is_none());
+        assert!(board.play_with_flips(Player::Black, 8, 0).is_err());
+    }
+
+    #[test]
+    fn apply_move_plays_in_place() {
+        let mut board = Board::new_start();
+        let undo = board.apply_move(Player::Black, 5, 4).unwrap();
+        assert_eq!(undo.player(), Player::Black);
+        assert_eq!(undo.position(), (5, 4));
+        assert_eq!(undo.flips(), vec![(4, 4)]);
+        let expected = Board::new_start()
+            .play(Player::Black, 5, 4)
+            .unwrap()
+            .unwrap();
+        assert_eq!(board, expected);
+    }
+
+    #[test]
+    fn undo_move_restores_the_board() {
+        let mut board = Board::new_start();
+        let first = board.apply_move(Player::Black, 5, 4).unwrap();
+        let after_first = board;
+        let second = board.apply_move(Player::White, 5, 5).unwrap();
+        board.undo_move(second);
+        assert_eq!(board, after_first);
+        board.undo_move(first);
+        assert_eq!(board, Board::new_start());
+    }
+
+    #[test]
+    fn apply_move_rejects_invalid_moves() {
+        let mut board = Board::new_start();
+        assert_eq!(
+            board.apply_move(Player::Black, 0, 0),
+            Err(BoardError::NoCapture { x: 0, y: 0 })
+        );
+        assert_eq!(
+            board.apply_move(Player::Black, 8, 0),
+            Err(BoardError::OutOfRange { x: 8, y: 0 })
+        );
+        assert!(board.iter().eq(Board::new_start().iter()));
+    }
+
+    #[test]
+    fn boards_are_equal_if_cells_are_the_same() {
+        use std::collections::HashSet;
+        let board = Board::new_start();
+        let mut other_board = Board::new_start();
+        assert_eq!(board, other_board);
+        other_board.set_piece(0, 0, Some(Player::Black)).unwrap();
+        assert_ne!(board, other_board);
+        let boards: HashSet<Board> = vec![board, other_board, Board::new_start()]
+            .into_iter()
+            .collect();
+        assert_eq!(boards.len(), 2);
+        assert!(boards.contains(&Board::new_start()));
+    }
+
+    #[test]
+    fn symmetries_transform_cells() {
+        let mut board = Board::new();
+        board.set_piece(1, 0, Some(Player::Black)).unwrap();
+        assert_eq!(
+            board.rotate90().get_piece(7, 1).unwrap(),
+            Some(Player::Black)
+        );
+        assert_eq!(
+            board.rotate180().get_piece(6, 7).unwrap(),
+            Some(Player::Black)
+        );
+        assert_eq!(
+            board.rotate270().get_piece(0, 6).unwrap(),
+            Some(Player::Black)
+        );
+        assert_eq!(
+            board.mirror_horizontal().get_piece(6, 0).unwrap(),
+            Some(Player::Black)
+        );
+        assert_eq!(
+            board.mirror_vertical().get_piece(1, 7).unwrap(),
+            Some(Player::Black)
+        );
+        assert_eq!(
+            board.transpose().get_piece(0, 1).unwrap(),
+            Some(Player::Black)
+        );
+        assert_eq!(
+            board.anti_transpose().get_piece(7, 6).unwrap(),
+            Some(Player::Black)
+        );
+        for &symmetry in Symmetry::ALL.iter() {
+            assert_eq!(
+                board.transform(symmetry).transform(symmetry.inverse()),
+                board
+            );
+        }
+    }
+
+    #[test]
+    fn canonical_is_shared_by_symmetric_boards() {
+        // the four first moves are symmetric.
+        let start = Board::new_start();
+        let canonical = start
+            .play(Player::Black, 5, 4)
+            .unwrap()
+            .unwrap()
+            .canonical();
+        for &(x, y) in [(3, 2), (2, 3), (4, 5)].iter() {
+            let board = start.play(Player::Black, x, y).unwrap().unwrap();
+            assert_eq!(board.canonical(), canonical);
+        }
+        let (canonical, symmetry) = start.canonical_with_symmetry();
+        assert_eq!(start.transform(symmetry), canonical);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn board_serialization_roundtrip() {
+        let board = Board::new_start();
+        let json = serde_json::to_string(&board).unwrap();
+        let deserialized = serde_json::from_str::<Board>(&json).unwrap();
+        assert_eq!(deserialized, board);
+        assert_eq!(deserialized.count_pieces(), (2, 2));
+        assert_eq!(serde_json::to_string(&Player::Black).unwrap(), "\"Black\"");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn board_serialization_checks_the_size() {
+        let board = Board::<6>::start();
+        let json = serde_json::to_string(&board).unwrap();
+        assert_eq!(serde_json::from_str::<Board<6>>(&json).unwrap(), board);
+        assert!(serde_json::from_str::<Board>(&json).is_err());
+    }
+
     #[test]
     fn count_players_pieces() {
         let mut board = Board::new_start();
@@ -508,6 +1540,70 @@ mod tests {
         assert_eq!(white, 3)
     }
 
+    #[test]
+    fn pieces_counts_follow_the_moves() {
+        // counts the cells one by one, as the board did before.
+        fn recount(board: &Board) -> (u8, u8) {
+            let count = |player| board.iter().filter(|&(_, _, p)| p == Some(player)).count();
+            (count(Player::Black) as u8, count(Player::White) as u8)
+        }
+
+        let mut board = Board::new_start();
+        board.set_piece(0, 0, Some(Player::White)).unwrap();
+        board.set_piece(0, 0, Some(Player::Black)).unwrap();
+        board.set_piece(0, 0, None).unwrap();
+        assert_eq!(board.count_pieces(), (2, 2));
+
+        let mut player = Player::Black;
+        while let Some((x, y)) = board.legal_moves(player).pop() {
+            let before = board;
+            let undo = board.apply_move(player, x, y).unwrap();
+            assert_eq!(board.count_pieces(), recount(&board));
+            assert_eq!(board.rotate90().count_pieces(), board.count_pieces());
+            board.undo_move(undo);
+            assert_eq!(board.count_pieces(), before.count_pieces());
+            board = board.play(player, x, y).unwrap().unwrap();
+            assert_eq!(board.count_pieces(), recount(&board));
+            player = player.opponent();
+        }
+    }
+
+    #[test]
+    fn piece_count_counts_by_region() {
+        let mut board = Board::new_start();
+        board.set_piece(0, 0, Some(Player::White)).unwrap();
+        board.set_piece(1, 0, Some(Player::White)).unwrap();
+        board.set_piece(1, 1, Some(Player::Black)).unwrap();
+        let count = board.piece_count();
+        let black_regions = RegionCount {
+            corners: 0,
+            edges: 0,
+            interior: 3,
+        };
+        let white_regions = RegionCount {
+            corners: 1,
+            edges: 1,
+            interior: 2,
+        };
+        assert_eq!(count.of(Player::Black), black_regions);
+        assert_eq!(count.of(Player::White), white_regions);
+        assert_eq!((count.black(), count.white()), board.count_pieces());
+        assert_eq!(count.total(), 7);
+    }
+
+    #[test]
+    fn empties_counts_the_empty_cells() {
+        let mut board = Board::new_start();
+        assert_eq!(board.empties(), 60);
+        assert!(!board.is_full());
+        for (x, y) in GridIterator::new() {
+            board.set_piece(x, y, Some(Player::White)).unwrap();
+        }
+        assert_eq!(board.empties(), 0);
+        assert!(board.is_full());
+        assert_eq!(Board::<6>::start().empties(), 32);
+    }
+
     #[test]
     fn fmt_build_a_board_representation() {
         let board = Board::new_start();
@@ -524,6 +1620,85 @@ mod tests {
         assert_eq!(format!("{}", board), expected);
     }
 
+    #[test]
+    fn square_methods_never_fail() {
+        let mut board = Board::new_start();
+        let square = Square::from_notation("F5").unwrap();
+        assert!(board.is_move_valid_at(Player::Black, square));
+        let expected = board.play(Player::Black, 5, 4).unwrap();
+        assert_eq!(board.play_at(Player::Black, square), expected);
+        let corner = Square::new(0, 0).unwrap();
+        assert_eq!(board.play_at(Player::Black, corner), None);
+        board.set_piece_at(corner, Some(Player::White));
+        assert_eq!(board.piece_at(corner), Some(Player::White));
+    }
+
+    #[test]
+    fn other_sizes_start_in_the_center() {
+        let board = Board::<6>::start();
+        assert_eq!(board.size(), 6);
+        assert_eq!(board.get_piece(2, 2).unwrap(), Some(Player::White));
+        assert_eq!(board.get_piece(3, 2).unwrap(), Some(Player::Black));
+        assert_eq!(board.count_pieces(), (2, 2));
+        assert!(board.get_piece(6, 0).is_err());
+        assert_eq!(
+            Board::<10>::start().legal_moves(Player::Black),
+            vec![(4, 3), (3, 4), (6, 5), (5, 6)]
+        );
+    }
+
+    #[test]
+    fn other_sizes_play_with_the_same_rules() {
+        // Black at A1, White from B1 to I1 on a 10x10 board : Black captures
+        // 8 pieces at J1.
+        let mut board = Board::<10>::empty();
+        board.set_piece(0, 0, Some(Player::Black)).unwrap();
+        for x in 1..=8 {
+            board.set_piece(x, 0, Some(Player::White)).unwrap();
+        }
+        assert_eq!(board.legal_moves(Player::Black), vec![(9, 0)]);
+        let undo = board.apply_move(Player::Black, 9, 0).unwrap();
+        assert_eq!(undo.flips().len(), 8);
+        assert_eq!(board.count_pieces(), (10, 0));
+        board.undo_move(undo);
+        assert_eq!(board.count_pieces(), (1, 8));
+
+        // moves on a smaller board never leave it.
+        let mut board = Board::<4>::empty();
+        board.set_piece(1, 0, Some(Player::Black)).unwrap();
+        board.set_piece(2, 0, Some(Player::White)).unwrap();
+        assert_eq!(board.legal_moves(Player::Black), vec![(3, 0)]);
+        assert!(board.play(Player::Black, 4, 0).is_err());
+    }
+
+    #[test]
+    fn other_sizes_have_symmetries() {
+        let board = Board::<6>::start()
+            .play(Player::Black, 1, 2)
+            .unwrap()
+            .unwrap();
+        assert_eq!(board.rotate90().rotate270(), board);
+        assert_eq!(
+            board.mirror_horizontal().get_piece(4, 2).unwrap(),
+            Some(Player::Black)
+        );
+        assert_eq!(board.rotate180().canonical(), board.canonical());
+    }
+
+    #[test]
+    fn fmt_alternate_adds_coordinates_labels() {
+        let board = Board::new_start();
+        let representation = format!("{:#}", board);
+        let lines: Vec<&str> = representation.lines().collect();
+        assert_eq!(lines.len(), 9);
+        assert_eq!(lines[0], "  ABCDEFGH");
+        assert_eq!(lines[4], "4    OX   .");
+        assert_eq!(lines[8], "8         .");
+        let representation = format!("{:#}", Board::<10>::start());
+        assert!(representation.starts_with("   ABCDEFGHIJ\n 1 "));
+        assert!(representation.ends_with("\n10           .\n"));
+    }
+
     #[test]
     fn grid_iterator_generates_all_coordonates() {
         let mut cells = [false; 64];
@@ -536,7 +1711,7 @@ mod tests {
 
     #[test]
     fn cell_navigation() {
-        let mut cn = CellsNavigator::new((3, 3), (1, -1)).unwrap();
+        let mut cn = CellsNavigator::<8>::new((3, 3), (1, -1)).unwrap();
         assert_eq!(cn.next(), Some((4, 2)));
         assert_eq!(cn.next(), Some((5, 1)));
         assert_eq!(cn.next(), Some((6, 0)));
@@ -545,7 +1720,7 @@ mod tests {
 
     #[test]
     fn cell_navigation_reverse() {
-        let mut cn = CellsNavigator::new((3, 3), (1, -1)).unwrap();
+        let mut cn = CellsNavigator::<8>::new((3, 3), (1, -1)).unwrap();
         assert_eq!(cn.next(), Some((4, 2)));
         cn.reverse();
         assert_eq!(cn.next(), Some((3, 3)));
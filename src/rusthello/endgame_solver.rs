@@ -0,0 +1,307 @@
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::cell::Cell;
+
+use super::bitboard::*;
+use super::board::*;
+use super::moves::*;
+use super::virtual_player::*;
+
+/// Exact endgame solver : explores the game tree up to the end of the game
+/// and returns the move maximizing the final disc difference.
+/// It's only usable when few empty cells remain, the cost grows exponentially
+/// with the number of empty cells.
+pub struct EndgameSolver {
+    move_count: Cell<u32>,
+    resign_threshold: Option<i32>,
+    wants_to_resign: Cell<bool>,
+}
+
+impl EndgameSolver {
+    /// Creates a new EndgameSolver.
+    pub fn new() -> Self {
+        Self {
+            move_count: Cell::new(0),
+            resign_threshold: None,
+            wants_to_resign: Cell::new(false),
+        }
+    }
+
+    /// Makes the solver resign when the proven final disc difference is at or
+    /// below -threshold (from its point of view), `i32::MIN` making it always
+    /// resign.
+    pub fn with_resign_threshold(mut self, threshold: i32) -> Self {
+        self.resign_threshold = Some(threshold);
+        self
+    }
+
+    /// Solves the given board for the given player.
+    /// Returns the best move and the final disc difference (from the player
+    /// point of view) if both players play perfectly, or None if the player
+    /// can't move.
+    pub fn solve(&self, board: &Board, me: Player) -> Option<((u8, u8), i32)> {
+        self.solve_within(board, me, Self::SCORE_BOUND)
+    }
+
+    /// Solves the given board for the given player only up to the win, loss
+    /// or draw, which is much faster than the exact score with more empty
+    /// cells. Returns the best move and 1 for a win, 0 for a draw, -1 for a
+    /// loss, or None if the player can't move.
+    pub fn solve_wld(&self, board: &Board, me: Player) -> Option<((u8, u8), i32)> {
+        self.solve_within(board, me, 1)
+            .map(|(position, score)| (position, score.signum()))
+    }
+
+    /// Returns the line played from the given board if both players play
+    /// perfectly, passes included, up to the end of the game. With `wld`,
+    /// the moves only keep the win, loss or draw (see `solve_wld`).
+    pub fn best_line(&self, board: &Board, me: Player, wld: bool) -> Vec<Move> {
+        let mut line = Vec::new();
+        let mut board = *board;
+        let mut player = me;
+        loop {
+            let solution = if wld {
+                self.solve_wld(&board, player)
+            } else {
+                self.solve(&board, player)
+            };
+            match solution {
+                Some(((x, y), _)) => {
+                    line.push(Move::Place { x, y });
+                    board = board
+                        .play(player, x, y)
+                        .expect("Unexpected error while solving endgame.")
+                        .expect("Unexpected invalid move while solving endgame.");
+                }
+                None if board.has_legal_move(player.opponent()) => line.push(Move::Pass),
+                None => return line,
+            }
+            player = player.opponent();
+        }
+    }
+
+    /// Searches the root moves within the window (-bound, bound), the scores
+    /// beyond it being only bounds of the exact ones.
+    fn solve_within(&self, board: &Board, me: Player, bound: i32) -> Option<((u8, u8), i32)> {
+        let board = BitBoard::from(board);
+        let mut best: Option<((u8, u8), i32)> = None;
+        let mut alpha = -bound;
+        for (x, y) in board.legal_moves(me) {
+            let board_after_move = board
+                .play(me, x, y)
+                .expect("Unexpected error while solving endgame.")
+                .expect("Unexpected invalid move while solving endgame.");
+            self.move_count.set(self.move_count.get() + 1);
+            let score = -self.negamax(&board_after_move, me.opponent(), -bound, -alpha);
+            if best.is_none() || score > alpha {
+                alpha = score;
+                best = Some(((x, y), score));
+            }
+            if score >= bound {
+                break;
+            }
+        }
+
+        best
+    }
+
+    /// Upper bound of any disc difference, used as an infinite window.
+    const SCORE_BOUND: i32 = 65;
+
+    /// Negamax implementation with alpha-beta pruning, returns the final
+    /// disc difference from the given player point of view.
+    /// It uses a BitBoard, much faster than a Board to generate and play
+    /// moves.
+    fn negamax(&self, board: &BitBoard, player: Player, alpha: i32, beta: i32) -> i32 {
+        let mut moves = board.legal_moves_mask(player);
+        if moves == 0 {
+            return if board.has_legal_move(player.opponent()) {
+                // the player passes.
+                -self.negamax(board, player.opponent(), -beta, -alpha)
+            } else {
+                // the game is over.
+                Self::disc_difference(board, player)
+            };
+        }
+
+        let mut alpha = alpha;
+        while moves != 0 {
+            // explores moves in grid order, from the lowest bit.
+            let index = moves.trailing_zeros() as u8;
+            moves &= moves - 1;
+            let board_after_move = board
+                .play(player, index % 8, index / 8)
+                .expect("Unexpected error while solving endgame.")
+                .expect("Unexpected invalid move while solving endgame.");
+            self.move_count.set(self.move_count.get() + 1);
+            let score = -self.negamax(&board_after_move, player.opponent(), -beta, -alpha);
+            if score >= beta {
+                return score;
+            }
+            if score > alpha {
+                alpha = score;
+            }
+        }
+
+        alpha
+    }
+
+    fn disc_difference(board: &BitBoard, player: Player) -> i32 {
+        let (black_pieces, white_pieces) = board.count_pieces();
+        let difference = black_pieces as i32 - white_pieces as i32;
+        match player {
+            Player::Black => difference,
+            Player::White => -difference,
+        }
+    }
+}
+
+impl Default for EndgameSolver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EngineInfo for EndgameSolver {
+    fn name(&self) -> String {
+        "EndgameSolver".to_string()
+    }
+
+    fn options(&self) -> String {
+        match self.resign_threshold {
+            Some(threshold) => format!("resign_threshold={}", threshold),
+            None => String::new(),
+        }
+    }
+}
+
+impl VirtualPlayer for EndgameSolver {
+    fn move_count(&self) -> u32 {
+        self.move_count.get()
+    }
+
+    fn wants_to_resign(&self) -> bool {
+        self.wants_to_resign.get()
+    }
+
+    fn compute_move(&self, board: &Board, me: Player) -> Option<(u8, u8)> {
+        self.compute_move_full(board, me)
+            .map(|analysis| analysis.position)
+    }
+
+    /// The score is the final disc difference. The solver does not keep the
+    /// expected line, the variation only holds the chosen move.
+    fn compute_move_full(&self, board: &Board, me: Player) -> Option<MoveAnalysis> {
+        let solution = self.solve(board, me);
+        let hopeless = match (solution, self.resign_threshold) {
+            (Some((_, score)), Some(threshold)) => score <= threshold.saturating_neg(),
+            _ => false,
+        };
+        self.wants_to_resign.set(hopeless);
+
+        solution.map(|(position, score)| MoveAnalysis {
+            position,
+            score: Some(score),
+            variation: vec![position],
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Builds a board where White has a single possible move at (3, 0),
+    /// after which none of the players can move and Black wins 8 to 4.
+    fn lost_board_for_white() -> Board {
+        let mut board = Board::new();
+        board.set_piece(0, 0, Some(Player::White)).unwrap();
+        board.set_piece(1, 0, Some(Player::Black)).unwrap();
+        board.set_piece(2, 0, Some(Player::Black)).unwrap();
+        for x in 0..=7 {
+            board.set_piece(x, 2, Some(Player::Black)).unwrap();
+        }
+        board
+    }
+
+    #[test]
+    fn solve_returns_none_if_the_player_cant_move() {
+        let mut board = Board::new();
+        board.set_piece(0, 0, Some(Player::Black)).unwrap();
+        let solver = EndgameSolver::new();
+        assert!(solver.solve(&board, Player::White).is_none());
+    }
+
+    #[test]
+    fn solve_returns_the_exact_final_score() {
+        let board = lost_board_for_white();
+        let solver = EndgameSolver::new();
+        assert_eq!(solver.solve(&board, Player::White), Some(((3, 0), -4)));
+    }
+
+    #[test]
+    fn compute_move_full_returns_the_final_score() {
+        let board = lost_board_for_white();
+        let solver = EndgameSolver::new();
+        let analysis = solver.compute_move_full(&board, Player::White).unwrap();
+        assert_eq!(analysis.position, (3, 0));
+        assert_eq!(analysis.score, Some(-4));
+    }
+
+    #[test]
+    fn solve_handles_passes() {
+        // Black fills the two last cells, White can't move in between.
+        let mut board = Board::new();
+        for (x, y) in GridIterator::new() {
+            if y < 6 {
+                board.set_piece(x, y, Some(Player::Black)).unwrap();
+            } else if y == 6 {
+                board.set_piece(x, y, Some(Player::White)).unwrap();
+            } else if x > 0 && x < 7 {
+                board.set_piece(x, y, Some(Player::Black)).unwrap();
+            }
+        }
+        let solver = EndgameSolver::new();
+        let (_, score) = solver.solve(&board, Player::Black).unwrap();
+        assert_eq!(score, 56);
+    }
+
+    #[test]
+    fn solve_wld_returns_the_sign_of_the_final_score() {
+        let board = lost_board_for_white();
+        let solver = EndgameSolver::new();
+        assert_eq!(solver.solve_wld(&board, Player::White), Some(((3, 0), -1)));
+    }
+
+    #[test]
+    fn best_line_plays_to_the_end_of_the_game() {
+        let board = lost_board_for_white();
+        let solver = EndgameSolver::new();
+        let line = solver.best_line(&board, Player::White, false);
+        assert_eq!(line, vec![Move::Place { x: 3, y: 0 }]);
+        assert_eq!(solver.best_line(&board, Player::White, true), line);
+        let line_after_pass = solver.best_line(&board, Player::Black, false);
+        assert_eq!(
+            line_after_pass,
+            vec![Move::Pass, Move::Place { x: 3, y: 0 }]
+        );
+    }
+
+    #[test]
+    fn solver_resigns_when_loss_is_proven_beyond_threshold() {
+        let board = lost_board_for_white();
+        let solver = EndgameSolver::new().with_resign_threshold(4);
+        solver.compute_move(&board, Player::White);
+        assert!(solver.wants_to_resign());
+        let solver = EndgameSolver::new().with_resign_threshold(5);
+        solver.compute_move(&board, Player::White);
+        assert!(!solver.wants_to_resign());
+        let solver = EndgameSolver::new().with_resign_threshold(i32::MIN);
+        solver.compute_move(&board, Player::White);
+        assert!(solver.wants_to_resign());
+    }
+}
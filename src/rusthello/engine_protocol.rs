@@ -0,0 +1,146 @@
+use std::io::{BufRead, Write};
+
+use super::board::*;
+use super::moves::*;
+use super::position::*;
+use super::virtual_player::*;
+
+/// Sends a move request, and returns the move answered by the engine
+/// (None if it passes). Illegal moves are reported as errors.
+pub(crate) fn request_move<R, W>(
+    reader: &mut R,
+    writer: &mut W,
+    board: &Board,
+    me: Player,
+) -> Result<Option<(u8, u8)>, String>
+where
+    R: BufRead,
+    W: Write,
+{
+    writeln!(writer, "move {}", Position::new(*board, me))
+        .and_then(|_| writer.flush())
+        .map_err(|error| format!("unable to send the request to the engine : {}", error))?;
+
+    let answer = read_line(reader)?.ok_or_else(|| "the engine stopped answering".to_string())?;
+    let (x, y) = match answer.parse::<Move>() {
+        Ok(Move::Place { x, y }) => (x, y),
+        Ok(Move::Pass) => return Ok(None),
+        Err(_) => return Err(format!("the engine answered an invalid move : {}", answer)),
+    };
+    if !board
+        .is_move_valid(me, x, y)
+        .map_err(|error| error.to_string())?
+    {
+        return Err(format!("the engine played an illegal move : {}", answer));
+    }
+
+    Ok(Some((x, y)))
+}
+
+/// Ends the session with the engine.
+pub(crate) fn send_quit<W: Write>(writer: &mut W) -> Result<(), String> {
+    writeln!(writer, "quit")
+        .and_then(|_| writer.flush())
+        .map_err(|error| format!("unable to send the request to the engine : {}", error))
+}
+
+/// Answers the requests read from the given reader using the given player,
+/// until the `quit` request or the end of the input.
+/// It's the engine side of the protocol used by ExternalEnginePlayer and
+/// NetworkPlayer, allowing to expose any VirtualPlayer to a remote peer.
+///
+/// Requests and answers are made of one line each :
+/// * request : `move <position>`, where position is in the text format of
+///   `Position` : the 64 cells of the board from A1 to H8 row by row (`X`
+///   for Black, `O` for White, `-` for empty cells), and the player to move
+///   (`X` or `O`).
+/// * answer : the move in standard notation (ie `f5`), or `pass`.
+///
+/// The request `quit` ends the session.
+pub fn serve_engine<R, W>(
+    reader: &mut R,
+    writer: &mut W,
+    player: &dyn VirtualPlayer,
+) -> Result<(), String>
+where
+    R: BufRead,
+    W: Write,
+{
+    while let Some(request) = read_line(reader)? {
+        let mut words = request.splitn(2, ' ');
+        match (words.next(), words.next()) {
+            (Some("quit"), None) => break,
+            (Some("move"), Some(position)) => {
+                let Position { board, player: me } = position.parse()?;
+                let answer = Move::from(player.compute_move(&board, me))
+                    .to_string()
+                    .to_ascii_lowercase();
+                writeln!(writer, "{}", answer)
+                    .and_then(|_| writer.flush())
+                    .map_err(|error| format!("unable to send the answer : {}", error))?;
+            }
+            _ => return Err(format!("invalid request : {}", request)),
+        }
+    }
+
+    Ok(())
+}
+
+/// Reads a trimmed line, or None at the end of the input.
+fn read_line<R: BufRead>(reader: &mut R) -> Result<Option<String>, String> {
+    let mut line = String::new();
+    let size = reader
+        .read_line(&mut line)
+        .map_err(|error| format!("unable to read from the engine : {}", error))?;
+    if size == 0 {
+        return Ok(None);
+    }
+
+    Ok(Some(line.trim().to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn request_move_returns_the_engine_move() {
+        let mut reader = Cursor::new("F5\n");
+        let mut writer = Vec::new();
+        let position = request_move(&mut reader, &mut writer, &Board::new_start(), Player::Black);
+        assert_eq!(position, Ok(Some((5, 4))));
+        let request = String::from_utf8(writer).unwrap();
+        assert_eq!(request, format!("move {}\n", Position::new_start()));
+    }
+
+    #[test]
+    fn request_move_rejects_illegal_moves() {
+        let mut reader = Cursor::new("a1\n");
+        let mut writer = Vec::new();
+        let position = request_move(&mut reader, &mut writer, &Board::new_start(), Player::Black);
+        assert!(position.is_err());
+    }
+
+    #[test]
+    fn serve_engine_answers_requests() {
+        let request = format!("move {}\nquit\n", Position::new_start());
+        let mut reader = Cursor::new(request);
+        let mut writer = Vec::new();
+        let player = AlphaBeta::new(1);
+        serve_engine(&mut reader, &mut writer, &player).unwrap();
+        let answer = String::from_utf8(writer).unwrap();
+        let (x, y) = answer.trim().parse::<Move>().unwrap().position().unwrap();
+        assert!(Board::new_start()
+            .is_move_valid(Player::Black, x, y)
+            .unwrap());
+    }
+
+    #[test]
+    fn serve_engine_rejects_invalid_requests() {
+        let mut reader = Cursor::new("hello\n");
+        let mut writer = Vec::new();
+        let player = AlphaBeta::new(1);
+        assert!(serve_engine(&mut reader, &mut writer, &player).is_err());
+    }
+}
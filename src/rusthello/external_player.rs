@@ -0,0 +1,176 @@
+use std::cell::RefCell;
+use std::io::BufReader;
+use std::process::{Child, ChildStdin, ChildStdout, Command, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use super::board::*;
+use super::engine_protocol::*;
+use super::virtual_player::*;
+
+/// A virtual player driving an external engine process, to match rusthello
+/// against other engines.
+///
+/// The engine reads requests on its standard input and answers on its
+/// standard output, see `serve_engine` for the protocol. When the player is
+/// dropped the `quit` request is sent to the engine, which is killed if it
+/// doesn't stop shortly.
+pub struct ExternalEnginePlayer {
+    program: String,
+    child: Child,
+    io: RefCell<EngineIo>,
+    last_error: RefCell<Option<String>>,
+}
+
+struct EngineIo {
+    stdin: ChildStdin,
+    stdout: BufReader<ChildStdout>,
+}
+
+impl ExternalEnginePlayer {
+    // Time given to the engine to stop after the quit request, and delay
+    // between the checks of its end.
+    const QUIT_DELAY: Duration = Duration::from_millis(500);
+    const QUIT_POLL_DELAY: Duration = Duration::from_millis(10);
+
+    /// Starts the given engine program.
+    pub fn spawn(program: &str, args: &[&str]) -> Result<Self, String> {
+        let mut child = Command::new(program)
+            .args(args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()
+            .map_err(|error| format!("unable to start the engine {} : {}", program, error))?;
+        let stdin = child
+            .stdin
+            .take()
+            .expect("Unexpected missing engine stdin.");
+        let stdout = child
+            .stdout
+            .take()
+            .expect("Unexpected missing engine stdout.");
+
+        Ok(Self {
+            program: program.to_string(),
+            child,
+            io: RefCell::new(EngineIo {
+                stdin,
+                stdout: BufReader::new(stdout),
+            }),
+            last_error: RefCell::new(None),
+        })
+    }
+
+    /// Returns the error which occurred during the last `compute_move` call,
+    /// if any.
+    pub fn last_error(&self) -> Option<String> {
+        self.last_error.borrow().clone()
+    }
+}
+
+impl EngineInfo for ExternalEnginePlayer {
+    fn name(&self) -> String {
+        "ExternalEngine".to_string()
+    }
+
+    fn version(&self) -> String {
+        "unknown".to_string()
+    }
+
+    fn author(&self) -> String {
+        "unknown".to_string()
+    }
+
+    fn options(&self) -> String {
+        format!("program={}", self.program)
+    }
+}
+
+impl VirtualPlayer for ExternalEnginePlayer {
+    fn move_count(&self) -> u32 {
+        0
+    }
+
+    fn compute_move(&self, board: &Board, me: Player) -> Option<(u8, u8)> {
+        let mut io = self.io.borrow_mut();
+        let EngineIo { stdin, stdout } = &mut *io;
+        match request_move(stdout, stdin, board, me) {
+            Ok(position) => {
+                *self.last_error.borrow_mut() = None;
+                position
+            }
+            Err(error) => {
+                *self.last_error.borrow_mut() = Some(error);
+                None
+            }
+        }
+    }
+}
+
+impl Drop for ExternalEnginePlayer {
+    fn drop(&mut self) {
+        if send_quit(&mut self.io.get_mut().stdin).is_ok() {
+            let deadline = Instant::now() + Self::QUIT_DELAY;
+            while Instant::now() < deadline {
+                match self.child.try_wait() {
+                    Ok(Some(_)) => return,
+                    Ok(None) => thread::sleep(Self::QUIT_POLL_DELAY),
+                    Err(_) => break,
+                }
+            }
+        }
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    fn engine_answering(answer: &str) -> ExternalEnginePlayer {
+        let script = format!(
+            "while read request; do [ \"$request\" = quit ] && exit; echo {}; done",
+            answer
+        );
+        ExternalEnginePlayer::spawn("sh", &["-c", &script]).unwrap()
+    }
+
+    #[test]
+    fn external_engine_returns_the_engine_move() {
+        let engine = engine_answering("F5");
+        let position = engine.compute_move(&Board::new_start(), Player::Black);
+        assert_eq!(position, Some((5, 4)));
+        assert!(engine.last_error().is_none());
+    }
+
+    #[test]
+    fn external_engine_rejects_illegal_moves() {
+        let engine = engine_answering("a1");
+        let position = engine.compute_move(&Board::new_start(), Player::Black);
+        assert_eq!(position, None);
+        assert!(engine.last_error().is_some());
+    }
+
+    #[test]
+    fn external_engine_can_pass() {
+        let engine = engine_answering("pass");
+        let position = engine.compute_move(&Board::new_start(), Player::Black);
+        assert_eq!(position, None);
+        assert!(engine.last_error().is_none());
+    }
+
+    #[test]
+    fn drop_kills_engines_ignoring_quit() {
+        let script = "while read request; do echo F5; done";
+        let engine = ExternalEnginePlayer::spawn("sh", &["-c", script]).unwrap();
+        let start = Instant::now();
+        drop(engine);
+        assert!(start.elapsed() < Duration::from_secs(5));
+    }
+
+    #[test]
+    fn spawn_fails_for_unknown_programs() {
+        assert!(ExternalEnginePlayer::spawn("/nonexistent/engine", &[]).is_err());
+    }
+}
@@ -1,53 +1,560 @@
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{cell::OnceCell, error, fmt, mem, time::Duration};
+#[cfg(all(feature = "serde", feature = "std"))]
+use std::{fs::File, io, path::Path};
+
 use super::board::*;
+use super::endgame_solver::*;
+use super::game_builder::*;
+use super::game_observer::*;
+use super::game_result::*;
+use super::game_statistics::*;
 use super::game_status::*;
+use super::moves::*;
+use super::rules::*;
+use super::square::*;
+use super::time_control::*;
+use super::virtual_player::*;
+
 /// Manage an Othello game workflow
-pub struct Game {
-    board: Board,
+/// By default a blocked player passes automatically, the turn staying to its
+/// opponent. With explicit passes (see `ExplicitPasses`), the turn goes to
+/// the blocked player who has to pass with `Game::pass`.
+/// Observers added to the game are notified of its moves, passes, undos and
+/// of its end.
+/// Games are played on 8x8 boards, other sizes being available through
+/// `Game::<N>::default()` or `GameBuilder::<N>::default()` (ie 6x6 or 10x10).
+/// The standard rules are used, variants (ie `AntiOthello`, `Reversi` or
+/// `Handicapped`) being given with `GameBuilder::with_rules` (see `Rules`).
+pub struct Game<const N: usize = 8, R: Rules<N> = Othello> {
+    rules: R,
+    // board and player at the start of the game, before any action.
+    start: (Board<N>, Player),
+    board: Board<N>,
     player: Option<Player>,
     opponent_is_blocked: bool,
     status: GameStatus,
+    resigned: Option<Player>,
+    lost_on_time: Option<Player>,
+    // official score proven by the endgame solver, for adjudicated games.
+    adjudicated: Option<(u8, u8)>,
+    history: Vec<HistoryEntry>,
+    // states before each action, used to undo them.
+    snapshots: Vec<Snapshot<N>>,
+    // actions undone, the last one being redone first, forgotten once
+    // another action is played.
+    undone: Vec<HistoryEntry>,
+    observers: Vec<Box<dyn GameObserver<N, R>>>,
+    // count of pieces still to place on the central squares, for games with
+    // a placement phase.
+    placements_left: u8,
+    // clocks of Black and White, for timed games.
+    clocks: Option<[Clock; 2]>,
+    // legal moves of the current player, computed once per turn.
+    legal_moves: OnceCell<Vec<(u8, u8)>>,
+    // data recorded for each move, when statistics are enabled.
+    statistics: Option<GameStatistics>,
+}
+
+/// Returns the central squares of a board of the given size, where the pieces
+/// are placed during the placement phase.
+pub(crate) fn center_squares(size: u8) -> [(u8, u8); 4] {
+    let middle = size / 2;
+    [
+        (middle - 1, middle - 1),
+        (middle, middle - 1),
+        (middle - 1, middle),
+        (middle, middle),
+    ]
 }
 
 impl Game {
     /// Create a new standard game
     pub fn new() -> Game {
-        let board = Board::new_start();
-        let mut game = Game {
-            board: board,
-            player: Some(Player::Black),
+        GameBuilder::new().build()
+    }
+
+    /// Replays a transcript from the start of a standard game, see
+    /// `Game::play_transcript`.
+    pub fn from_transcript(transcript: &str) -> Result<Game, GameError> {
+        let mut game = Game::new();
+        game.play_transcript(transcript)?;
+        Ok(game)
+    }
+}
+
+impl<R: Rules> Game<8, R> {
+    /// Asks the given engine for the move of the current player, plays it
+    /// and returns it. A player having to pass passes without asking the
+    /// engine, and during the placement phase the first free central square
+    /// is taken. An engine giving no move, or wanting to resign, resigns the
+    /// game. The time spent isn't charged to the clocks, see
+    /// `Game::play_timed` for timed games.
+    pub fn play_ai_turn(&mut self, engine: &dyn VirtualPlayer) -> Result<Move, GameError> {
+        let player = self.player.ok_or(GameError::GameOver)?;
+        let played = if self.must_pass() {
+            Move::Pass
+        } else if self.placement_phase() {
+            Move::from(self.legal_moves().first().copied())
+        } else {
+            match engine.compute_move(&self.board, player) {
+                Some((x, y)) if !engine.wants_to_resign() => Move::Place { x, y },
+                _ => {
+                    self.resign(player)?;
+                    return Err(GameError::Resigned { player });
+                }
+            }
+        };
+        self.play_move(player, played)?;
+        Ok(played)
+    }
+
+    /// Ends the game with the official score of a perfect play from the
+    /// current position, proven by the given solver. As the solver explores
+    /// the whole game tree, it's only usable when few empty squares remain.
+    /// The solver plays standard Othello, the games of other variants (or
+    /// during their placement phase) being rejected.
+    pub fn adjudicate(&mut self, solver: &EndgameSolver) -> Result<GameResult, GameError> {
+        let mut player = self.player.ok_or(GameError::GameOver)?;
+        if !self.rules.standard_play() || self.placement_phase() {
+            return Err(GameError::UnsupportedRules);
+        }
+        let mut board = self.board;
+        let mut passed = false;
+        loop {
+            match solver.solve(&board, player) {
+                Some(((x, y), _)) => {
+                    board = board
+                        .play(player, x, y)?
+                        .expect("Unexpected invalid move from the solver.");
+                    passed = false;
+                }
+                None if passed => break,
+                None => passed = true,
+            }
+            player = player.opponent();
+        }
+        self.adjudicated = self.rules.result(&board).score();
+        self.player = None;
+        self.notify(|observer, game| observer.on_game_over(game));
+        Ok(self
+            .result()
+            .expect("Unexpected running game after its adjudication."))
+    }
+}
+
+impl<const N: usize, R: Rules<N>> Game<N, R> {
+    /// Creates a game starting from the setup of the given builder. Unless
+    /// passes are explicit, a blocked player passes at once.
+    pub(crate) fn from_builder(builder: &GameBuilder<N, R>) -> Self {
+        let board = builder.starting_board();
+        let player = builder.player;
+        let placements_left = if builder.rules.placement_phase() {
+            center_squares(board.size()).len() as u8
+        } else {
+            0
+        };
+        let mut game = Self {
+            rules: builder.rules.clone(),
+            start: (board, player),
+            board,
+            player: Some(player),
             opponent_is_blocked: false,
             status: Default::default(),
+            resigned: None,
+            lost_on_time: None,
+            adjudicated: None,
+            history: Vec::new(),
+            snapshots: Vec::new(),
+            undone: Vec::new(),
+            observers: Vec::new(),
+            placements_left,
+            clocks: builder
+                .clocks
+                .map(|[black, white]| [Clock::new(black), Clock::new(white)]),
+            legal_moves: OnceCell::new(),
+            statistics: if builder.statistics {
+                Some(GameStatistics::default())
+            } else {
+                None
+            },
         };
         game.update_status();
+        if game.game_over() {
+            game.player = None;
+        } else if !game.rules.explicit_passes() && game.must_pass() {
+            game.record(player, Move::Pass);
+            game.player = Some(player.opponent());
+            game.opponent_is_blocked = true;
+        }
 
         game
     }
 
-    pub fn board(&self) -> &Board {
+    /// Plays the moves of a transcript in standard notation (ie "F5D6C3"),
+    /// ignoring case and whitespaces, the passes being implicit. Each move is
+    /// checked against the rules, the error giving the ply of the first
+    /// invalid move (counting the passes played automatically) and its index
+    /// in the transcript (starting at 1), the previous moves being played.
+    pub fn play_transcript(&mut self, transcript: &str) -> Result<(), GameError> {
+        for (index, notation) in split_transcript(transcript).iter().enumerate() {
+            self.play_notation(notation)
+                .map_err(|error| GameError::Transcript {
+                    ply: self.ply() + 1,
+                    move_index: index + 1,
+                    error: Box::new(error),
+                })?;
+        }
+
+        Ok(())
+    }
+
+    /// Gives a clock to each player, running according to the given time
+    /// controls. The time spent is given to `Game::play_timed`.
+    pub fn with_clocks(mut self, black: TimeControl, white: TimeControl) -> Self {
+        self.clocks = Some([Clock::new(black), Clock::new(white)]);
+        self
+    }
+
+    /// Records statistics for each move played from now on, see
+    /// `Game::statistics`.
+    pub fn with_statistics(mut self, statistics: bool) -> Self {
+        self.statistics = if statistics {
+            Some(GameStatistics::default())
+        } else {
+            None
+        };
+        self
+    }
+
+    /// Returns an independent copy of the game, to explore other lines
+    /// without changing it. The copy keeps the history and can be undone,
+    /// but the observers aren't copied.
+    pub fn fork(&self) -> Self {
+        Self {
+            rules: self.rules.clone(),
+            start: self.start,
+            board: self.board,
+            player: self.player,
+            opponent_is_blocked: self.opponent_is_blocked,
+            status: self.status,
+            resigned: self.resigned,
+            lost_on_time: self.lost_on_time,
+            adjudicated: self.adjudicated,
+            history: self.history.clone(),
+            snapshots: self.snapshots.clone(),
+            undone: self.undone.clone(),
+            observers: Vec::new(),
+            placements_left: self.placements_left,
+            clocks: self.clocks,
+            legal_moves: self.legal_moves.clone(),
+            statistics: self.statistics.clone(),
+        }
+    }
+
+    /// Adds an observer, notified of the next changes of the game.
+    pub fn add_observer(&mut self, observer: Box<dyn GameObserver<N, R>>) {
+        self.observers.push(observer);
+    }
+
+    pub fn board(&self) -> &Board<N> {
         &self.board
     }
 
-    pub fn play(&mut self, player: Player, x: u8, y: u8) -> Result<(), String> {
-        match self.player {
-            None => return Err("None of the players can move, the game is over.".to_string()),
-            Some(p) if p != player => {
-                return Err(format!("It's the turn of {}, not {}.", p, player))
+    /// Returns the rules of the game.
+    pub fn rules(&self) -> &R {
+        &self.rules
+    }
+
+    /// Plays at the given position for the given player. During the
+    /// placement phase, the piece is placed on a central square.
+    pub fn play(&mut self, player: Player, x: u8, y: u8) -> Result<(), GameError> {
+        self.check_turn(player)?;
+        if self.placement_phase() {
+            return self.place(player, x, y);
+        }
+        if self.board.is_move_valid(player, x, y)?
+            && !self.rules.allows_move(&self.board, player, x, y)
+        {
+            return Err(GameError::ForbiddenMove { x, y });
+        }
+        match self.board.play_with_flips(player, x, y)? {
+            Some((new_board, mut changed)) => {
+                self.take_snapshot();
+                self.board = new_board;
+                self.record(player, Move::Place { x, y });
+                changed.push((x, y));
+                self.update_status_after_move(&changed);
+                self.update_player();
+                self.notify_actions();
+                Ok(())
             }
-            _ => (),
+            None => Err(self.board.check_move(player, x, y).unwrap_err().into()),
         }
-        let result = self.board.play(player, x, y)?;
-        if let Some(new_board) = result {
-            self.board = new_board;
-            self.update_status();
-            self.update_player();
-            Ok(())
+    }
+
+    /// Plays a move in standard notation (ie "D3", ignoring case) for the
+    /// current player, "pass" being also accepted when the player has to
+    /// pass.
+    pub fn play_notation(&mut self, notation: &str) -> Result<(), GameError> {
+        let player = self.player.ok_or(GameError::GameOver)?;
+        if notation.eq_ignore_ascii_case("pass") {
+            return self.pass(player);
+        }
+        let (x, y) = Square::<N>::from_notation(notation)?.coordinates();
+        self.play(player, x, y)
+    }
+
+    /// The given player passes, it's only allowed when the player can't move.
+    pub fn pass(&mut self, player: Player) -> Result<(), GameError> {
+        self.check_turn(player)?;
+        if !self.must_pass() {
+            return Err(GameError::CannotPass { player });
+        }
+        self.take_snapshot();
+        self.record(player, Move::Pass);
+        self.player = Some(player.opponent());
+        self.legal_moves.take();
+        self.notify_actions();
+        Ok(())
+    }
+
+    /// Plays the given move, placing a piece or passing.
+    pub fn play_move(&mut self, player: Player, played: Move) -> Result<(), GameError> {
+        match played {
+            Move::Place { x, y } => self.play(player, x, y),
+            Move::Pass => self.pass(player),
+        }
+    }
+
+    /// Plays the given move, the player having spent the given time on it.
+    /// If the player exceeded its time, the move is not played and the game
+    /// is lost on time. An invalid move does not use the clock of the player.
+    pub fn play_timed(
+        &mut self,
+        player: Player,
+        played: Move,
+        elapsed: Duration,
+    ) -> Result<(), GameError> {
+        self.check_turn(player)?;
+        let mut clocks = self.clocks;
+        if let Some(clocks) = clocks.as_mut() {
+            if !clocks[clock_index(player)].record_move(elapsed) {
+                self.check_time(elapsed);
+                return Err(GameError::TimeForfeit { player });
+            }
+        }
+        self.play_move(player, played)?;
+        self.clocks = clocks;
+        if let Some(statistics) = self.statistics.as_mut() {
+            // the move is the first action recorded after the last snapshot.
+            let history_len = self
+                .snapshots
+                .last()
+                .map_or(0, |snapshot| snapshot.history_len);
+            statistics.set_time_spent(history_len + 1, elapsed);
+        }
+        Ok(())
+    }
+
+    /// Checks the clock of the player to move, having already spent the
+    /// given time on its move. Returns true if its flag fell, the game being
+    /// lost on time.
+    pub fn check_time(&mut self, elapsed: Duration) -> bool {
+        let player = match self.player {
+            Some(player) => player,
+            None => return false,
+        };
+        let clock = match self.clocks.as_mut() {
+            Some(clocks) => &mut clocks[clock_index(player)],
+            None => return false,
+        };
+        if elapsed <= clock.remaining() {
+            return false;
+        }
+        clock.record_move(elapsed);
+        self.lose_on_time(player)
+            .expect("Unexpected time forfeit error while the game is running.");
+        true
+    }
+
+    /// Returns the clock of the given player, for timed games.
+    pub fn clock(&self, player: Player) -> Option<&Clock> {
+        self.clocks
+            .as_ref()
+            .map(|clocks| &clocks[clock_index(player)])
+    }
+
+    /// The given player resigns, the game is over and the opponent wins.
+    pub fn resign(&mut self, player: Player) -> Result<(), GameError> {
+        if self.game_over() {
+            return Err(GameError::GameOver);
+        }
+        self.resigned = Some(player);
+        self.player = None;
+        self.notify(|observer, game| observer.on_game_over(game));
+        Ok(())
+    }
+
+    /// The given player exceeded its time, the game is over and the opponent
+    /// wins.
+    pub fn lose_on_time(&mut self, player: Player) -> Result<(), GameError> {
+        if self.game_over() {
+            return Err(GameError::GameOver);
+        }
+        self.lost_on_time = Some(player);
+        self.player = None;
+        self.notify(|observer, game| observer.on_game_over(game));
+        Ok(())
+    }
+
+    /// Undoes the last move or explicit pass, with the automatic passes which
+    /// followed it, the move being kept to be redone. A game lost by
+    /// resignation or on time, or adjudicated, can't be undone.
+    pub fn undo(&mut self) -> Result<(), GameError> {
+        if self.resigned.is_some() || self.lost_on_time.is_some() || self.adjudicated.is_some() {
+            return Err(GameError::GameOver);
+        }
+        let snapshot = self.snapshots.pop().ok_or(GameError::NothingToUndo)?;
+        let undone = self.history.split_off(snapshot.history_len);
+        // the action is the first entry recorded after the snapshot, the
+        // automatic passes following it being replayed with it.
+        if let Some(&entry) = undone.first() {
+            self.undone.push(entry);
+        }
+        if let Some(statistics) = self.statistics.as_mut() {
+            statistics.truncate(snapshot.history_len);
+        }
+        self.board = snapshot.board;
+        self.player = snapshot.player;
+        self.opponent_is_blocked = snapshot.opponent_is_blocked;
+        self.placements_left = snapshot.placements_left;
+        self.clocks = snapshot.clocks;
+        self.update_status();
+        for entry in undone.iter().rev() {
+            self.notify(|observer, game| observer.on_undo(game, entry));
+        }
+        Ok(())
+    }
+
+    /// Redoes the last move or explicit pass undone, as long as no other
+    /// action was played since.
+    pub fn redo(&mut self) -> Result<(), GameError> {
+        let mut undone = mem::take(&mut self.undone);
+        let entry = undone.pop().ok_or(GameError::NothingToRedo)?;
+        // the actions to redo are put back, playing the move forgot them.
+        let played = self.play_move(entry.player, entry.played);
+        if played.is_err() {
+            undone.push(entry);
+        }
+        self.undone = undone;
+        played
+    }
+
+    /// Checks if an undone action can be redone, see `Game::redo`.
+    pub fn can_redo(&self) -> bool {
+        !self.undone.is_empty()
+    }
+
+    /// Takes back the last move or explicit pass of the given player, with
+    /// all the actions which followed it (ie the reply of an engine), the
+    /// turn being back to the player.
+    pub fn takeback(&mut self, player: Player) -> Result<(), GameError> {
+        // each snapshot starts an action, the first entry recorded after it
+        // being the one of the acting player.
+        let undos = self
+            .snapshots
+            .iter()
+            .rev()
+            .position(|snapshot| self.history[snapshot.history_len].player == player)
+            .ok_or(GameError::NothingToUndo)?;
+        for _ in 0..=undos {
+            self.undo()?;
+        }
+        Ok(())
+    }
+
+    fn place(&mut self, player: Player, x: u8, y: u8) -> Result<(), GameError> {
+        if !center_squares(self.board.size()).contains(&(x, y)) {
+            return Err(GameError::NotInCenter { x, y });
+        }
+        if self.board.get_piece(x, y)?.is_some() {
+            return Err(BoardError::SquareOccupied { x, y }.into());
+        }
+        self.take_snapshot();
+        self.board.set_piece(x, y, Some(player))?;
+        self.record(player, Move::Place { x, y });
+        self.placements_left -= 1;
+        self.update_status_after_move(&[(x, y)]);
+        if self.placement_phase() {
+            self.player = Some(player.opponent());
         } else {
-            Err("The move is invalid.".to_string())
+            self.update_player();
         }
+        self.notify_actions();
+        Ok(())
+    }
+
+    fn check_turn(&self, player: Player) -> Result<(), GameError> {
+        match self.player {
+            None => Err(GameError::GameOver),
+            Some(p) if p != player => Err(GameError::NotYourTurn { player }),
+            _ => Ok(()),
+        }
+    }
+
+    fn take_snapshot(&mut self) {
+        self.undone.clear();
+        self.snapshots.push(Snapshot {
+            board: self.board,
+            player: self.player,
+            opponent_is_blocked: self.opponent_is_blocked,
+            history_len: self.history.len(),
+            placements_left: self.placements_left,
+            clocks: self.clocks,
+        });
+    }
+
+    /// Notifies the observers of the moves and passes recorded since the
+    /// last snapshot, and of the end of the game.
+    fn notify_actions(&mut self) {
+        let start = self
+            .snapshots
+            .last()
+            .map_or(0, |snapshot| snapshot.history_len);
+        for index in start..self.history.len() {
+            let entry = self.history[index];
+            match entry.played {
+                Move::Place { .. } => self.notify(|observer, game| observer.on_move(game, &entry)),
+                Move::Pass => self.notify(|observer, game| observer.on_pass(game, &entry)),
+            }
+        }
+        if self.game_over() {
+            self.notify(|observer, game| observer.on_game_over(game));
+        }
+    }
+
+    fn notify<F: Fn(&mut dyn GameObserver<N, R>, &Self)>(&mut self, notification: F) {
+        // the observers are taken out of the game while they look at it.
+        let mut observers = mem::take(&mut self.observers);
+        for observer in observers.iter_mut() {
+            notification(observer.as_mut(), self);
+        }
+        self.observers = observers;
+    }
+
+    fn update_status_after_move(&mut self, changed: &[(u8, u8)]) {
+        self.status.update_after_move(&self.board, changed);
+        self.legal_moves.take();
     }
 
     fn update_status(&mut self) {
         self.status = GameStatus::evaluate_board(&self.board);
+        self.legal_moves.take();
     }
 
     fn update_player(&mut self) {
@@ -62,15 +569,60 @@ impl Game {
 
         // As the game isn't over, at least one player can move, then
         // we don't need to check both cases.
-        if self.status.can_player_move(player.opponent()) {
+        if self.rules.explicit_passes() || self.can_move(player.opponent()) {
             player = player.opponent();
             self.opponent_is_blocked = false;
         } else {
             self.opponent_is_blocked = true;
+            self.record(player.opponent(), Move::Pass);
         }
         self.player = Some(player);
     }
 
+    fn record(&mut self, player: Player, played: Move) {
+        let turn = self.history.len() + 1;
+        self.history.push(HistoryEntry {
+            turn,
+            player,
+            played,
+        });
+        if self.statistics.is_some() {
+            let stats = self.move_statistics(turn, player, played);
+            if let Some(statistics) = self.statistics.as_mut() {
+                statistics.push(stats);
+            }
+        }
+    }
+
+    /// Computes the statistics of the move just played on the board.
+    fn move_statistics(&self, turn: usize, player: Player, played: Move) -> MoveStatistics {
+        // the pieces of the player are compared to the ones of the board
+        // saved before the action, the placed piece not being flipped.
+        let flipped = match (played, self.snapshots.last()) {
+            (Move::Place { .. }, Some(snapshot)) => {
+                let pieces = |board: &Board<N>| match player {
+                    Player::Black => board.count_pieces().0,
+                    Player::White => board.count_pieces().1,
+                };
+                pieces(&self.board) - pieces(&snapshot.board) - 1
+            }
+            _ => 0,
+        };
+        MoveStatistics {
+            turn,
+            player,
+            flipped,
+            black_mobility: self.board.legal_moves_iter(Player::Black).count() as u8,
+            white_mobility: self.board.legal_moves_iter(Player::White).count() as u8,
+            time_spent: None,
+        }
+    }
+
+    /// Returns the statistics of the moves, for games recording them.
+    pub fn statistics(&self) -> Option<&GameStatistics> {
+        self.statistics.as_ref()
+    }
+
     pub fn player(&self) -> Option<Player> {
         self.player
     }
@@ -79,12 +631,111 @@ impl Game {
         self.opponent_is_blocked
     }
 
+    /// Returns the legal moves of the current player, computed once per turn.
+    /// During the placement phase, these are the empty central squares.
+    pub fn legal_moves(&self) -> &[(u8, u8)] {
+        let player = match self.player {
+            Some(player) => player,
+            None => return &[],
+        };
+        self.legal_moves.get_or_init(|| {
+            if self.placement_phase() {
+                IntoIterator::into_iter(center_squares(self.board.size()))
+                    .filter(|&(x, y)| self.board.get_piece(x, y) == Ok(None))
+                    .collect()
+            } else if self.status.tracks_legal_squares() {
+                self.status
+                    .legal_squares_iter(player)
+                    .filter(|&(x, y)| self.rules.allows_move(&self.board, player, x, y))
+                    .collect()
+            } else {
+                self.board
+                    .legal_moves_iter(player)
+                    .filter(|&(x, y)| self.rules.allows_move(&self.board, player, x, y))
+                    .collect()
+            }
+        })
+    }
+
+    /// Checks if the given player has a move allowed by the rules.
+    fn can_move(&self, player: Player) -> bool {
+        let allowed = |(x, y)| self.rules.allows_move(&self.board, player, x, y);
+        if !self.status.can_player_move(player) {
+            false
+        } else if self.status.tracks_legal_squares() {
+            self.status.legal_squares_iter(player).any(allowed)
+        } else {
+            self.board.legal_moves_iter(player).any(allowed)
+        }
+    }
+
+    /// Returns the status of the board, with the legal squares of both
+    /// players.
+    pub fn status(&self) -> &GameStatus {
+        &self.status
+    }
+
+    /// Checks if the current player can't move and has to pass, which only
+    /// happens with explicit passes.
+    pub fn must_pass(&self) -> bool {
+        match self.player {
+            Some(player) => !self.placement_phase() && !self.can_move(player),
+            None => false,
+        }
+    }
+
+    /// Checks if the pieces are still placed on the central squares, for
+    /// games having a placement phase.
+    pub fn placement_phase(&self) -> bool {
+        self.placements_left > 0
+    }
+
     pub fn game_over(&self) -> bool {
-        self.status.game_over()
+        self.resigned.is_some()
+            || self.lost_on_time.is_some()
+            || self.adjudicated.is_some()
+            || (!self.placement_phase()
+                && !self.can_move(Player::Black)
+                && !self.can_move(Player::White))
     }
 
+    /// Returns the result of the game, None while it is running.
+    pub fn result(&self) -> Option<GameResult> {
+        if let Some(player) = self.resigned {
+            return Some(GameResult::Resignation { player });
+        }
+        if let Some(player) = self.lost_on_time {
+            return Some(GameResult::Timeout { player });
+        }
+        if let Some((black, white)) = self.adjudicated {
+            return Some(GameResult::Adjudicated { black, white });
+        }
+        if !self.game_over() {
+            return None;
+        }
+        Some(self.rules.result(&self.board))
+    }
+
+    /// Returns the winner of the game, None while it is running or for a
+    /// draw, see `Game::result`.
     pub fn winner(&self) -> Option<Player> {
-        self.status.winner()
+        self.result().and_then(|result| result.winner())
+    }
+
+    /// Returns the player who resigned, if any.
+    pub fn resigned(&self) -> Option<Player> {
+        self.resigned
+    }
+
+    /// Returns the handicap given at the start of the game by its rules, if
+    /// any.
+    pub fn handicap(&self) -> Option<Handicap> {
+        self.rules.handicap()
+    }
+
+    /// Returns the player who lost on time, if any.
+    pub fn lost_on_time(&self) -> Option<Player> {
+        self.lost_on_time
     }
 
     pub fn count_pieces(&self) -> (u8, u8) {
@@ -93,42 +744,430 @@ impl Game {
             self.status.pieces_count(Player::White),
         )
     }
+
+    /// Returns the moves played since the start of the game, in order,
+    /// including the passes of the blocked players.
+    pub fn history(&self) -> &[HistoryEntry] {
+        &self.history
+    }
+
+    /// Returns the number of plies played, each move or pass being a ply.
+    pub fn ply(&self) -> usize {
+        self.history.len()
+    }
+
+    /// Returns the number of the next move, starting at 1, the passes not
+    /// being counted as in the transcripts.
+    pub fn move_number(&self) -> usize {
+        self.ply() - self.passes() + 1
+    }
+
+    /// Returns the number of passes since the start of the game.
+    pub fn passes(&self) -> usize {
+        self.history
+            .iter()
+            .filter(|entry| entry.played == Move::Pass)
+            .count()
+    }
+
+    /// Replays the game from its start, giving for each move or pass its
+    /// turn, the move and the board after it.
+    pub fn replay(&self) -> Replay<'_, N> {
+        // the automatic passes of the start are played on the same board.
+        let (board, _) = self.start;
+        let placements_left = if self.rules.placement_phase() {
+            center_squares(board.size()).len() as u8
+        } else {
+            0
+        };
+        Replay {
+            entries: self.history.iter(),
+            board,
+            placements_left,
+        }
+    }
+
+    /// Returns the transcript of the game in standard notation (ie "F5D6C3"),
+    /// the passes being left implicit as usual.
+    pub fn to_transcript(&self) -> String {
+        self.history
+            .iter()
+            .filter(|entry| entry.played != Move::Pass)
+            .map(|entry| entry.played.to_string())
+            .collect()
+    }
+}
+
+/// Returns the index of the clock of the given player.
+fn clock_index(player: Player) -> usize {
+    match player {
+        Player::Black => 0,
+        Player::White => 1,
+    }
+}
+
+/// Splits a transcript in moves, each one being a letter followed by digits.
+fn split_transcript(transcript: &str) -> Vec<String> {
+    let mut moves: Vec<String> = Vec::new();
+    for c in transcript.chars().filter(|c| !c.is_whitespace()) {
+        match moves.last_mut() {
+            Some(notation) if c.is_ascii_digit() => notation.push(c),
+            _ => moves.push(c.to_string()),
+        }
+    }
+    moves
+}
+
+/// Creates a standard game on a board of size N.
+impl<const N: usize> Default for Game<N> {
+    fn default() -> Self {
+        GameBuilder::default().build()
+    }
+}
+
+/// Saving and loading of games as JSON, to resume them later. Only their
+/// setup, history and clocks are saved, the games being rebuilt by replaying
+/// their history.
+#[cfg(all(feature = "serde", feature = "std"))]
+impl<const N: usize, R> Game<N, R>
+where
+    R: Rules<N> + serde::Serialize + serde::de::DeserializeOwned,
+{
+    /// Writes the game as JSON to the given writer.
+    pub fn save_to<W: io::Write>(&self, writer: W) -> Result<(), String> {
+        let (board, player) = self.start;
+        let saved = SavedGame {
+            rules: self.rules.clone(),
+            board,
+            player,
+            history: self.history.clone(),
+            clocks: self.clocks,
+            statistics: self.statistics.is_some(),
+            resigned: self.resigned,
+            lost_on_time: self.lost_on_time,
+            adjudicated: self.adjudicated,
+        };
+        serde_json::to_writer(writer, &saved).map_err(|error| error.to_string())
+    }
+
+    /// Reads a game written by `save_to` from the given reader. Each action
+    /// of the history is played again, the first one which can't be played
+    /// being reported.
+    pub fn load_from<T: io::Read>(reader: T) -> Result<Self, String> {
+        let saved: SavedGame<N, R> =
+            serde_json::from_reader(reader).map_err(|error| error.to_string())?;
+        let mut builder = GameBuilder::<N>::default()
+            .with_rules(saved.rules)
+            .with_board(saved.board)
+            .with_player(saved.player)
+            .with_statistics(saved.statistics);
+        if let Some([black, white]) = saved.clocks {
+            builder = builder.with_clocks(black.control(), white.control());
+        }
+        let mut game = builder.build();
+        for (index, entry) in saved.history.iter().enumerate() {
+            // the automatic passes are recorded with the action before them.
+            if index >= game.history.len() {
+                game.play_move(entry.player, entry.played)
+                    .map_err(|error| {
+                        format!("Invalid action {} of the game : {}", index + 1, error)
+                    })?;
+            }
+            if game.history.get(index) != Some(entry) {
+                return Err(format!("Invalid action {} of the game.", index + 1));
+            }
+        }
+        if game.history.len() != saved.history.len() {
+            return Err("Incomplete history of the game.".to_string());
+        }
+        game.clocks = saved.clocks;
+        if let Some(player) = saved.resigned {
+            game.resign(player).map_err(|error| error.to_string())?;
+        }
+        if let Some(player) = saved.lost_on_time {
+            game.lose_on_time(player)
+                .map_err(|error| error.to_string())?;
+        }
+        if let Some((black, white)) = saved.adjudicated {
+            if game.game_over() || usize::from(black) + usize::from(white) > N * N {
+                return Err("Invalid adjudication of the game.".to_string());
+            }
+            game.adjudicated = Some((black, white));
+            game.player = None;
+        }
+        Ok(game)
+    }
+
+    /// Saves the game to the given file, replacing its content.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), String> {
+        let file = File::create(path).map_err(|error| error.to_string())?;
+        self.save_to(io::BufWriter::new(file))
+    }
+
+    /// Loads a game saved with `save` from the given file.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, String> {
+        let file = File::open(path).map_err(|error| error.to_string())?;
+        Self::load_from(io::BufReader::new(file))
+    }
+}
+
+/// A game as saved by `Game::save_to` : its setup, history and clocks, with
+/// the resignation, time forfeit or adjudication which ended it.
+#[cfg(all(feature = "serde", feature = "std"))]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct SavedGame<const N: usize, R> {
+    rules: R,
+    board: Board<N>,
+    player: Player,
+    history: Vec<HistoryEntry>,
+    clocks: Option<[Clock; 2]>,
+    #[serde(default)]
+    statistics: bool,
+    #[serde(default)]
+    resigned: Option<Player>,
+    #[serde(default)]
+    lost_on_time: Option<Player>,
+    #[serde(default)]
+    adjudicated: Option<(u8, u8)>,
+}
+
+/// The state of a game before an action, to undo it.
+#[derive(Debug, Clone, Copy)]
+struct Snapshot<const N: usize> {
+    board: Board<N>,
+    player: Option<Player>,
+    opponent_is_blocked: bool,
+    history_len: usize,
+    placements_left: u8,
+    clocks: Option<[Clock; 2]>,
+}
+
+/// A move recorded in the history of a game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HistoryEntry {
+    /// The number of the turn, starting at 1, each move or pass being a turn.
+    pub turn: usize,
+    pub player: Player,
+    pub played: Move,
+}
+
+/// An iterator replaying the history of a game, see `Game::replay`.
+pub struct Replay<'a, const N: usize> {
+    entries: core::slice::Iter<'a, HistoryEntry>,
+    board: Board<N>,
+    placements_left: u8,
+}
+
+impl<const N: usize> Replay<'_, N> {
+    /// Returns the board before the next move or pass.
+    pub fn board(&self) -> &Board<N> {
+        &self.board
+    }
+
+    /// Checks if the next move places a piece during the placement phase.
+    pub fn placement_phase(&self) -> bool {
+        self.placements_left > 0
+    }
+}
+
+impl<const N: usize> Iterator for Replay<'_, N> {
+    type Item = (usize, Move, Board<N>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let entry = self.entries.next()?;
+        if let Move::Place { x, y } = entry.played {
+            if self.placements_left > 0 {
+                self.placements_left -= 1;
+                self.board
+                    .set_piece(x, y, Some(entry.player))
+                    .expect("Unexpected invalid placement in the history.");
+            } else {
+                self.board
+                    .apply_move(entry.player, x, y)
+                    .expect("Unexpected invalid move in the history.");
+            }
+        }
+        Some((entry.turn, entry.played, self.board))
+    }
+}
+
+/// Errors of the game workflow.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GameError {
+    /// The game is over, no more action is possible.
+    GameOver,
+    /// The given player tried to move during the turn of its opponent.
+    NotYourTurn { player: Player },
+    /// The given player tried to pass while being able to move.
+    CannotPass { player: Player },
+    /// There is no move to undo.
+    NothingToUndo,
+    /// There is no undone move to redo.
+    NothingToRedo,
+    /// The given player resigned instead of moving.
+    Resigned { player: Player },
+    /// The given player exceeded its time, and lost the game.
+    TimeForfeit { player: Player },
+    /// A piece was placed outside of the central squares during the
+    /// placement phase.
+    NotInCenter { x: u8, y: u8 },
+    /// The move at the given position is valid, but forbidden by the rules
+    /// of the variant played.
+    ForbiddenMove { x: u8, y: u8 },
+    /// A handicap was given with a count of corners outside of 1 to 4.
+    InvalidHandicap { corners: u8 },
+    /// The engines don't play by the rules of the game, see
+    /// `Rules::standard_play`.
+    UnsupportedRules,
+    /// The board rejected the move.
+    Board(BoardError),
+    /// The move of a transcript can't be played at the given ply, the passes
+    /// being counted, `move_index` being its index in the transcript
+    /// (starting at 1, the passes not being counted).
+    Transcript {
+        ply: usize,
+        move_index: usize,
+        error: Box<GameError>,
+    },
+}
+
+impl fmt::Display for GameError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GameError::GameOver => f.write_str("The game is over."),
+            GameError::NotYourTurn { player } => {
+                write!(f, "It's the turn of {}, not {}.", player.opponent(), player)
+            }
+            GameError::CannotPass { player } => {
+                write!(f, "{} can't pass while being able to move.", player)
+            }
+            GameError::NothingToUndo => f.write_str("There is no move to undo."),
+            GameError::NothingToRedo => f.write_str("There is no move to redo."),
+            GameError::Resigned { player } => write!(f, "{} resigned.", player),
+            GameError::TimeForfeit { player } => write!(f, "{} lost on time.", player),
+            GameError::NotInCenter { x, y } => write!(
+                f,
+                "{} is not a central square, where pieces are placed first.",
+                Move::Place { x: *x, y: *y }
+            ),
+            GameError::ForbiddenMove { x, y } => write!(
+                f,
+                "{} is forbidden by the rules of the game.",
+                Move::Place { x: *x, y: *y }
+            ),
+            GameError::InvalidHandicap { corners } => {
+                write!(f, "A handicap gives 1 to 4 corners, not {}.", corners)
+            }
+            GameError::UnsupportedRules => {
+                f.write_str("The engines don't play by the rules of the game.")
+            }
+            GameError::Board(error) => write!(f, "{}", error),
+            GameError::Transcript {
+                ply,
+                move_index,
+                error,
+            } => write!(
+                f,
+                "Invalid move {} of the transcript (ply {}) : {}",
+                move_index, ply, error
+            ),
+        }
+    }
+}
+
+impl error::Error for GameError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            GameError::Board(error) => Some(error),
+            GameError::Transcript { error, .. } => Some(error.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl From<BoardError> for GameError {
+    fn from(error: BoardError) -> Self {
+        GameError::Board(error)
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use super::super::{AlphaBeta, GreedyPlayer};
     use super::*;
 
+    /// Observer keeping a description of the notifications.
+    struct Recorder(Rc<RefCell<Vec<String>>>);
+
+    impl GameObserver for Recorder {
+        fn on_move(&mut self, game: &Game, entry: &HistoryEntry) {
+            let event = format!(
+                "{} {} ({} pieces)",
+                entry.player,
+                entry.played,
+                game.count_pieces().0
+            );
+            self.0.borrow_mut().push(event);
+        }
+
+        fn on_pass(&mut self, _game: &Game, entry: &HistoryEntry) {
+            self.0.borrow_mut().push(format!("{} passes", entry.player));
+        }
+
+        fn on_game_over(&mut self, game: &Game) {
+            self.0
+                .borrow_mut()
+                .push(format!("over, winner {:?}", game.winner()));
+        }
+
+        fn on_undo(&mut self, _game: &Game, entry: &HistoryEntry) {
+            self.0.borrow_mut().push(format!("undo {}", entry.played));
+        }
+    }
+
+    fn recorded_game(game: &mut Game) -> Rc<RefCell<Vec<String>>> {
+        let events = Rc::new(RefCell::new(Vec::new()));
+        game.add_observer(Box::new(Recorder(Rc::clone(&events))));
+        events
+    }
+
     #[test]
     fn game_over_not_for_a_new_game() {
         let game = Game::new();
         assert!(!game.game_over())
     }
 
+    fn game_from_board(board: Board) -> Game {
+        GameBuilder::new().with_board(board).build()
+    }
+
     #[test]
     fn game_over_if_all_cells_are_occupied() {
-        let mut game = Game::new();
+        let mut board = Board::new();
         for (x, y) in GridIterator::new() {
-            game.board.set_piece(x, y, Some(Player::Black)).unwrap();
-            game.update_status();
+            board.set_piece(x, y, Some(Player::Black)).unwrap();
         }
+        let game = game_from_board(board);
         assert!(game.game_over());
+        assert_eq!(game.player(), None);
     }
 
     #[test]
     fn game_over_if_none_of_the_players_can_move() {
-        let mut game = Game::new();
-        game.board = Board::new();
-        game.board.set_piece(0, 0, Some(Player::Black)).unwrap();
-        game.board.set_piece(7, 7, Some(Player::White)).unwrap();
-        game.update_status();
-        assert!(game.game_over());
+        let mut board = Board::new();
+        board.set_piece(0, 0, Some(Player::Black)).unwrap();
+        board.set_piece(7, 7, Some(Player::White)).unwrap();
+        assert!(game_from_board(board).game_over());
     }
 
     #[test]
     fn no_winner_for_a_new_game() {
         let game = Game::new();
-        assert!(game.winner().is_none())
+        assert!(game.winner().is_none());
+        assert!(game.result().is_none());
     }
 
     #[test]
@@ -142,9 +1181,7 @@ mod tests {
             };
             board_50_50.set_piece(x, y, piece).unwrap();
         }
-        let mut game = Game::new();
-        game.board = board_50_50;
-        game.update_status();
+        let game = game_from_board(board_50_50);
         assert!(game.winner().is_none())
     }
 
@@ -154,10 +1191,924 @@ mod tests {
         for (x, y) in GridIterator::new() {
             unicolor_board.set_piece(x, y, Some(Player::Black)).unwrap();
         }
+        let game = game_from_board(unicolor_board);
+        assert_eq!(game.winner(), Some(Player::Black));
+    }
+
+    #[test]
+    fn resign_ends_the_game() {
         let mut game = Game::new();
-        game.board = unicolor_board;
-        game.update_status();
+        game.resign(Player::Black).unwrap();
+        assert!(game.game_over());
+        assert_eq!(game.player(), None);
+        assert_eq!(game.resigned(), Some(Player::Black));
+        assert_eq!(game.winner(), Some(Player::White));
+        let result = GameResult::Resignation {
+            player: Player::Black,
+        };
+        assert_eq!(game.result(), Some(result));
+    }
+
+    #[test]
+    fn resign_wins_over_the_board() {
+        let mut game = Game::new();
+        game.play(Player::Black, 5, 4).unwrap();
+        assert_eq!(game.count_pieces(), (4, 1));
+        // the leading player may resign while waiting for its opponent.
+        game.resign(Player::Black).unwrap();
+        assert_eq!(game.resigned(), Some(Player::Black));
+        assert_eq!(game.winner(), Some(Player::White));
+        assert_eq!(game.history().len(), 1);
+    }
+
+    #[test]
+    fn resign_fails_if_the_game_is_over() {
+        let mut game = Game::new();
+        game.resign(Player::White).unwrap();
+        assert_eq!(game.resign(Player::Black), Err(GameError::GameOver));
+        assert_eq!(game.winner(), Some(Player::Black));
+    }
+
+    #[test]
+    fn lose_on_time_ends_the_game() {
+        let mut game = Game::new();
+        game.lose_on_time(Player::White).unwrap();
+        assert!(game.game_over());
+        assert_eq!(game.player(), None);
+        assert_eq!(game.lost_on_time(), Some(Player::White));
+        assert_eq!(game.resigned(), None);
         assert_eq!(game.winner(), Some(Player::Black));
+        assert!(game.lose_on_time(Player::Black).is_err());
+    }
+
+    #[test]
+    fn clocks_are_decremented_around_each_move() {
+        let control = TimeControl::new(Duration::from_secs(10), Duration::from_secs(2));
+        let mut game = GameBuilder::new().with_clocks(control, control).build();
+        game.play_timed(
+            Player::Black,
+            Move::Place { x: 5, y: 4 },
+            Duration::from_secs(3),
+        )
+        .unwrap();
+        let clock = game.clock(Player::Black).unwrap();
+        assert_eq!(clock.remaining(), Duration::from_secs(9));
+        let clock = game.clock(Player::White).unwrap();
+        assert_eq!(clock.remaining(), Duration::from_secs(10));
+
+        // an invalid move doesn't use the clock.
+        let played = game.play_timed(
+            Player::White,
+            Move::Place { x: 0, y: 0 },
+            Duration::from_secs(1),
+        );
+        assert!(played.is_err());
+        let clock = game.clock(Player::White).unwrap();
+        assert_eq!(clock.remaining(), Duration::from_secs(10));
+
+        game.undo().unwrap();
+        let clock = game.clock(Player::Black).unwrap();
+        assert_eq!(clock.remaining(), Duration::from_secs(10));
+    }
+
+    #[test]
+    fn flag_fall_loses_the_game_on_time() {
+        let control = TimeControl::new(Duration::from_secs(10), Duration::from_secs(0));
+        let mut game = Game::new().with_clocks(control, control);
+        assert!(!game.check_time(Duration::from_secs(5)));
+        let played = game.play_timed(
+            Player::Black,
+            Move::Place { x: 5, y: 4 },
+            Duration::from_secs(11),
+        );
+        assert_eq!(
+            played,
+            Err(GameError::TimeForfeit {
+                player: Player::Black
+            })
+        );
+        assert!(game.game_over());
+        assert_eq!(game.lost_on_time(), Some(Player::Black));
+        assert_eq!(game.winner(), Some(Player::White));
+        assert!(game.clock(Player::Black).unwrap().is_flagged());
+        assert_eq!(game.history().len(), 0);
+    }
+
+    #[test]
+    fn untimed_games_have_no_clocks() {
+        let mut game = Game::new();
+        assert!(game.clock(Player::Black).is_none());
+        assert!(!game.check_time(Duration::from_secs(3600)));
+        game.play_timed(
+            Player::Black,
+            Move::Place { x: 5, y: 4 },
+            Duration::from_secs(3600),
+        )
+        .unwrap();
+    }
+
+    /// Saves the game to memory, and loads it back.
+    #[cfg(all(feature = "serde", feature = "std"))]
+    fn save_and_load<R>(game: &Game<8, R>) -> Result<Game<8, R>, String>
+    where
+        R: Rules + serde::Serialize + serde::de::DeserializeOwned,
+    {
+        let mut saved = Vec::new();
+        game.save_to(&mut saved).unwrap();
+        Game::load_from(&saved[..])
+    }
+
+    #[cfg(all(feature = "serde", feature = "std"))]
+    #[test]
+    fn save_and_load_replay_the_game() {
+        let mut game = Game::new();
+        game.play(Player::Black, 5, 4).unwrap();
+        let mut loaded = save_and_load(&game).unwrap();
+        assert_eq!(loaded.board(), game.board());
+        assert_eq!(loaded.player(), Some(Player::White));
+        assert_eq!(loaded.history(), game.history());
+        loaded.undo().unwrap();
+        assert_eq!(loaded.board(), &Board::new_start());
+        loaded.redo().unwrap();
+        loaded.play(Player::White, 5, 5).unwrap();
+        assert_eq!(loaded.count_pieces(), (3, 3));
+    }
+
+    #[cfg(all(feature = "serde", feature = "std"))]
+    #[test]
+    fn save_and_load_keep_the_setup_and_the_end() {
+        let mut game = GameBuilder::new()
+            .with_rules(Reversi(AntiOthello))
+            .with_clocks(
+                TimeControl::new(Duration::from_secs(60), Duration::ZERO),
+                TimeControl::new(Duration::from_secs(60), Duration::ZERO),
+            )
+            .build();
+        game.play_timed(
+            Player::Black,
+            Move::Place { x: 3, y: 3 },
+            Duration::from_secs(10),
+        )
+        .unwrap();
+        game.resign(Player::White).unwrap();
+        let loaded = save_and_load(&game).unwrap();
+        assert!(loaded.placement_phase());
+        assert_eq!(loaded.rules(), &Reversi(AntiOthello));
+        assert_eq!(
+            loaded.clock(Player::Black).unwrap().remaining(),
+            Duration::from_secs(50)
+        );
+        assert_eq!(loaded.result(), game.result());
+    }
+
+    #[cfg(all(feature = "serde", feature = "std"))]
+    #[test]
+    fn load_rejects_an_invalid_history() {
+        let mut game = Game::new();
+        game.play_transcript("F5D6").unwrap();
+        let mut saved = Vec::new();
+        game.save_to(&mut saved).unwrap();
+        let saved = String::from_utf8(saved).unwrap();
+        // D6 is played by Black instead of White.
+        let corrupt = saved.replace(
+            "\"turn\":2,\"player\":\"White\"",
+            "\"turn\":2,\"player\":\"Black\"",
+        );
+        assert_ne!(corrupt, saved);
+        assert_eq!(
+            Game::<8>::load_from(corrupt.as_bytes()).err(),
+            Some("Invalid action 2 of the game : It's the turn of White, not Black.".to_string())
+        );
+        // the game keeps on after the last move, its pass being missing.
+        let mut game = game_with_a_pass(Othello);
+        game.play_notation("C1").unwrap();
+        let mut saved = Vec::new();
+        game.save_to(&mut saved).unwrap();
+        let saved = String::from_utf8(saved).unwrap();
+        let truncated = saved.replace(",{\"turn\":2,\"player\":\"White\",\"played\":\"Pass\"}", "");
+        assert_ne!(truncated, saved);
+        assert_eq!(
+            Game::<8>::load_from(truncated.as_bytes()).err(),
+            Some("Incomplete history of the game.".to_string())
+        );
+    }
+
+    #[cfg(all(feature = "serde", feature = "std"))]
+    #[test]
+    fn save_and_load_keep_the_whole_game() {
+        let mut game = game_with_a_pass(ExplicitPasses(Othello));
+        game.play(Player::Black, 2, 0).unwrap();
+        let path = std::env::temp_dir().join(format!("rusthello-{}.json", std::process::id()));
+        game.save(&path).unwrap();
+        let loaded = Game::<8, ExplicitPasses>::load(&path);
+        std::fs::remove_file(&path).unwrap();
+        let mut loaded = loaded.unwrap();
+        assert_eq!(loaded.board(), game.board());
+        assert_eq!(loaded.history(), game.history());
+        assert!(loaded.must_pass());
+        loaded.pass(Player::White).unwrap();
+    }
+
+    #[cfg(all(feature = "serde", feature = "std"))]
+    #[test]
+    fn load_rejects_invalid_content() {
+        assert!(Game::<8>::load_from(&b"{}"[..]).is_err());
+        assert!(Game::<8>::load_from(&b"not json"[..]).is_err());
+        assert!(Game::<8>::load(std::env::temp_dir().join("rusthello-missing.json")).is_err());
+    }
+
+    #[test]
+    fn play_reports_the_error_kind() {
+        let mut game = Game::new();
+        assert_eq!(
+            game.play(Player::White, 5, 4),
+            Err(GameError::NotYourTurn {
+                player: Player::White
+            })
+        );
+        assert_eq!(
+            game.play(Player::Black, 0, 0),
+            Err(GameError::Board(BoardError::NoCapture { x: 0, y: 0 }))
+        );
+        assert_eq!(
+            game.play(Player::Black, 3, 3),
+            Err(GameError::Board(BoardError::SquareOccupied { x: 3, y: 3 }))
+        );
+        let error = game.play(Player::Black, 8, 0).unwrap_err();
+        assert_eq!(error, BoardError::OutOfRange { x: 8, y: 0 }.into());
+        assert!(std::error::Error::source(&error).is_some());
+        assert_eq!(
+            error.to_string(),
+            "the given coordinates are out of range : (8, 0)"
+        );
+    }
+
+    #[test]
+    fn history_records_moves_and_passes() {
+        assert!(Game::new().history().is_empty());
+        let mut game = game_with_a_pass(Othello);
+        game.play(Player::Black, 2, 0).unwrap();
+        game.play(Player::Black, 2, 2).unwrap();
+        assert!(game.game_over());
+        let expected = [
+            (1, Player::Black, Move::Place { x: 2, y: 0 }),
+            (2, Player::White, Move::Pass),
+            (3, Player::Black, Move::Place { x: 2, y: 2 }),
+        ];
+        let history: Vec<_> = game
+            .history()
+            .iter()
+            .map(|entry| (entry.turn, entry.player, entry.played))
+            .collect();
+        assert_eq!(history, expected);
+    }
+
+    /// Builds a game where White can't move after Black plays at (2, 0),
+    /// Black keeping a move at (2, 2).
+    fn game_with_a_pass<R: Rules>(rules: R) -> Game<8, R> {
+        let mut board = Board::new();
+        board.set_piece(0, 0, Some(Player::Black)).unwrap();
+        board.set_piece(1, 0, Some(Player::White)).unwrap();
+        board.set_piece(0, 2, Some(Player::Black)).unwrap();
+        board.set_piece(1, 2, Some(Player::White)).unwrap();
+        GameBuilder::new()
+            .with_rules(rules)
+            .with_board(board)
+            .build()
+    }
+
+    #[test]
+    fn explicit_passes_give_the_turn_to_the_blocked_player() {
+        let mut game = game_with_a_pass(ExplicitPasses(Othello));
+        assert!(!game.must_pass());
+        assert_eq!(
+            game.pass(Player::Black),
+            Err(GameError::CannotPass {
+                player: Player::Black
+            })
+        );
+        game.play(Player::Black, 2, 0).unwrap();
+        assert_eq!(game.player(), Some(Player::White));
+        assert!(game.must_pass());
+        assert!(!game.opponent_is_blocked());
+        assert_eq!(game.history().len(), 1);
+        assert!(game.play(Player::Black, 2, 2).is_err());
+        game.play_move(Player::White, Move::Pass).unwrap();
+        assert_eq!(game.player(), Some(Player::Black));
+        game.play_move(Player::Black, Move::Place { x: 2, y: 2 })
+            .unwrap();
+        assert!(game.game_over());
+        let moves: Vec<_> = game.history().iter().map(|entry| entry.played).collect();
+        assert_eq!(
+            moves,
+            [
+                Move::Place { x: 2, y: 0 },
+                Move::Pass,
+                Move::Place { x: 2, y: 2 }
+            ]
+        );
+    }
+
+    #[test]
+    fn pass_is_refused_when_blocked_players_pass_automatically() {
+        let mut game = game_with_a_pass(Othello);
+        game.play(Player::Black, 2, 0).unwrap();
+        assert!(game.opponent_is_blocked());
+        assert!(!game.must_pass());
+        assert!(game.pass(Player::White).is_err());
+        assert!(game.pass(Player::Black).is_err());
+    }
+
+    #[test]
+    fn history_ignores_rejected_moves() {
+        let mut game = Game::new();
+        game.play(Player::Black, 5, 4).unwrap();
+        assert!(game.play(Player::Black, 2, 3).is_err());
+        assert!(game.play(Player::White, 0, 0).is_err());
+        assert_eq!(
+            game.history(),
+            &[HistoryEntry {
+                turn: 1,
+                player: Player::Black,
+                played: Move::Place { x: 5, y: 4 },
+            }]
+        );
+    }
+
+    #[test]
+    fn to_transcript_concatenates_the_moves() {
+        let mut game = Game::new();
+        assert_eq!(game.to_transcript(), "");
+        game.play(Player::Black, 5, 4).unwrap();
+        game.play(Player::White, 3, 5).unwrap();
+        game.play(Player::Black, 2, 2).unwrap();
+        assert_eq!(game.to_transcript(), "F5D6C3");
+    }
+
+    #[test]
+    fn to_transcript_omits_passes() {
+        let mut game = game_with_a_pass(ExplicitPasses(Othello));
+        game.play(Player::Black, 2, 0).unwrap();
+        game.pass(Player::White).unwrap();
+        game.play(Player::Black, 2, 2).unwrap();
+        assert_eq!(game.to_transcript(), "C1C3");
+    }
+
+    #[test]
+    fn from_transcript_replays_the_moves() {
+        let game = Game::from_transcript("f5D6 c3").unwrap();
+        assert_eq!(game.to_transcript(), "F5D6C3");
+        assert_eq!(game.player(), Some(Player::White));
+        assert_eq!(game.count_pieces(), (5, 2));
+        let game = Game::from_transcript("").unwrap();
+        assert_eq!(game.board(), &Board::new_start());
+    }
+
+    #[test]
+    fn from_transcript_handles_passes() {
+        // a player can't move near the end of this game, and passes.
+        let transcript = concat!(
+            "C4C3C2E3F6C6D6E6F5C5B6C1F2E2B4B7D3B5A6F7F8A3F1B3D7E7B8C8F3",
+            "D2A2F4D1G5G4G3H3H2B2G1H6A4B1A8A5A7G7G2C7G8H8H7H1H5H4G6E8E1"
+        );
+        let game = Game::from_transcript(transcript).unwrap();
+        assert!(game
+            .history()
+            .iter()
+            .any(|entry| entry.played == Move::Pass));
+        assert_eq!(game.history().len(), 59);
+        assert_eq!(game.to_transcript(), transcript);
+        // the index of an invalid move doesn't count the pass, its ply does.
+        assert!(matches!(
+            Game::from_transcript(&format!("{}Z9", transcript)),
+            Err(GameError::Transcript {
+                ply: 60,
+                move_index: 59,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn from_transcript_reports_the_invalid_move() {
+        let transcript_error = |transcript| match Game::from_transcript(transcript) {
+            Err(GameError::Transcript {
+                ply,
+                move_index,
+                error,
+            }) => (ply, move_index, *error),
+            _ => panic!("Unexpected valid transcript."),
+        };
+        assert_eq!(
+            transcript_error("F5D6D6"),
+            (3, 3, BoardError::SquareOccupied { x: 3, y: 5 }.into())
+        );
+        assert_eq!(
+            transcript_error("F5A1"),
+            (2, 2, BoardError::NoCapture { x: 0, y: 0 }.into())
+        );
+        assert_eq!(
+            transcript_error("F5Z9"),
+            (2, 2, BoardError::InvalidNotation("Z9".to_string()).into())
+        );
+        assert_eq!(
+            transcript_error("F5D"),
+            (2, 2, BoardError::InvalidNotation("D".to_string()).into())
+        );
+        // white passes after the 58th move, before the invalid one.
+        let with_a_pass = concat!(
+            "C4C3C2E3F6C6D6E6F5C5B6C1F2E2B4B7D3B5A6F7F8A3F1B3D7E7B8C8F3",
+            "D2A2F4D1G5G4G3H3H2B2G1H6A4B1A8A5A7G7G2C7G8H8H7H1H5H4G6E8E1",
+            "D4"
+        );
+        assert_eq!(
+            transcript_error(with_a_pass),
+            (60, 59, BoardError::SquareOccupied { x: 3, y: 3 }.into())
+        );
+        let error = match Game::from_transcript("F5D6D6") {
+            Err(error) => error,
+            Ok(_) => panic!("Unexpected valid transcript."),
+        };
+        assert!(std::error::Error::source(&error).is_some());
+        assert_eq!(
+            error.to_string(),
+            "Invalid move 3 of the transcript (ply 3) : D6 is already occupied"
+        );
+    }
+
+    #[test]
+    fn observers_are_notified_of_the_changes() {
+        let mut game = game_with_a_pass(Othello);
+        let events = recorded_game(&mut game);
+        game.play(Player::Black, 2, 0).unwrap();
+        game.undo().unwrap();
+        game.play(Player::Black, 2, 0).unwrap();
+        game.play(Player::Black, 2, 2).unwrap();
+        assert!(game.play(Player::White, 3, 3).is_err());
+        assert_eq!(
+            *events.borrow(),
+            [
+                "Black C1 (4 pieces)",
+                "White passes",
+                "undo pass",
+                "undo C1",
+                "Black C1 (4 pieces)",
+                "White passes",
+                "Black C3 (6 pieces)",
+                "over, winner Some(Black)",
+            ]
+        );
+    }
+
+    #[test]
+    fn observers_are_notified_of_resignations() {
+        let mut game = Game::new();
+        let events = recorded_game(&mut game);
+        game.resign(Player::White).unwrap();
+        assert_eq!(*events.borrow(), ["over, winner Some(Black)"]);
+    }
+
+    #[test]
+    fn undo_restores_the_previous_state() {
+        let mut game = Game::new();
+        assert_eq!(game.undo(), Err(GameError::NothingToUndo));
+        game.play(Player::Black, 5, 4).unwrap();
+        game.play(Player::White, 5, 5).unwrap();
+        game.undo().unwrap();
+        assert_eq!(game.player(), Some(Player::White));
+        assert_eq!(game.count_pieces(), (4, 1));
+        assert_eq!(game.to_transcript(), "F5");
+        game.undo().unwrap();
+        assert_eq!(game.board(), &Board::new_start());
+        assert!(game.history().is_empty());
+        assert_eq!(game.undo(), Err(GameError::NothingToUndo));
+    }
+
+    #[test]
+    fn redo_replays_the_undone_moves() {
+        let mut game = Game::from_transcript("F5D6C3").unwrap();
+        let board = *game.board();
+        assert_eq!(game.redo(), Err(GameError::NothingToRedo));
+        game.undo().unwrap();
+        game.undo().unwrap();
+        assert!(game.can_redo());
+        game.redo().unwrap();
+        game.redo().unwrap();
+        assert_eq!(game.board(), &board);
+        assert_eq!(game.to_transcript(), "F5D6C3");
+        assert!(!game.can_redo());
+        game.undo().unwrap();
+        game.play(Player::Black, 2, 2).unwrap();
+        assert_eq!(game.redo(), Err(GameError::NothingToRedo));
+    }
+
+    #[test]
+    fn undo_handles_passes_and_game_over() {
+        let mut game = game_with_a_pass(ExplicitPasses(Othello));
+        game.play(Player::Black, 2, 0).unwrap();
+        game.pass(Player::White).unwrap();
+        game.play(Player::Black, 2, 2).unwrap();
+        assert!(game.game_over());
+        game.undo().unwrap();
+        assert!(!game.game_over());
+        assert_eq!(game.player(), Some(Player::Black));
+        game.undo().unwrap();
+        assert!(game.must_pass());
+        assert_eq!(game.history().len(), 1);
+    }
+
+    #[test]
+    fn takeback_undoes_the_reply_too() {
+        let mut game = Game::new();
+        game.play_notation("F5").unwrap();
+        let after_f5 = *game.board();
+        game.play_transcript("D6C3").unwrap();
+        game.takeback(Player::Black).unwrap();
+        assert_eq!(game.to_transcript(), "F5D6");
+        assert_eq!(game.player(), Some(Player::Black));
+        game.takeback(Player::White).unwrap();
+        assert_eq!(*game.board(), after_f5);
+        assert_eq!(game.player(), Some(Player::White));
+    }
+
+    #[test]
+    fn takeback_skips_the_automatic_passes() {
+        let mut game = game_with_a_pass(Othello);
+        game.play_notation("C1").unwrap();
+        assert_eq!(game.player(), Some(Player::Black));
+        assert_eq!(game.takeback(Player::White), Err(GameError::NothingToUndo));
+        game.takeback(Player::Black).unwrap();
+        assert!(game.history().is_empty());
+    }
+
+    #[test]
+    fn undo_fails_after_a_resignation() {
+        let mut game = Game::new();
+        game.play(Player::Black, 5, 4).unwrap();
+        game.resign(Player::White).unwrap();
+        assert_eq!(game.undo(), Err(GameError::GameOver));
+    }
+
+    #[test]
+    fn legal_moves_follow_the_turns() {
+        let mut game = game_with_a_pass(ExplicitPasses(Othello));
+        assert_eq!(game.legal_moves(), [(2, 0), (2, 2)]);
+        game.play(Player::Black, 2, 0).unwrap();
+        assert!(game.legal_moves().is_empty());
+        game.pass(Player::White).unwrap();
+        assert_eq!(game.legal_moves(), [(2, 2)]);
+        game.undo().unwrap();
+        game.undo().unwrap();
+        assert_eq!(game.legal_moves(), [(2, 0), (2, 2)]);
+        game.resign(Player::Black).unwrap();
+        assert!(game.legal_moves().is_empty());
+    }
+
+    #[test]
+    fn legal_moves_are_the_free_central_squares_during_placement() {
+        let mut game = GameBuilder::new().with_rules(Reversi(Othello)).build();
+        assert_eq!(game.legal_moves(), [(3, 3), (4, 3), (3, 4), (4, 4)]);
+        game.play(Player::Black, 4, 3).unwrap();
+        assert_eq!(game.legal_moves(), [(3, 3), (3, 4), (4, 4)]);
+    }
+
+    #[test]
+    fn placement_phase_fills_the_center_first() {
+        let mut game = GameBuilder::new().with_rules(Reversi(Othello)).build();
+        assert!(!game.must_pass());
+        assert_eq!(
+            game.play(Player::Black, 2, 3),
+            Err(GameError::NotInCenter { x: 2, y: 3 })
+        );
+        assert!(game.pass(Player::Black).is_err());
+        game.play(Player::Black, 3, 3).unwrap();
+        assert_eq!(
+            game.play(Player::White, 3, 3),
+            Err(BoardError::SquareOccupied { x: 3, y: 3 }.into())
+        );
+        // a parallel opening, the pieces of each player being on a column.
+        game.play(Player::White, 4, 3).unwrap();
+        game.play(Player::Black, 3, 4).unwrap();
+        assert_eq!(game.winner(), None);
+        game.undo().unwrap();
+        assert!(game.placement_phase());
+        game.play(Player::Black, 3, 4).unwrap();
+        game.play(Player::White, 4, 4).unwrap();
+        assert!(!game.placement_phase());
+        assert_eq!(game.player(), Some(Player::Black));
+        assert_eq!(game.count_pieces(), (2, 2));
+        assert_eq!(game.to_transcript(), "D4E4D5E5");
+        assert_eq!(
+            game.play(Player::Black, 3, 2),
+            Err(BoardError::NoCapture { x: 3, y: 2 }.into())
+        );
+        game.play(Player::Black, 5, 3).unwrap();
+        assert_eq!(game.count_pieces(), (4, 1));
+    }
+
+    #[test]
+    fn other_sizes_start_in_the_center() {
+        let mut game = Game::<6>::default();
+        assert_eq!(game.board(), &Board::<6>::start());
+        assert_eq!(game.player(), Some(Player::Black));
+        game.play(Player::Black, 4, 3).unwrap();
+        assert_eq!(game.count_pieces(), (4, 1));
+        assert_eq!(game.to_transcript(), "E4");
+        let mut game = Game::<10>::default();
+        game.play_transcript("g6").unwrap();
+        assert_eq!(game.count_pieces(), (4, 1));
+        assert!(game.play_transcript("k1").is_err());
+    }
+
+    #[test]
+    fn other_sizes_are_played_to_the_end() {
+        // the first legal move is always played, until the end of the game.
+        let mut game = Game::<6>::default();
+        while let Some(player) = game.player() {
+            let (x, y) = game.board().legal_moves(player)[0];
+            game.play(player, x, y).unwrap();
+        }
+        assert!(game.game_over());
+        let (black, white) = game.count_pieces();
+        let expected_winner = match black.cmp(&white) {
+            std::cmp::Ordering::Greater => Some(Player::Black),
+            std::cmp::Ordering::Less => Some(Player::White),
+            std::cmp::Ordering::Equal => None,
+        };
+        assert_eq!(game.winner(), expected_winner);
+        let replayed = {
+            let mut replayed = Game::<6>::default();
+            replayed.play_transcript(&game.to_transcript()).unwrap();
+            replayed
+        };
+        assert_eq!(replayed.board(), game.board());
+    }
+
+    #[test]
+    fn other_sizes_have_variants() {
+        let game = GameBuilder::<10>::default()
+            .with_rules(Handicapped::new(Othello, Player::White, 2).unwrap())
+            .build();
+        assert_eq!(game.board().get_piece(9, 9).unwrap(), Some(Player::White));
+        assert_eq!(game.count_pieces(), (2, 4));
+        let mut game = GameBuilder::<6>::default()
+            .with_rules(Reversi(Othello))
+            .build();
+        assert!(game.play(Player::Black, 3, 3).is_ok());
+        assert!(game.play(Player::White, 4, 4).is_err());
+    }
+
+    /// Rules forbidding the corners, the blocked players passing explicitly.
+    #[derive(Clone)]
+    struct NoCorners;
+
+    impl Rules for NoCorners {
+        fn allows_move(&self, board: &Board, _player: Player, x: u8, y: u8) -> bool {
+            let last = board.size() - 1;
+            !((x == 0 || x == last) && (y == 0 || y == last))
+        }
+
+        fn explicit_passes(&self) -> bool {
+            true
+        }
+    }
+
+    #[test]
+    fn rules_decide_the_allowed_moves_and_the_passes() {
+        let mut board = Board::new();
+        board.set_piece(1, 0, Some(Player::White)).unwrap();
+        board.set_piece(2, 0, Some(Player::Black)).unwrap();
+        let mut game = GameBuilder::new()
+            .with_rules(NoCorners)
+            .with_board(board)
+            .build();
+        // A1 is the only valid move of Black.
+        assert!(game.must_pass());
+        assert!(game.legal_moves().is_empty());
+        assert_eq!(
+            game.play(Player::Black, 0, 0),
+            Err(GameError::ForbiddenMove { x: 0, y: 0 })
+        );
+        game.pass(Player::Black).unwrap();
+        game.play(Player::White, 3, 0).unwrap();
+        assert_eq!(game.winner(), Some(Player::White));
+    }
+
+    #[test]
+    fn anti_othello_is_won_with_the_fewest_pieces() {
+        let mut game = GameBuilder::new().with_rules(AntiOthello).build();
+        let mut standard = Game::new();
+        while let Some(player) = game.player() {
+            let (x, y) = game.legal_moves()[0];
+            game.play(player, x, y).unwrap();
+            standard.play(player, x, y).unwrap();
+        }
+        let (black, white) = game.count_pieces();
+        assert_ne!(black, white);
+        let result = game.result().unwrap();
+        assert!(matches!(result, GameResult::FewestPieces { .. }));
+        assert_eq!(game.winner(), standard.winner().map(Player::opponent));
+    }
+
+    #[test]
+    fn statistics_follow_the_moves() {
+        let mut game = Game::new().with_statistics(true);
+        game.play_notation("F5").unwrap();
+        game.play_timed(
+            Player::White,
+            Move::Place { x: 3, y: 5 },
+            Duration::from_secs(2),
+        )
+        .unwrap();
+        game.play_notation("C3").unwrap();
+        game.undo().unwrap();
+        let statistics = game.statistics().unwrap();
+        assert_eq!(
+            statistics.moves()[0],
+            MoveStatistics {
+                turn: 1,
+                player: Player::Black,
+                flipped: 1,
+                black_mobility: 3,
+                white_mobility: 3,
+                time_spent: None,
+            }
+        );
+        assert_eq!(statistics.moves().len(), 2);
+        assert_eq!(statistics.flipped(Player::White), 1);
+        assert_eq!(statistics.average_mobility(Player::White), Some(4.0));
+        assert_eq!(statistics.time_spent(Player::White), Duration::from_secs(2));
+        assert!(Game::new().statistics().is_none());
+    }
+
+    #[test]
+    fn statistics_handle_passes_and_placements() {
+        let mut game = game_with_a_pass(Othello).with_statistics(true);
+        game.play_notation("C1").unwrap();
+        let moves = game.statistics().unwrap().moves();
+        assert_eq!((moves[1].player, moves[1].flipped), (Player::White, 0));
+        assert_eq!(moves[1].white_mobility, 0);
+        let mut game = GameBuilder::new()
+            .with_rules(Reversi(Othello))
+            .with_statistics(true)
+            .build();
+        game.play_transcript("D4E4D5E5F4").unwrap();
+        let flipped: Vec<u8> = game
+            .statistics()
+            .unwrap()
+            .moves()
+            .iter()
+            .map(|stats| stats.flipped)
+            .collect();
+        assert_eq!(flipped, [0, 0, 0, 0, 1]);
+    }
+
+    #[test]
+    fn fork_is_independent_of_the_game() {
+        let mut game = Game::new();
+        let events = recorded_game(&mut game);
+        game.play_notation("F5").unwrap();
+        let mut fork = game.fork();
+        fork.play_notation("D6").unwrap();
+        assert_eq!(fork.to_transcript(), "F5D6");
+        fork.undo().unwrap();
+        fork.undo().unwrap();
+        assert_eq!(fork.to_transcript(), "");
+        assert_eq!(game.to_transcript(), "F5");
+        assert_eq!(game.player(), Some(Player::White));
+        assert_eq!(events.borrow().len(), 1);
+    }
+
+    #[test]
+    fn play_ai_turn_plays_until_the_end() {
+        let mut game = Game::new();
+        let engine = GreedyPlayer::new();
+        while !game.game_over() {
+            let player = game.player().unwrap();
+            let played = game.play_ai_turn(&engine).unwrap();
+            assert_eq!(game.history().last().unwrap().played, played);
+            assert_eq!(game.history().last().unwrap().player, player);
+        }
+        assert_eq!(game.play_ai_turn(&engine), Err(GameError::GameOver));
+    }
+
+    #[test]
+    fn play_ai_turn_passes_and_places() {
+        let engine = GreedyPlayer::new();
+        let mut game = game_with_a_pass(ExplicitPasses(Othello));
+        game.play_notation("C1").unwrap();
+        assert_eq!(game.play_ai_turn(&engine), Ok(Move::Pass));
+        let mut game = GameBuilder::new().with_rules(Reversi(Othello)).build();
+        assert_eq!(game.play_ai_turn(&engine), Ok(Move::Place { x: 3, y: 3 }));
+    }
+
+    #[test]
+    fn play_ai_turn_resigns_for_the_engine() {
+        let mut game = Game::new();
+        let engine = AlphaBeta::new(1).with_resign_threshold(i32::MIN);
+        assert_eq!(
+            game.play_ai_turn(&engine),
+            Err(GameError::Resigned {
+                player: Player::Black
+            })
+        );
+        assert_eq!(game.resigned(), Some(Player::Black));
+    }
+
+    #[test]
+    fn adjudicate_ends_the_game_with_the_proven_score() {
+        let mut game = Game::new();
+        let engine = GreedyPlayer::new();
+        while game.board().empties() > 8 {
+            game.play_ai_turn(&engine).unwrap();
+        }
+        let mut played = game.fork();
+        let solver = EndgameSolver::new();
+        while !played.game_over() {
+            played.play_ai_turn(&solver).unwrap();
+        }
+        let proven = played.result().unwrap().score().unwrap();
+        let (black, white) = proven;
+        assert_eq!(
+            game.adjudicate(&solver),
+            Ok(GameResult::Adjudicated { black, white })
+        );
+        assert!(game.game_over());
+        assert_eq!(game.undo(), Err(GameError::GameOver));
+        assert_eq!(game.adjudicate(&solver), Err(GameError::GameOver));
+    }
+
+    #[test]
+    fn adjudicate_rejects_the_variants_the_solver_does_not_play() {
+        let mut game = GameBuilder::new().with_rules(AntiOthello).build();
+        game.play_transcript("F5D6C3D3C4F4F6F3E6E7").unwrap();
+        assert_eq!(
+            game.adjudicate(&EndgameSolver::new()),
+            Err(GameError::UnsupportedRules)
+        );
+        assert_eq!(game.result(), None);
+        let mut game = GameBuilder::new().with_rules(Reversi(Othello)).build();
+        assert_eq!(
+            game.adjudicate(&EndgameSolver::new()),
+            Err(GameError::UnsupportedRules)
+        );
+        assert!(game.placement_phase());
+    }
+
+    #[test]
+    fn ply_and_move_number_count_the_passes_apart() {
+        let mut game = game_with_a_pass(Othello);
+        assert_eq!((game.ply(), game.move_number(), game.passes()), (0, 1, 0));
+        game.play_notation("C1").unwrap();
+        assert_eq!((game.ply(), game.move_number(), game.passes()), (2, 2, 1));
+        game.undo().unwrap();
+        assert_eq!((game.ply(), game.move_number(), game.passes()), (0, 1, 0));
+    }
+
+    #[test]
+    fn replay_rebuilds_the_boards() {
+        let mut game = game_with_a_pass(Othello);
+        game.play_notation("C1").unwrap();
+        game.play_notation("C3").unwrap();
+        let replay: Vec<_> = game.replay().collect();
+        assert_eq!(replay.len(), 3);
+        assert_eq!(replay[0].0, 1);
+        assert_eq!(replay[0].2.get_piece(1, 0), Ok(Some(Player::Black)));
+        assert_eq!(replay[1].1, Move::Pass);
+        assert_eq!(replay[1].2, replay[0].2);
+        assert_eq!(replay[2].2, *game.board());
+    }
+
+    #[test]
+    fn replay_handles_the_placement_phase() {
+        let mut game = GameBuilder::new().with_rules(Reversi(Othello)).build();
+        game.play_transcript("D4E4D5E5F4").unwrap();
+        let boards: Vec<_> = game.replay().map(|(_, _, board)| board).collect();
+        assert_eq!(boards[3].count_pieces(), (2, 2));
+        assert_eq!(boards[4], *game.board());
+    }
+
+    #[test]
+    fn play_notation_plays_for_the_current_player() {
+        let mut game = game_with_a_pass(ExplicitPasses(Othello));
+        game.play_notation("c1").unwrap();
+        assert_eq!(
+            game.play_notation("Z9"),
+            Err(BoardError::InvalidNotation("Z9".to_string()).into())
+        );
+        assert_eq!(
+            game.play_notation("A1"),
+            Err(BoardError::SquareOccupied { x: 0, y: 0 }.into())
+        );
+        game.play_notation("PASS").unwrap();
+        game.play_notation("C3").unwrap();
+        assert_eq!(game.play_notation("D4"), Err(GameError::GameOver));
+    }
+
+    #[test]
+    fn split_transcript_handles_two_digits_rows() {
+        assert_eq!(split_transcript("F5j10 a1"), ["F5", "j10", "a1"]);
+        assert!(split_transcript("").is_empty());
     }
 
     #[test]
@@ -0,0 +1,392 @@
+use alloc::vec::Vec;
+use core::fmt;
+
+use super::board::*;
+use super::game::*;
+use super::moves::*;
+use super::rules::*;
+use super::virtual_player::*;
+
+/// Annotation of a move losing too much of the evaluation, see
+/// `annotate_game`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Annotation {
+    Inaccuracy,
+    Mistake,
+    Blunder,
+}
+
+impl Annotation {
+    /// Returns the usual symbol of the annotation : "?!", "?" or "??".
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Annotation::Inaccuracy => "?!",
+            Annotation::Mistake => "?",
+            Annotation::Blunder => "??",
+        }
+    }
+}
+
+impl fmt::Display for Annotation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Annotation::Inaccuracy => "inaccuracy",
+            Annotation::Mistake => "mistake",
+            Annotation::Blunder => "blunder",
+        })
+    }
+}
+
+/// Drops of evaluation, compared to the best move, from which the moves are
+/// annotated. They are expressed in the units of the engine, the default
+/// ones suiting the evaluation of `AlphaBeta` where a corner is worth 8.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct AnnotationThresholds {
+    pub inaccuracy: i32,
+    pub mistake: i32,
+    pub blunder: i32,
+}
+
+impl AnnotationThresholds {
+    /// Returns the annotation of a move losing the given evaluation.
+    pub fn annotation(&self, drop: i32) -> Option<Annotation> {
+        if drop >= self.blunder {
+            Some(Annotation::Blunder)
+        } else if drop >= self.mistake {
+            Some(Annotation::Mistake)
+        } else if drop >= self.inaccuracy {
+            Some(Annotation::Inaccuracy)
+        } else {
+            None
+        }
+    }
+}
+
+impl Default for AnnotationThresholds {
+    fn default() -> Self {
+        Self {
+            inaccuracy: 8,
+            mistake: 16,
+            blunder: 32,
+        }
+    }
+}
+
+/// A move or pass of an annotated game. The evaluations are given from the
+/// point of view of the player, passes and pieces placed during the
+/// placement phase not being evaluated.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnnotatedMove {
+    pub entry: HistoryEntry,
+    /// The best move according to the engine.
+    pub best: Option<(u8, u8)>,
+    /// The evaluation of the best move.
+    pub best_score: Option<i32>,
+    /// The evaluation of the played move.
+    pub played_score: Option<i32>,
+    pub annotation: Option<Annotation>,
+}
+
+/// A game whose moves were evaluated by an engine, see `annotate_game`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnnotatedGame {
+    moves: Vec<AnnotatedMove>,
+}
+
+impl AnnotatedGame {
+    /// Returns the moves and passes of the game, in order.
+    pub fn moves(&self) -> &[AnnotatedMove] {
+        &self.moves
+    }
+
+    /// Returns the count of moves of the given player having the given
+    /// annotation.
+    pub fn count(&self, player: Player, annotation: Annotation) -> usize {
+        self.moves
+            .iter()
+            .filter(|annotated| {
+                annotated.entry.player == player && annotated.annotation == Some(annotation)
+            })
+            .count()
+    }
+}
+
+/// Displays the transcript of the game in standard notation, each annotated
+/// move being followed by its symbol (ie "F5 D6 C3?? F4").
+impl fmt::Display for AnnotatedGame {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut separator = "";
+        for annotated in &self.moves {
+            if annotated.entry.played == Move::Pass {
+                continue;
+            }
+            write!(f, "{}{}", separator, annotated.entry.played)?;
+            if let Some(annotation) = annotated.annotation {
+                f.write_str(annotation.symbol())?;
+            }
+            separator = " ";
+        }
+        Ok(())
+    }
+}
+
+/// Evaluates each move of the game with the given engine, at its own depth,
+/// and annotates the moves whose evaluation drops, compared to the best
+/// move, reach the given thresholds. Engines without evaluation leave the
+/// moves unannotated. As the engines play standard Othello, the games of
+/// other variants are rejected (see `Rules::standard_play`).
+pub fn annotate_game<R: Rules>(
+    game: &Game<8, R>,
+    engine: &dyn VirtualPlayer,
+    thresholds: AnnotationThresholds,
+) -> Result<AnnotatedGame, GameError> {
+    check_rules(game)?;
+    let mut moves = Vec::new();
+    let mut replay = game.replay();
+    let entries = game.history().iter();
+    for entry in entries {
+        let board = *replay.board();
+        let placement = replay.placement_phase();
+        let (_, _, board_after) = replay
+            .next()
+            .expect("Unexpected end of the replay before the history.");
+        let mut annotated = AnnotatedMove {
+            entry: *entry,
+            best: None,
+            best_score: None,
+            played_score: None,
+            annotation: None,
+        };
+        if let (Move::Place { x, y }, false) = (entry.played, placement) {
+            // both moves are scored from the board following them, to be
+            // compared at the same depth.
+            let player = entry.player;
+            let score_after = |board: &Board| {
+                evaluate(engine, game.rules(), board, player.opponent()).map(i32::saturating_neg)
+            };
+            annotated.played_score = score_after(&board_after);
+            annotated.best = engine
+                .compute_move_full(&board, player)
+                .map(|analysis| analysis.position);
+            annotated.best_score = match annotated.best {
+                Some(best) if best == (x, y) => annotated.played_score,
+                Some((best_x, best_y)) => board
+                    .play(player, best_x, best_y)
+                    .ok()
+                    .flatten()
+                    .and_then(|board| score_after(&board)),
+                None => None,
+            };
+            if let (Some(best_score), Some(played_score)) =
+                (annotated.best_score, annotated.played_score)
+            {
+                let drop = best_score.saturating_sub(played_score);
+                annotated.annotation = thresholds.annotation(drop);
+            }
+        }
+        moves.push(annotated);
+    }
+
+    Ok(AnnotatedGame { moves })
+}
+
+/// Returns the evaluation by the given engine of the position after each
+/// ply of the game, from the point of view of Black (positive when Black is
+/// better), to draw the advantage graph of the game. The plies of the
+/// placement phase, or the ones the engine doesn't evaluate, are skipped.
+/// The games of the variants the engines don't play are rejected, as by
+/// `annotate_game`.
+pub fn evaluation_graph<R: Rules>(
+    game: &Game<8, R>,
+    engine: &dyn VirtualPlayer,
+) -> Result<Vec<(usize, i32)>, GameError> {
+    check_rules(game)?;
+    let mut graph = Vec::new();
+    let mut replay = game.replay();
+    while let Some((ply, _, board)) = replay.next() {
+        if replay.placement_phase() {
+            continue;
+        }
+        let entry = &game.history()[ply - 1];
+        // the opponent of the player is to move, or passes.
+        let to_move = entry.player.opponent();
+        let evaluation =
+            evaluate(engine, game.rules(), &board, to_move).map(|score| match to_move {
+                Player::Black => score,
+                Player::White => score.saturating_neg(),
+            });
+        if let Some(score) = evaluation {
+            graph.push((ply, score));
+        }
+    }
+
+    Ok(graph)
+}
+
+/// Checks that the engines play by the rules of the game.
+fn check_rules<R: Rules>(game: &Game<8, R>) -> Result<(), GameError> {
+    if game.rules().standard_play() {
+        Ok(())
+    } else {
+        Err(GameError::UnsupportedRules)
+    }
+}
+
+/// Returns the evaluation of the board by the engine, from the point of view
+/// of the given player to move, a blocked player passing. A finished game is
+/// scored by the rules as the engines do : `i32::MAX` for a win, `-i32::MAX`
+/// for a loss.
+pub(crate) fn evaluate<R: Rules>(
+    engine: &dyn VirtualPlayer,
+    rules: &R,
+    board: &Board,
+    player: Player,
+) -> Option<i32> {
+    if board.has_legal_move(player) {
+        return engine
+            .compute_move_full(board, player)
+            .and_then(|analysis| analysis.score);
+    }
+    if board.has_legal_move(player.opponent()) {
+        return evaluate(engine, rules, board, player.opponent()).map(i32::saturating_neg);
+    }
+    Some(match rules.result(board).winner() {
+        Some(winner) if winner == player => i32::MAX,
+        Some(_) => -i32::MAX,
+        None => 0,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::super::game_builder::*;
+    use super::super::greedy_player::*;
+    use super::*;
+
+    #[test]
+    fn thresholds_give_the_annotations() {
+        let thresholds = AnnotationThresholds::default();
+        assert_eq!(thresholds.annotation(-5), None);
+        assert_eq!(thresholds.annotation(7), None);
+        assert_eq!(thresholds.annotation(8), Some(Annotation::Inaccuracy));
+        assert_eq!(thresholds.annotation(20), Some(Annotation::Mistake));
+        assert_eq!(thresholds.annotation(i32::MAX), Some(Annotation::Blunder));
+    }
+
+    #[test]
+    fn best_moves_are_not_annotated() {
+        let mut game = Game::new();
+        let engine = AlphaBeta::new(2);
+        for _ in 0..10 {
+            game.play_ai_turn(&engine).unwrap();
+        }
+        let annotated = annotate_game(&game, &engine, AnnotationThresholds::default()).unwrap();
+        assert_eq!(annotated.moves().len(), game.ply());
+        for annotated_move in annotated.moves() {
+            assert_eq!(annotated_move.best, annotated_move.entry.played.position());
+            assert_eq!(annotated_move.annotation, None);
+        }
+        assert_eq!(annotated.to_string().split(' ').count(), 10);
+    }
+
+    #[test]
+    fn missing_a_corner_is_annotated() {
+        let mut game = Game::new();
+        let greedy = GreedyPlayer::new();
+        for _ in 0..33 {
+            game.play_ai_turn(&greedy).unwrap();
+        }
+        let engine = AlphaBeta::new(3);
+        let annotated = annotate_game(&game, &engine, AnnotationThresholds::default()).unwrap();
+        // Black plays F1 instead of taking the corner H1.
+        let f1 = &annotated.moves()[32];
+        assert_eq!(f1.entry.played, Move::Place { x: 5, y: 0 });
+        assert_eq!(f1.best, Some((7, 0)));
+        assert_eq!(f1.annotation, Some(Annotation::Mistake));
+        assert!(annotated.to_string().ends_with("F1?"));
+        let strict = AnnotationThresholds {
+            inaccuracy: 4,
+            mistake: 8,
+            blunder: 16,
+        };
+        let annotated = annotate_game(&game, &engine, strict).unwrap();
+        assert_eq!(annotated.moves()[32].annotation, Some(Annotation::Blunder));
+        assert_eq!(annotated.count(Player::Black, Annotation::Blunder), 1);
+    }
+
+    #[test]
+    fn evaluation_graph_follows_the_game() {
+        let mut game = Game::new();
+        let greedy = GreedyPlayer::new();
+        while !game.game_over() {
+            game.play_ai_turn(&greedy).unwrap();
+        }
+        let graph = evaluation_graph(&game, &AlphaBeta::new(2)).unwrap();
+        let plies: Vec<usize> = graph.iter().map(|&(ply, _)| ply).collect();
+        assert_eq!(plies, (1..=game.ply()).collect::<Vec<_>>());
+        let expected = match game.winner() {
+            Some(Player::Black) => i32::MAX,
+            Some(Player::White) => -i32::MAX,
+            None => 0,
+        };
+        assert_eq!(graph.last(), Some(&(game.ply(), expected)));
+        // only the end of the game is scored without evaluation.
+        assert_eq!(
+            evaluation_graph(&game, &greedy).unwrap(),
+            [(game.ply(), expected)]
+        );
+    }
+
+    #[test]
+    fn evaluation_graph_is_given_for_black() {
+        let game = Game::from_transcript("F5D6C3").unwrap();
+        let engine = AlphaBeta::new(1);
+        let graph = evaluation_graph(&game, &engine).unwrap();
+        let mut board = *Game::from_transcript("F5D6").unwrap().board();
+        board = board.play(Player::Black, 2, 2).unwrap().unwrap();
+        let white_view = engine
+            .compute_move_full(&board, Player::White)
+            .unwrap()
+            .score
+            .unwrap();
+        assert_eq!(graph[2], (3, -white_view));
+    }
+
+    #[test]
+    fn evaluation_graph_skips_the_placement_phase() {
+        let mut game = GameBuilder::new().with_rules(Reversi(Othello)).build();
+        game.play_transcript("D4E4D5E5F4").unwrap();
+        let graph = evaluation_graph(&game, &AlphaBeta::new(1)).unwrap();
+        let plies: Vec<usize> = graph.iter().map(|&(ply, _)| ply).collect();
+        assert_eq!(plies, [4, 5]);
+    }
+
+    #[test]
+    fn engines_without_evaluation_leave_the_moves_unannotated() {
+        let game = Game::from_transcript("F5D6C3").unwrap();
+        let annotated =
+            annotate_game(&game, &GreedyPlayer::new(), AnnotationThresholds::default()).unwrap();
+        assert!(annotated
+            .moves()
+            .iter()
+            .all(|annotated| annotated.annotation.is_none() && annotated.best.is_some()));
+        assert_eq!(annotated.to_string(), "F5 D6 C3");
+    }
+
+    #[test]
+    fn variants_the_engines_do_not_play_are_rejected() {
+        let mut game = GameBuilder::new().with_rules(AntiOthello).build();
+        game.play_transcript("F5D6C3").unwrap();
+        let engine = AlphaBeta::new(1);
+        assert_eq!(
+            annotate_game(&game, &engine, AnnotationThresholds::default()).err(),
+            Some(GameError::UnsupportedRules)
+        );
+        assert_eq!(
+            evaluation_graph(&game, &engine),
+            Err(GameError::UnsupportedRules)
+        );
+    }
+}
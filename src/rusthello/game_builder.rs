@@ -0,0 +1,185 @@
+use super::board::*;
+use super::game::*;
+use super::rules::*;
+use super::time_control::*;
+
+/// Builder of games starting from a custom setup : rules, board, player to
+/// move and clocks. By default it builds a standard game.
+#[derive(Debug, Clone)]
+pub struct GameBuilder<const N: usize = 8, R = Othello> {
+    pub(crate) rules: R,
+    // starting board, the one of the rules when not given.
+    pub(crate) board: Option<Board<N>>,
+    pub(crate) player: Player,
+    pub(crate) clocks: Option<[TimeControl; 2]>,
+    pub(crate) statistics: bool,
+}
+
+impl GameBuilder {
+    /// Creates a builder of standard 8x8 games, `GameBuilder::<N>::default()`
+    /// giving the builder for other sizes.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<const N: usize, R: Rules<N>> GameBuilder<N, R> {
+    /// Changes the rules of the game (ie `AntiOthello`, or `Reversi` and
+    /// `Handicapped` for other starts), the other settings being kept.
+    pub fn with_rules<S: Rules<N>>(self, rules: S) -> GameBuilder<N, S> {
+        GameBuilder {
+            rules,
+            board: self.board,
+            player: self.player,
+            clocks: self.clocks,
+            statistics: self.statistics,
+        }
+    }
+
+    /// Changes the starting board, instead of the one of the rules.
+    pub fn with_board(mut self, board: Board<N>) -> Self {
+        self.board = Some(board);
+        self
+    }
+
+    /// Changes the player moving first.
+    pub fn with_player(mut self, player: Player) -> Self {
+        self.player = player;
+        self
+    }
+
+    /// Gives a clock to each player, running according to the given time
+    /// controls, see `Game::play_timed`.
+    pub fn with_clocks(mut self, black: TimeControl, white: TimeControl) -> Self {
+        self.clocks = Some([black, white]);
+        self
+    }
+
+    /// Records statistics for each move, see `Game::statistics`.
+    pub fn with_statistics(mut self, statistics: bool) -> Self {
+        self.statistics = statistics;
+        self
+    }
+
+    /// Builds the game. Unless the rules make the passes explicit, a first
+    /// player unable to move passes at once.
+    pub fn build(&self) -> Game<N, R> {
+        Game::from_builder(self)
+    }
+
+    /// Returns the board of the game at its start.
+    pub(crate) fn starting_board(&self) -> Board<N> {
+        self.board.unwrap_or_else(|| self.rules.start_board())
+    }
+}
+
+/// Creates a builder of standard games on a board of size N.
+impl<const N: usize> Default for GameBuilder<N> {
+    fn default() -> Self {
+        Self {
+            rules: Othello,
+            board: None,
+            player: Player::Black,
+            clocks: None,
+            statistics: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::moves::*;
+    use super::*;
+
+    #[test]
+    fn default_builder_builds_a_standard_game() {
+        let game = GameBuilder::new().build();
+        assert_eq!(game.board(), Game::new().board());
+        assert_eq!(game.player(), Some(Player::Black));
+    }
+
+    #[test]
+    fn builder_sets_the_board_and_the_player() {
+        let board = Board::new_start()
+            .play(Player::Black, 5, 4)
+            .unwrap()
+            .unwrap();
+        let mut game = GameBuilder::new()
+            .with_board(board)
+            .with_player(Player::White)
+            .build();
+        assert_eq!(game.board(), &board);
+        assert_eq!(game.count_pieces(), (4, 1));
+        game.play(Player::White, 5, 5).unwrap();
+        assert_eq!(game.history().len(), 1);
+    }
+
+    #[test]
+    fn blocked_first_player_passes_unless_passes_are_explicit() {
+        // White can't move, Black can capture at C1.
+        let mut board = Board::new();
+        board.set_piece(0, 0, Some(Player::Black)).unwrap();
+        board.set_piece(1, 0, Some(Player::White)).unwrap();
+        let builder = GameBuilder::new()
+            .with_board(board)
+            .with_player(Player::White);
+
+        let game = builder.build();
+        assert_eq!(game.player(), Some(Player::Black));
+        assert!(game.opponent_is_blocked());
+        assert_eq!(game.history()[0].played, Move::Pass);
+
+        let game = builder.with_rules(ExplicitPasses(Othello)).build();
+        assert_eq!(game.player(), Some(Player::White));
+        assert!(game.must_pass());
+        assert!(game.history().is_empty());
+    }
+
+    #[test]
+    fn handicap_gives_corners_to_the_weaker_player() {
+        let game = GameBuilder::new()
+            .with_rules(Handicapped::new(Othello, Player::White, 3).unwrap())
+            .build();
+        let board = game.board();
+        assert_eq!(board.get_piece(0, 0).unwrap(), Some(Player::White));
+        assert_eq!(board.get_piece(7, 7).unwrap(), Some(Player::White));
+        assert_eq!(board.get_piece(7, 0).unwrap(), Some(Player::White));
+        assert_eq!(board.get_piece(0, 7).unwrap(), None);
+        assert_eq!(game.count_pieces(), (2, 5));
+        assert_eq!(
+            game.handicap(),
+            Some(Handicap {
+                player: Player::White,
+                corners: 3
+            })
+        );
+        assert_eq!(game.player(), Some(Player::Black));
+    }
+
+    #[test]
+    fn handicap_gives_one_to_four_corners() {
+        let game = GameBuilder::new()
+            .with_rules(Handicapped::new(Othello, Player::Black, 4).unwrap())
+            .build();
+        assert_eq!(game.count_pieces(), (6, 2));
+        assert_eq!(
+            Handicapped::new(Othello, Player::Black, 5),
+            Err(GameError::InvalidHandicap { corners: 5 })
+        );
+        assert_eq!(
+            Handicapped::new(Othello, Player::Black, 0),
+            Err(GameError::InvalidHandicap { corners: 0 })
+        );
+        assert_eq!(GameBuilder::new().build().handicap(), None);
+    }
+
+    #[test]
+    fn placement_phase_starts_with_an_empty_center() {
+        let game = GameBuilder::new().with_rules(Reversi(Othello)).build();
+        assert!(game.placement_phase());
+        assert_eq!(game.board(), &Board::new());
+        assert_eq!(game.player(), Some(Player::Black));
+        assert!(!game.game_over());
+        assert!(!GameBuilder::new().build().placement_phase());
+    }
+}
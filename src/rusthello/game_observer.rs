@@ -0,0 +1,20 @@
+use super::game::*;
+use super::rules::*;
+
+/// Observer of a game, notified by `Game` when its state changes. It allows
+/// loggers, broadcasters or user interfaces to follow a game without polling
+/// it. All the notifications do nothing by default.
+pub trait GameObserver<const N: usize = 8, R: Rules<N> = Othello> {
+    /// A piece was placed, the game being in its state after the move.
+    fn on_move(&mut self, _game: &Game<N, R>, _entry: &HistoryEntry) {}
+
+    /// A player passed, the game being in its state after the pass.
+    fn on_pass(&mut self, _game: &Game<N, R>, _entry: &HistoryEntry) {}
+
+    /// The game is over, by the rules, a resignation or a time loss.
+    fn on_game_over(&mut self, _game: &Game<N, R>) {}
+
+    /// The given move or pass was undone, the game being in its state after
+    /// the undo.
+    fn on_undo(&mut self, _game: &Game<N, R>, _entry: &HistoryEntry) {}
+}
@@ -0,0 +1,195 @@
+use core::fmt;
+
+use super::board::*;
+
+/// The result of a finished game, with the reason why it ended. The pieces
+/// counts of a game played to the end are the official ones : the empty
+/// squares are awarded to the winner, or shared in case of a draw.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GameResult {
+    /// None of the players can move, some squares being still empty.
+    BothBlocked { black: u8, white: u8 },
+    /// All the squares are occupied.
+    BoardFull { black: u8, white: u8 },
+    /// The given player resigned.
+    Resignation { player: Player },
+    /// The given player exceeded its time.
+    Timeout { player: Player },
+    /// The game was stopped, the endgame solver proving the given official
+    /// pieces counts with a perfect play.
+    Adjudicated { black: u8, white: u8 },
+    /// A game of anti-Othello played to the end, won by the player with the
+    /// fewest pieces, the empty squares being left out of the counts.
+    FewestPieces { black: u8, white: u8 },
+}
+
+impl GameResult {
+    /// Builds the result of a game played to the end on the given board.
+    pub fn from_board<const N: usize>(board: &Board<N>) -> Self {
+        let (mut black, mut white) = board.count_pieces();
+        let empties = board.empties();
+        if empties == 0 {
+            return GameResult::BoardFull { black, white };
+        }
+        if black > white {
+            black += empties;
+        } else if white > black {
+            white += empties;
+        } else {
+            black += empties / 2;
+            white += empties / 2;
+        }
+        GameResult::BothBlocked { black, white }
+    }
+
+    /// Builds the result of a game of anti-Othello played to the end on the
+    /// given board, see `AntiOthello`.
+    pub fn from_board_reversed<const N: usize>(board: &Board<N>) -> Self {
+        let (black, white) = board.count_pieces();
+        GameResult::FewestPieces { black, white }
+    }
+
+    /// Returns the winner, None for a draw.
+    pub fn winner(&self) -> Option<Player> {
+        match *self {
+            GameResult::BothBlocked { black, white }
+            | GameResult::BoardFull { black, white }
+            | GameResult::Adjudicated { black, white } => {
+                if black > white {
+                    Some(Player::Black)
+                } else if white > black {
+                    Some(Player::White)
+                } else {
+                    None
+                }
+            }
+            GameResult::FewestPieces { black, white } => {
+                if black < white {
+                    Some(Player::Black)
+                } else if white < black {
+                    Some(Player::White)
+                } else {
+                    None
+                }
+            }
+            GameResult::Resignation { player } | GameResult::Timeout { player } => {
+                Some(player.opponent())
+            }
+        }
+    }
+
+    /// Returns the official pieces counts (Black then White) of a game played
+    /// to the end (or adjudicated), None if the game ended by a resignation or
+    /// a timeout.
+    pub fn score(&self) -> Option<(u8, u8)> {
+        match *self {
+            GameResult::BothBlocked { black, white }
+            | GameResult::BoardFull { black, white }
+            | GameResult::Adjudicated { black, white }
+            | GameResult::FewestPieces { black, white } => Some((black, white)),
+            GameResult::Resignation { .. } | GameResult::Timeout { .. } => None,
+        }
+    }
+
+    /// Returns the difference between the official pieces counts, None if the
+    /// game ended by a resignation or a timeout.
+    pub fn margin(&self) -> Option<u8> {
+        self.score().map(|(black, white)| black.abs_diff(white))
+    }
+}
+
+impl fmt::Display for GameResult {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match (self.winner(), self.score()) {
+            (Some(winner), Some((black, white))) => {
+                write!(f, "{} wins {} - {}", winner, black, white)?
+            }
+            (None, Some((black, white))) => write!(f, "Draw {} - {}", black, white)?,
+            (Some(winner), None) => write!(f, "{} wins", winner)?,
+            (None, None) => unreachable!("a game without score always has a winner"),
+        }
+        match self {
+            GameResult::BothBlocked { .. } => f.write_str(", none of the players can move"),
+            GameResult::BoardFull { .. } => f.write_str(", the board is full"),
+            GameResult::Resignation { player } => write!(f, ", {} resigned", player),
+            GameResult::Timeout { player } => write!(f, ", {} lost on time", player),
+            GameResult::Adjudicated { .. } => f.write_str(", adjudicated by the endgame solver"),
+            GameResult::FewestPieces { .. } => f.write_str(", the fewest pieces win"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::*;
+
+    #[test]
+    fn empty_squares_are_awarded_to_the_winner() {
+        let mut board = Board::new();
+        board.set_piece(0, 0, Some(Player::Black)).unwrap();
+        board.set_piece(1, 0, Some(Player::Black)).unwrap();
+        board.set_piece(7, 7, Some(Player::White)).unwrap();
+        let result = GameResult::from_board(&board);
+        assert_eq!(
+            result,
+            GameResult::BothBlocked {
+                black: 63,
+                white: 1
+            }
+        );
+        assert_eq!(result.winner(), Some(Player::Black));
+        assert_eq!(result.margin(), Some(62));
+    }
+
+    #[test]
+    fn empty_squares_are_shared_for_a_draw() {
+        let mut board = Board::new();
+        board.set_piece(0, 0, Some(Player::Black)).unwrap();
+        board.set_piece(7, 7, Some(Player::White)).unwrap();
+        let result = GameResult::from_board(&board);
+        assert_eq!(result.score(), Some((32, 32)));
+        assert_eq!(result.margin(), Some(0));
+        assert_eq!(result.winner(), None);
+    }
+
+    #[test]
+    fn resignation_and_timeout_have_no_score() {
+        let result = GameResult::Timeout {
+            player: Player::Black,
+        };
+        assert_eq!(result.winner(), Some(Player::White));
+        assert_eq!(result.score(), None);
+        assert_eq!(result.margin(), None);
+    }
+
+    #[test]
+    fn display_gives_the_reason() {
+        let result = GameResult::BoardFull {
+            black: 40,
+            white: 24,
+        };
+        assert_eq!(result.to_string(), "Black wins 40 - 24, the board is full");
+        let result = GameResult::Resignation {
+            player: Player::White,
+        };
+        assert_eq!(result.to_string(), "Black wins, White resigned");
+    }
+
+    #[test]
+    fn fewest_pieces_win_reversed_games() {
+        let mut board = Board::new();
+        board.set_piece(0, 0, Some(Player::Black)).unwrap();
+        board.set_piece(1, 0, Some(Player::Black)).unwrap();
+        board.set_piece(7, 7, Some(Player::White)).unwrap();
+        let result = GameResult::from_board_reversed(&board);
+        assert_eq!(result.score(), Some((2, 1)));
+        assert_eq!(result.winner(), Some(Player::White));
+        assert_eq!(
+            result.to_string(),
+            "White wins 2 - 1, the fewest pieces win"
+        );
+    }
+}
@@ -0,0 +1,515 @@
+use std::time::Instant;
+
+use super::board::*;
+use super::endgame_solver::*;
+use super::game::*;
+use super::game_result::*;
+use super::moves::*;
+use super::rules::*;
+use super::virtual_player::*;
+
+/// Events of a session, given to its listeners with the game in its state
+/// after the event.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SessionEvent {
+    /// The given player is asked for its move.
+    Thinking { player: Player },
+    /// The given player played, with the analysis of its move.
+    Played {
+        player: Player,
+        analysis: MoveAnalysis,
+    },
+    /// The given player passed, being blocked.
+    Passed { player: Player },
+    /// The given player resigned, or gave no valid move.
+    Resigned { player: Player },
+    /// The given player exceeded its time.
+    TimeForfeit { player: Player },
+    /// The given player took back its last move, with the reply of its
+    /// opponent.
+    TakenBack { player: Player },
+    /// The moves taken back were redone, until the turn of the given player.
+    Redone { player: Player },
+    /// The game is over.
+    GameOver { result: GameResult },
+}
+
+/// Listener of the events of a session, see `GameSession::add_listener`.
+pub type SessionListener<R = Othello> = dyn FnMut(&Game<8, R>, &SessionEvent);
+
+/// A game between two participants, humans (see `HumanPlayer`) or engines,
+/// running the turns loop : the participants are asked for their moves, the
+/// blocked players pass, the clocks of timed games are used, and a
+/// participant giving no valid move, or wanting to resign, resigns. The
+/// listeners follow the game through the events, user interfaces only having
+/// to render them.
+/// The game is played by the given rules : with explicit passes the blocked
+/// players pass, and during the placement phase the first free central
+/// square is taken for the participants.
+pub struct GameSession<R: Rules = Othello> {
+    game: Game<8, R>,
+    black: Box<dyn VirtualPlayer>,
+    white: Box<dyn VirtualPlayer>,
+    listeners: Vec<Box<SessionListener<R>>>,
+    adjudication_empties: Option<u8>,
+    // when the player to move started to think, see `start_thinking`.
+    thinking_since: Option<Instant>,
+}
+
+impl<R: Rules> GameSession<R> {
+    /// Creates a session playing the given game between the given
+    /// participants.
+    pub fn new(
+        game: Game<8, R>,
+        black: Box<dyn VirtualPlayer>,
+        white: Box<dyn VirtualPlayer>,
+    ) -> Self {
+        Self {
+            game,
+            black,
+            white,
+            listeners: Vec::new(),
+            adjudication_empties: None,
+            thinking_since: None,
+        }
+    }
+
+    /// Adjudicates the game with the endgame solver once the given count of
+    /// empty squares is reached, instead of playing it to the end, see
+    /// `Game::adjudicate`. The games of the variants the solver doesn't play
+    /// are played to the end.
+    pub fn with_adjudication(mut self, empties: u8) -> Self {
+        self.adjudication_empties = Some(empties);
+        self
+    }
+
+    /// Adds a listener notified of the events of the session.
+    pub fn add_listener<F>(&mut self, listener: F)
+    where
+        F: FnMut(&Game<8, R>, &SessionEvent) + 'static,
+    {
+        self.listeners.push(Box::new(listener));
+    }
+
+    pub fn game(&self) -> &Game<8, R> {
+        &self.game
+    }
+
+    /// Replaces the game of the session (ie by a loaded one), the
+    /// participants keeping their colors. Returns the previous game.
+    pub fn replace_game(&mut self, game: Game<8, R>) -> Game<8, R> {
+        self.thinking_since = None;
+        std::mem::replace(&mut self.game, game)
+    }
+
+    /// Starts the clock of the player to move now, for a participant
+    /// choosing its move before its turn is played (ie a human typing it
+    /// while the game can still be changed), the time spent until its move is
+    /// played being charged to its clock.
+    pub fn start_thinking(&mut self) {
+        self.thinking_since = Some(Instant::now());
+    }
+
+    /// Ends the session, returning its game.
+    pub fn into_game(self) -> Game<8, R> {
+        self.game
+    }
+
+    /// Returns the participant playing the given color.
+    pub fn participant(&self, player: Player) -> &dyn VirtualPlayer {
+        match player {
+            Player::Black => self.black.as_ref(),
+            Player::White => self.white.as_ref(),
+        }
+    }
+
+    /// Plays the game until its end, and returns its result.
+    pub fn run(&mut self) -> GameResult {
+        while self.play_turn() {}
+        self.game
+            .result()
+            .expect("Unexpected running game at the end of the session.")
+    }
+
+    /// Takes back the last move of the given player, usually a human, with
+    /// the reply of its opponent, see `Game::takeback`.
+    pub fn takeback(&mut self, player: Player) -> Result<(), GameError> {
+        self.game.takeback(player)?;
+        self.thinking_since = None;
+        self.notify(&SessionEvent::TakenBack { player });
+        Ok(())
+    }
+
+    /// Redoes the moves taken back, until the turn is back to the given
+    /// player, see `Game::redo`.
+    pub fn redo(&mut self, player: Player) -> Result<(), GameError> {
+        self.game.redo()?;
+        while self.game.player() != Some(player) && self.game.can_redo() {
+            self.game.redo()?;
+        }
+        self.thinking_since = None;
+        self.notify(&SessionEvent::Redone { player });
+        Ok(())
+    }
+
+    /// Plays the turn of the current player, with the passes following it.
+    /// Returns false once the game is over.
+    pub fn play_turn(&mut self) -> bool {
+        let player = match self.game.player() {
+            Some(player) => player,
+            None => return false,
+        };
+        if self.must_adjudicate() {
+            self.game
+                .adjudicate(&EndgameSolver::new())
+                .expect("Unexpected adjudication error while the game is running.");
+            self.notify_game_over();
+            return false;
+        }
+        let played_from = self.game.history().len();
+        let mut analysis = None;
+        if self.game.must_pass() {
+            self.game
+                .pass(player)
+                .expect("Unexpected pass error while the player has to pass.");
+        } else if self.game.placement_phase() {
+            let (x, y) = self.game.legal_moves()[0];
+            self.game
+                .play(player, x, y)
+                .expect("Unexpected placement error on a free central square.");
+        } else {
+            self.notify(&SessionEvent::Thinking { player });
+            match self.ask_move(player) {
+                Ok(found) => analysis = Some(found),
+                Err(event) => {
+                    self.notify(&event);
+                    self.notify_game_over();
+                    return false;
+                }
+            }
+        }
+
+        let played: Vec<HistoryEntry> = self.game.history()[played_from..].to_vec();
+        for entry in played {
+            let event = match (entry.played, analysis.take()) {
+                (Move::Place { .. }, Some(analysis)) => SessionEvent::Played {
+                    player: entry.player,
+                    analysis,
+                },
+                (Move::Place { x, y }, None) => SessionEvent::Played {
+                    player: entry.player,
+                    analysis: MoveAnalysis {
+                        position: (x, y),
+                        score: None,
+                        variation: vec![(x, y)],
+                    },
+                },
+                (Move::Pass, _) => SessionEvent::Passed {
+                    player: entry.player,
+                },
+            };
+            self.notify(&event);
+        }
+        self.notify_game_over();
+        !self.game.game_over()
+    }
+
+    /// Asks the participant for its move and plays it, the error being the
+    /// event ending the game when it fails.
+    fn ask_move(&mut self, player: Player) -> Result<MoveAnalysis, SessionEvent> {
+        let participant = match player {
+            Player::Black => self.black.as_ref(),
+            Player::White => self.white.as_ref(),
+        };
+        let start = self.thinking_since.take().unwrap_or_else(Instant::now);
+        let analysis = match self.game.clock(player) {
+            Some(clock) => participant
+                .compute_move_timed(self.game.board(), player, clock)
+                .map(|position| MoveAnalysis {
+                    position,
+                    score: None,
+                    variation: vec![position],
+                }),
+            None => participant.compute_move_full(self.game.board(), player),
+        };
+        let elapsed = start.elapsed();
+        if self.game.check_time(elapsed) {
+            return Err(SessionEvent::TimeForfeit { player });
+        }
+        match analysis {
+            Some(analysis) if !participant.wants_to_resign() => {
+                let (x, y) = analysis.position;
+                if self
+                    .game
+                    .play_timed(player, Move::Place { x, y }, elapsed)
+                    .is_ok()
+                {
+                    return Ok(analysis);
+                }
+            }
+            _ => (),
+        }
+        self.game
+            .resign(player)
+            .expect("Unexpected resignation error while the game is running.");
+        Err(SessionEvent::Resigned { player })
+    }
+
+    fn must_adjudicate(&self) -> bool {
+        match self.adjudication_empties {
+            Some(empties) => {
+                self.game.rules().standard_play()
+                    && !self.game.placement_phase()
+                    && self.game.board().empties() <= empties
+            }
+            None => false,
+        }
+    }
+
+    fn notify_game_over(&mut self) {
+        if let Some(result) = self.game.result() {
+            self.notify(&SessionEvent::GameOver { result });
+        }
+    }
+
+    fn notify(&mut self, event: &SessionEvent) {
+        for listener in self.listeners.iter_mut() {
+            listener(&self.game, event);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc, time::Duration};
+
+    use super::super::game_builder::*;
+    use super::super::greedy_player::*;
+    use super::super::human_player::*;
+    use super::super::time_control::*;
+    use super::*;
+
+    fn recorded_session<R: Rules>(
+        game: Game<8, R>,
+        black: Box<dyn VirtualPlayer>,
+        white: Box<dyn VirtualPlayer>,
+    ) -> (GameSession<R>, Rc<RefCell<Vec<SessionEvent>>>) {
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let mut session = GameSession::new(game, black, white);
+        let recorded = Rc::clone(&events);
+        session.add_listener(move |_, event| recorded.borrow_mut().push(event.clone()));
+        (session, events)
+    }
+
+    #[test]
+    fn run_plays_until_the_end() {
+        let (mut session, events) = recorded_session(
+            Game::new(),
+            Box::new(GreedyPlayer::new()),
+            Box::new(AlphaBeta::new(1)),
+        );
+        let result = session.run();
+        let events = events.borrow();
+        assert_eq!(events.last(), Some(&SessionEvent::GameOver { result }));
+        let played = events
+            .iter()
+            .filter(|event| matches!(event, SessionEvent::Played { .. }))
+            .count();
+        let passed = events
+            .iter()
+            .filter(|event| matches!(event, SessionEvent::Passed { .. }))
+            .count();
+        assert_eq!(played + passed, session.game().ply());
+        assert!(!session.play_turn());
+    }
+
+    #[test]
+    fn play_turn_reports_the_passes() {
+        let mut board = Board::new();
+        board.set_piece(0, 0, Some(Player::Black)).unwrap();
+        board.set_piece(1, 0, Some(Player::White)).unwrap();
+        board.set_piece(0, 2, Some(Player::Black)).unwrap();
+        board.set_piece(1, 2, Some(Player::White)).unwrap();
+        let game = GameBuilder::new().with_board(board).build();
+        let (mut session, events) = recorded_session(
+            game,
+            Box::new(HumanPlayer::new(|_, _| Some((2, 0)))),
+            Box::new(GreedyPlayer::new()),
+        );
+        assert!(session.play_turn());
+        assert_eq!(
+            events.borrow()[1..],
+            [
+                SessionEvent::Played {
+                    player: Player::Black,
+                    analysis: MoveAnalysis {
+                        position: (2, 0),
+                        score: None,
+                        variation: vec![(2, 0)],
+                    },
+                },
+                SessionEvent::Passed {
+                    player: Player::White
+                },
+            ]
+        );
+        assert_eq!(session.game().player(), Some(Player::Black));
+    }
+
+    #[test]
+    fn play_turn_passes_for_the_blocked_player_with_explicit_passes() {
+        let mut board = Board::new();
+        board.set_piece(0, 0, Some(Player::Black)).unwrap();
+        board.set_piece(1, 0, Some(Player::White)).unwrap();
+        board.set_piece(0, 2, Some(Player::Black)).unwrap();
+        board.set_piece(1, 2, Some(Player::White)).unwrap();
+        let game = GameBuilder::new()
+            .with_rules(ExplicitPasses(Othello))
+            .with_board(board)
+            .build();
+        let (mut session, events) = recorded_session(
+            game,
+            Box::new(HumanPlayer::new(|_, _| Some((2, 0)))),
+            Box::new(GreedyPlayer::new()),
+        );
+        assert!(session.play_turn());
+        assert_eq!(session.game().player(), Some(Player::White));
+        assert!(session.play_turn());
+        assert_eq!(
+            events.borrow().last(),
+            Some(&SessionEvent::Passed {
+                player: Player::White
+            })
+        );
+        assert_eq!(session.game().player(), Some(Player::Black));
+    }
+
+    #[test]
+    fn play_turn_places_the_pieces_in_the_center() {
+        let game = GameBuilder::new().with_rules(Reversi(Othello)).build();
+        let (mut session, events) = recorded_session(
+            game,
+            Box::new(GreedyPlayer::new()),
+            Box::new(GreedyPlayer::new()),
+        );
+        while session.game().placement_phase() {
+            assert!(session.play_turn());
+        }
+        assert_eq!(session.game().ply(), 4);
+        assert!(events
+            .borrow()
+            .iter()
+            .all(|event| matches!(event, SessionEvent::Played { .. })));
+    }
+
+    #[test]
+    fn takeback_gives_the_turn_back() {
+        let (mut session, events) = recorded_session(
+            Game::new(),
+            Box::new(HumanPlayer::new(|_, _| Some((5, 4)))),
+            Box::new(GreedyPlayer::new()),
+        );
+        session.play_turn();
+        session.play_turn();
+        session.takeback(Player::Black).unwrap();
+        assert!(session.game().history().is_empty());
+        assert_eq!(
+            events.borrow().last(),
+            Some(&SessionEvent::TakenBack {
+                player: Player::Black
+            })
+        );
+        session.redo(Player::Black).unwrap();
+        assert_eq!(session.game().ply(), 2);
+        assert_eq!(session.game().player(), Some(Player::Black));
+    }
+
+    #[test]
+    fn invalid_moves_resign() {
+        let (mut session, events) = recorded_session(
+            Game::new(),
+            Box::new(HumanPlayer::new(|_, _| Some((0, 0)))),
+            Box::new(GreedyPlayer::new()),
+        );
+        assert!(!session.play_turn());
+        assert_eq!(session.game().resigned(), Some(Player::Black));
+        assert_eq!(
+            events.borrow()[1],
+            SessionEvent::Resigned {
+                player: Player::Black
+            }
+        );
+    }
+
+    #[test]
+    fn adjudication_stops_the_game() {
+        let (session, events) = recorded_session(
+            Game::new(),
+            Box::new(GreedyPlayer::new()),
+            Box::new(GreedyPlayer::new()),
+        );
+        let mut session = session.with_adjudication(8);
+        let result = session.run();
+        assert!(matches!(result, GameResult::Adjudicated { .. }));
+        assert_eq!(session.game().board().empties(), 8);
+        assert_eq!(
+            events.borrow().last(),
+            Some(&SessionEvent::GameOver { result })
+        );
+    }
+
+    #[test]
+    fn adjudication_plays_the_other_variants_to_the_end() {
+        let game = GameBuilder::new().with_rules(AntiOthello).build();
+        let (session, _) = recorded_session(
+            game,
+            Box::new(GreedyPlayer::new()),
+            Box::new(GreedyPlayer::new()),
+        );
+        let mut session = session.with_adjudication(8);
+        let result = session.run();
+        assert!(!matches!(result, GameResult::Adjudicated { .. }));
+        assert!(session.game().game_over());
+    }
+
+    #[test]
+    fn start_thinking_charges_the_time_before_the_turn() {
+        let control = TimeControl::new(Duration::from_secs(60), Duration::from_secs(0));
+        let game = Game::new().with_clocks(control, control);
+        let (mut session, _) = recorded_session(
+            game,
+            Box::new(AlphaBeta::new(1)),
+            Box::new(AlphaBeta::new(1)),
+        );
+        session.start_thinking();
+        std::thread::sleep(Duration::from_millis(20));
+        session.play_turn();
+        let remaining = session.game().clock(Player::Black).unwrap().remaining();
+        assert!(remaining <= Duration::from_millis(59_980));
+        session.play_turn();
+        let remaining = session.game().clock(Player::White).unwrap().remaining();
+        assert!(remaining > Duration::from_millis(59_980));
+    }
+
+    #[test]
+    fn time_forfeits_end_the_game() {
+        let plenty = TimeControl::new(Duration::from_secs(3600), Duration::from_secs(0));
+        let none = TimeControl::new(Duration::from_secs(0), Duration::from_secs(0));
+        let game = Game::new().with_clocks(plenty, none);
+        let (mut session, events) = recorded_session(
+            game,
+            Box::new(AlphaBeta::new(1)),
+            Box::new(AlphaBeta::new(1)),
+        );
+        let result = session.run();
+        assert_eq!(
+            result,
+            GameResult::Timeout {
+                player: Player::White
+            }
+        );
+        assert!(events.borrow().contains(&SessionEvent::TimeForfeit {
+            player: Player::White
+        }));
+    }
+}
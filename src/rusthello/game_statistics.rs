@@ -0,0 +1,90 @@
+use alloc::vec::Vec;
+use core::time::Duration;
+
+use super::board::*;
+
+/// Data recorded for a move or a pass of a game, see `Game::with_statistics`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MoveStatistics {
+    /// The turn of the move, as in the history of the game.
+    pub turn: usize,
+    pub player: Player,
+    /// The count of pieces flipped by the move, 0 for a pass or a piece
+    /// placed during the placement phase.
+    pub flipped: u8,
+    /// The count of legal moves of Black after the move.
+    pub black_mobility: u8,
+    /// The count of legal moves of White after the move.
+    pub white_mobility: u8,
+    /// The time spent on the move, known for the moves given to
+    /// `Game::play_timed`.
+    pub time_spent: Option<Duration>,
+}
+
+/// Statistics of the moves of a game, for post-game summaries and plots.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GameStatistics {
+    moves: Vec<MoveStatistics>,
+}
+
+impl GameStatistics {
+    /// Returns the statistics of each move and pass, in order.
+    pub fn moves(&self) -> &[MoveStatistics] {
+        &self.moves
+    }
+
+    /// Returns the total count of pieces flipped by the given player.
+    pub fn flipped(&self, player: Player) -> u32 {
+        self.moves_of(player)
+            .map(|stats| stats.flipped as u32)
+            .sum()
+    }
+
+    /// Returns the average count of legal moves the given player had after
+    /// its own moves, None if it didn't play.
+    pub fn average_mobility(&self, player: Player) -> Option<f64> {
+        let mobilities: Vec<u8> = self
+            .moves_of(player)
+            .map(|stats| match player {
+                Player::Black => stats.black_mobility,
+                Player::White => stats.white_mobility,
+            })
+            .collect();
+        if mobilities.is_empty() {
+            return None;
+        }
+        let total: u32 = mobilities.iter().map(|&mobility| mobility as u32).sum();
+        Some(total as f64 / mobilities.len() as f64)
+    }
+
+    /// Returns the total time spent by the given player on its timed moves.
+    pub fn time_spent(&self, player: Player) -> Duration {
+        self.moves_of(player)
+            .filter_map(|stats| stats.time_spent)
+            .sum()
+    }
+
+    pub(crate) fn push(&mut self, stats: MoveStatistics) {
+        self.moves.push(stats);
+    }
+
+    /// Sets the time spent on the move of the given turn.
+    pub(crate) fn set_time_spent(&mut self, turn: usize, elapsed: Duration) {
+        if let Some(stats) = self.moves.iter_mut().find(|stats| stats.turn == turn) {
+            stats.time_spent = Some(elapsed);
+        }
+    }
+
+    /// Forgets the moves played after the given count of turns, once undone.
+    pub(crate) fn truncate(&mut self, turns: usize) {
+        self.moves.retain(|stats| stats.turn <= turns);
+    }
+
+    fn moves_of(&self, player: Player) -> impl Iterator<Item = &MoveStatistics> {
+        self.moves
+            .iter()
+            .filter(move |stats| stats.player == player)
+    }
+}
@@ -1,32 +1,87 @@
+use alloc::vec::Vec;
+
 use super::board::*;
 
 /// GameStatus implement cross-cutting concerns about a game.
 /// It's useful for the game workflow and virtual players implémentations.
-#[derive(Default)]
+/// After a move, the status is updated from the changed squares only : the
+/// legality of an empty square can only change if one of the squares on its
+/// lines changed.
+/// The legal squares of both players are kept, then UIs and engines don't
+/// have to check the moves again. They aren't serialized : a deserialized
+/// status only knows if the players can move, and is built again from the
+/// board at its next update.
+#[derive(Default, Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GameStatus {
     black_can_move: bool,
     white_can_move: bool,
     black_pieces: u8,
     white_pieces: u8,
+    // legal squares of Black and White, valid when tracked.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    legal_squares: [SquareSet; 2],
+    #[cfg_attr(feature = "serde", serde(skip))]
+    tracked: bool,
 }
 
 impl GameStatus {
     /// Build a GameStatus from a board.
-    pub fn evaluate_board(board: &Board) -> Self {
-        let mut black_can_move = false;
-        let mut white_can_move = false;
-        let (black_pieces, white_pieces) = board.count_pieces();
-        if (black_pieces + white_pieces) != 64 {
-            black_can_move = board.can_player_move(Player::Black);
-            white_can_move = board.can_player_move(Player::White);
+    pub fn evaluate_board<const N: usize>(board: &Board<N>) -> Self {
+        let mut status = Self::default();
+        for player in [Player::Black, Player::White] {
+            for (x, y) in board.legal_moves_iter(player) {
+                status.legal_squares[player_index(player)].insert(x, y);
+            }
         }
+        status.tracked = true;
+        status.update_counts(board);
+        status
+    }
 
-        Self {
-            black_can_move,
-            white_can_move,
-            black_pieces,
-            white_pieces,
+    /// Updates the status after a move on the given board, the move having
+    /// changed the given squares (the placed piece and the flipped ones).
+    /// A status not tracking the legal squares, once deserialized, is built
+    /// again from the board.
+    pub fn update_after_move<const N: usize>(&mut self, board: &Board<N>, changed: &[(u8, u8)]) {
+        if !self.tracked {
+            *self = Self::evaluate_board(board);
+            return;
         }
+
+        let mut candidates = SquareSet::default();
+        for &(x, y) in changed {
+            for player_squares in self.legal_squares.iter_mut() {
+                player_squares.remove(x, y);
+            }
+            for (line_x, line_y) in Board::<N>::lines_through(x, y) {
+                if board.get_piece(line_x, line_y) == Ok(None) {
+                    candidates.insert(line_x, line_y);
+                }
+            }
+        }
+        for (x, y) in candidates.iter() {
+            for player in [Player::Black, Player::White] {
+                let legal = board
+                    .is_move_valid(player, x, y)
+                    .expect("Unexpected square out of the board.");
+                let player_squares = &mut self.legal_squares[player_index(player)];
+                if legal {
+                    player_squares.insert(x, y);
+                } else {
+                    player_squares.remove(x, y);
+                }
+            }
+        }
+        self.update_counts(board);
+    }
+
+    fn update_counts<const N: usize>(&mut self, board: &Board<N>) {
+        let (black_pieces, white_pieces) = board.count_pieces();
+        self.black_pieces = black_pieces;
+        self.white_pieces = white_pieces;
+        self.black_can_move = !self.legal_squares[player_index(Player::Black)].is_empty();
+        self.white_can_move = !self.legal_squares[player_index(Player::White)].is_empty();
     }
 
     /// Returns the count of pieces for the given player.
@@ -44,6 +99,31 @@ impl GameStatus {
         }
     }
 
+    /// Returns the squares where the given player can move, in grid order.
+    pub fn legal_squares(&self, player: Player) -> Vec<(u8, u8)> {
+        self.legal_squares_iter(player).collect()
+    }
+
+    /// Iterates over the squares where the given player can move.
+    pub(crate) fn legal_squares_iter(&self, player: Player) -> impl Iterator<Item = (u8, u8)> + '_ {
+        self.legal_squares[player_index(player)].iter()
+    }
+
+    /// Checks if the given player can move to the given square.
+    pub fn is_legal(&self, player: Player, x: u8, y: u8) -> bool {
+        x < 16 && y < 16 && self.legal_squares[player_index(player)].contains(x, y)
+    }
+
+    /// Returns the count of squares where the given player can move.
+    pub fn mobility(&self, player: Player) -> u8 {
+        self.legal_squares[player_index(player)].len()
+    }
+
+    /// Checks if the legal squares are known, see `GameStatus`.
+    pub(crate) fn tracks_legal_squares(&self) -> bool {
+        self.tracked
+    }
+
     /// Is the game over ?
     pub fn game_over(&self) -> bool {
         !self.black_can_move && !self.white_can_move
@@ -62,3 +142,119 @@ impl GameStatus {
         }
     }
 }
+
+fn player_index(player: Player) -> usize {
+    match player {
+        Player::Black => 0,
+        Player::White => 1,
+    }
+}
+
+/// Set of squares of a board up to 16x16, one bit per square.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+struct SquareSet([u64; 4]);
+
+impl SquareSet {
+    fn bit(x: u8, y: u8) -> (usize, u64) {
+        let index = ((y as usize) << 4) | x as usize;
+        (index / 64, 1 << (index % 64))
+    }
+
+    fn insert(&mut self, x: u8, y: u8) {
+        let (word, mask) = Self::bit(x, y);
+        self.0[word] |= mask;
+    }
+
+    fn remove(&mut self, x: u8, y: u8) {
+        let (word, mask) = Self::bit(x, y);
+        self.0[word] &= !mask;
+    }
+
+    fn contains(&self, x: u8, y: u8) -> bool {
+        let (word, mask) = Self::bit(x, y);
+        self.0[word] & mask != 0
+    }
+
+    fn len(&self) -> u8 {
+        self.0.iter().map(|word| word.count_ones() as u8).sum()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.0.iter().all(|&word| word == 0)
+    }
+
+    /// Returns the squares of the set, in grid order.
+    fn iter(&self) -> impl Iterator<Item = (u8, u8)> + '_ {
+        self.0.iter().enumerate().flat_map(|(word_index, &word)| {
+            (0..64)
+                .filter(move |bit| word & (1 << bit) != 0)
+                .map(move |bit| {
+                    let index = word_index * 64 + bit;
+                    ((index & 15) as u8, (index >> 4) as u8)
+                })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::random::*;
+    use super::*;
+
+    #[test]
+    fn update_after_move_follows_random_games() {
+        let mut random = XorShiftRng::new(42);
+        for _ in 0..20 {
+            let mut board = Board::<6>::start();
+            let mut status = GameStatus::evaluate_board(&board);
+            let mut player = Player::Black;
+            while !status.game_over() {
+                if !status.can_player_move(player) {
+                    player = player.opponent();
+                }
+                let moves = board.legal_moves(player);
+                let (x, y) = moves[random.next_below(moves.len())];
+                let (new_board, mut changed) =
+                    board.play_with_flips(player, x, y).unwrap().unwrap();
+                changed.push((x, y));
+                board = new_board;
+                status.update_after_move(&board, &changed);
+                let expected = GameStatus::evaluate_board(&board);
+                for checked in [Player::Black, Player::White] {
+                    assert_eq!(
+                        status.legal_squares(checked),
+                        expected.legal_squares(checked)
+                    );
+                    assert_eq!(
+                        status.can_player_move(checked),
+                        expected.can_player_move(checked)
+                    );
+                    assert_eq!(status.pieces_count(checked), expected.pieces_count(checked));
+                }
+                player = player.opponent();
+            }
+        }
+    }
+
+    #[test]
+    fn legal_squares_are_exposed() {
+        let status = GameStatus::evaluate_board(&Board::new_start());
+        assert_eq!(
+            status.legal_squares(Player::Black),
+            Board::new_start().legal_moves(Player::Black)
+        );
+        assert_eq!(status.mobility(Player::White), 4);
+        assert!(status.is_legal(Player::Black, 3, 2));
+        assert!(!status.is_legal(Player::White, 3, 2));
+        assert!(!status.is_legal(Player::White, 200, 2));
+    }
+
+    #[test]
+    fn untracked_status_is_evaluated_again() {
+        let board = Board::new_start();
+        let mut status = GameStatus::default();
+        status.update_after_move(&board, &[(3, 3)]);
+        assert!(status.can_player_move(Player::Black));
+        assert_eq!(status.mobility(Player::Black), 4);
+    }
+}
@@ -0,0 +1,83 @@
+use alloc::string::{String, ToString};
+use core::cell::Cell;
+
+use super::board::*;
+use super::virtual_player::*;
+
+/// A virtual player choosing the move flipping the most pieces, without any
+/// look-ahead. It's a classic weak baseline : maximizing flips usually gives
+/// mobility to the opponent.
+pub struct GreedyPlayer {
+    move_count: Cell<u32>,
+}
+
+impl GreedyPlayer {
+    /// Creates a new GreedyPlayer.
+    pub fn new() -> Self {
+        Self {
+            move_count: Cell::new(0),
+        }
+    }
+}
+
+impl Default for GreedyPlayer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EngineInfo for GreedyPlayer {
+    fn name(&self) -> String {
+        "Greedy".to_string()
+    }
+}
+
+impl VirtualPlayer for GreedyPlayer {
+    fn move_count(&self) -> u32 {
+        self.move_count.get()
+    }
+
+    fn compute_move(&self, board: &Board, me: Player) -> Option<(u8, u8)> {
+        let mut best_move: Option<((u8, u8), u8)> = None;
+        for (x, y) in GridIterator::new() {
+            let flipped = board
+                .flip_count(me, x, y)
+                .expect("Unexpected error while computing move.");
+            if flipped > 0 {
+                self.move_count.set(self.move_count.get() + 1);
+                match best_move {
+                    Some((_, best_flipped)) if best_flipped >= flipped => (),
+                    _ => best_move = Some(((x, y), flipped)),
+                }
+            }
+        }
+
+        best_move.map(|(position, _)| position)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn greedy_player_maximizes_flips() {
+        // Black can flip one piece at (2, 0), or two at (0, 3).
+        let mut board = Board::new();
+        board.set_piece(0, 0, Some(Player::Black)).unwrap();
+        board.set_piece(1, 0, Some(Player::White)).unwrap();
+        board.set_piece(0, 1, Some(Player::White)).unwrap();
+        board.set_piece(0, 2, Some(Player::White)).unwrap();
+        let player = GreedyPlayer::new();
+        assert_eq!(player.compute_move(&board, Player::Black), Some((0, 3)));
+        assert_eq!(player.move_count(), 2);
+    }
+
+    #[test]
+    fn greedy_player_returns_none_if_it_cant_move() {
+        let mut board = Board::new();
+        board.set_piece(0, 0, Some(Player::Black)).unwrap();
+        let player = GreedyPlayer::new();
+        assert_eq!(player.compute_move(&board, Player::White), None);
+    }
+}
@@ -0,0 +1,77 @@
+use std::sync::mpsc::Receiver;
+
+use super::board::*;
+use super::virtual_player::*;
+
+/// Function providing the moves of a human, see `HumanPlayer::new`.
+pub type HumanInput = dyn Fn(&Board, Player) -> Option<(u8, u8)>;
+
+/// Adapter allowing a human to be used as a VirtualPlayer, the moves being
+/// provided by a user-supplied function (reading a terminal, a GUI event, ...).
+/// Then the game workflow can handle humans and engines the same way.
+pub struct HumanPlayer {
+    input: Box<HumanInput>,
+}
+
+impl HumanPlayer {
+    /// Creates a HumanPlayer getting its moves from the given function.
+    /// The function returns None if the human gives up the game.
+    pub fn new<F>(input: F) -> Self
+    where
+        F: Fn(&Board, Player) -> Option<(u8, u8)> + 'static,
+    {
+        Self {
+            input: Box::new(input),
+        }
+    }
+
+    /// Creates a HumanPlayer getting its moves from a channel.
+    /// Closing the channel means that the human gives up the game.
+    pub fn from_receiver(receiver: Receiver<(u8, u8)>) -> Self {
+        Self::new(move |_, _| receiver.recv().ok())
+    }
+}
+
+impl EngineInfo for HumanPlayer {
+    fn name(&self) -> String {
+        "Human".to_string()
+    }
+}
+
+impl VirtualPlayer for HumanPlayer {
+    fn move_count(&self) -> u32 {
+        0
+    }
+
+    fn compute_move(&self, board: &Board, me: Player) -> Option<(u8, u8)> {
+        (self.input)(board, me)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::mpsc;
+
+    #[test]
+    fn human_player_delegates_to_the_function() {
+        let human = HumanPlayer::new(|_, player| match player {
+            Player::Black => Some((5, 4)),
+            Player::White => None,
+        });
+        let board = Board::new_start();
+        assert_eq!(human.compute_move(&board, Player::Black), Some((5, 4)));
+        assert_eq!(human.compute_move(&board, Player::White), None);
+    }
+
+    #[test]
+    fn human_player_reads_moves_from_a_channel() {
+        let (sender, receiver) = mpsc::channel();
+        let human = HumanPlayer::from_receiver(receiver);
+        let board = Board::new_start();
+        sender.send((3, 2)).unwrap();
+        assert_eq!(human.compute_move(&board, Player::Black), Some((3, 2)));
+        drop(sender);
+        assert_eq!(human.compute_move(&board, Player::Black), None);
+    }
+}
@@ -0,0 +1,90 @@
+use super::player_factory::*;
+use super::virtual_player::*;
+
+/// Named difficulty levels, from the weakest to the strongest, each one
+/// being a concrete configuration of the bundled players.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Level {
+    Beginner,
+    Easy,
+    Medium,
+    Hard,
+    Max,
+}
+
+impl Level {
+    /// All the levels, from the weakest to the strongest.
+    pub const ALL: [Level; 5] = [
+        Level::Beginner,
+        Level::Easy,
+        Level::Medium,
+        Level::Hard,
+        Level::Max,
+    ];
+
+    /// Returns the name of the level.
+    pub fn name(self) -> &'static str {
+        match self {
+            Level::Beginner => "beginner",
+            Level::Easy => "easy",
+            Level::Medium => "medium",
+            Level::Hard => "hard",
+            Level::Max => "max",
+        }
+    }
+
+    /// Returns the level of the given name, ignoring the case.
+    pub fn by_name(name: &str) -> Option<Level> {
+        let name = name.trim().to_ascii_lowercase();
+        Self::ALL.iter().copied().find(|level| level.name() == name)
+    }
+
+    /// Returns the specification of the player of the level, for the
+    /// PlayerFactory :
+    /// * beginner : random moves,
+    /// * easy : greedy, taking the most pieces,
+    /// * medium : minimax handicapped by a shallow depth,
+    /// * hard : alphabeta,
+    /// * max : alphabeta with the opening book and the endgame solver.
+    pub fn spec(self) -> &'static str {
+        match self {
+            Level::Beginner => "random",
+            Level::Easy => "greedy",
+            Level::Medium => "minimax:2",
+            Level::Hard => "alphabeta:6",
+            Level::Max => "pipeline:8",
+        }
+    }
+
+    /// Builds the player of the level.
+    pub fn build(self) -> Box<dyn VirtualPlayer> {
+        PlayerFactory::standard()
+            .build(self.spec())
+            .expect("Unexpected invalid level specification.")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::board::*;
+    use super::*;
+
+    #[test]
+    fn levels_build_players() {
+        let board = Board::new_start();
+        for level in Level::ALL.iter() {
+            let player = level.build();
+            assert!(player.compute_move(&board, Player::Black).is_some());
+        }
+    }
+
+    #[test]
+    fn by_name_finds_the_levels() {
+        assert_eq!(Level::by_name("Hard"), Some(Level::Hard));
+        assert_eq!(Level::by_name(" max "), Some(Level::Max));
+        assert_eq!(Level::by_name("impossible"), None);
+        for level in Level::ALL.iter() {
+            assert_eq!(Level::by_name(level.name()), Some(*level));
+        }
+    }
+}
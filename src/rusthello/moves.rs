@@ -0,0 +1,107 @@
+use alloc::{format, string::String};
+use core::{fmt, str::FromStr};
+
+/// A move of a player : a piece placed at the given position, or a pass
+/// when no placement is possible. Its standard notation is the column
+/// letter followed by the row number (ie `D3`), or `pass`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Move {
+    Place { x: u8, y: u8 },
+    Pass,
+}
+
+impl Move {
+    /// Returns the position of the placed piece, None for a pass.
+    pub fn position(self) -> Option<(u8, u8)> {
+        match self {
+            Move::Place { x, y } => Some((x, y)),
+            Move::Pass => None,
+        }
+    }
+}
+
+impl From<Option<(u8, u8)>> for Move {
+    /// Converts a position as returned by virtual players, None being a pass.
+    fn from(position: Option<(u8, u8)>) -> Self {
+        match position {
+            Some((x, y)) => Move::Place { x, y },
+            None => Move::Pass,
+        }
+    }
+}
+
+impl fmt::Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Move::Place { x, y } => write!(f, "{}{}", (b'A' + x) as char, y + 1),
+            Move::Pass => f.write_str("pass"),
+        }
+    }
+}
+
+impl FromStr for Move {
+    type Err = String;
+
+    /// Parses a move in standard notation, ignoring case. Columns go from A
+    /// to Z and rows from 1 to 26, the move being checked against the board
+    /// only when played.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("invalid move notation : {}", s);
+        if s.eq_ignore_ascii_case("pass") {
+            return Ok(Move::Pass);
+        }
+
+        let mut chars = s.chars();
+        let letter = chars.next().ok_or_else(invalid)?.to_ascii_lowercase();
+        let digits = chars.as_str();
+        if !letter.is_ascii_lowercase()
+            || digits.starts_with('0')
+            || !digits.chars().all(|c| c.is_ascii_digit())
+        {
+            return Err(invalid());
+        }
+        let row: u8 = digits.parse().map_err(|_| invalid())?;
+        if row > 26 {
+            return Err(invalid());
+        }
+
+        Ok(Move::Place {
+            x: letter as u8 - b'a',
+            y: row - 1,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_uses_the_standard_notation() {
+        assert_eq!(Move::Place { x: 3, y: 2 }.to_string(), "D3");
+        assert_eq!(Move::Place { x: 9, y: 9 }.to_string(), "J10");
+        assert_eq!(Move::Pass.to_string(), "pass");
+    }
+
+    #[test]
+    fn from_str_parses_the_standard_notation() {
+        assert_eq!("D3".parse(), Ok(Move::Place { x: 3, y: 2 }));
+        assert_eq!("f5".parse(), Ok(Move::Place { x: 5, y: 4 }));
+        assert_eq!("j10".parse(), Ok(Move::Place { x: 9, y: 9 }));
+        assert_eq!("PASS".parse(), Ok(Move::Pass));
+    }
+
+    #[test]
+    fn from_str_rejects_invalid_moves() {
+        for notation in &["", "D", "3D", "D0", "D03", "D+3", "D27", "D3 ", "é3"] {
+            assert!(notation.parse::<Move>().is_err(), "{}", notation);
+        }
+    }
+
+    #[test]
+    fn moves_convert_from_positions() {
+        assert_eq!(Move::from(Some((5, 4))).position(), Some((5, 4)));
+        assert_eq!(Move::from(None), Move::Pass);
+    }
+}
@@ -0,0 +1,138 @@
+use std::cell::RefCell;
+use std::io::BufReader;
+use std::net::{TcpStream, ToSocketAddrs};
+
+use super::board::*;
+use super::engine_protocol::*;
+use super::virtual_player::*;
+
+/// A virtual player forwarding positions to a remote peer over TCP, and
+/// waiting for its moves. The peer can use `serve_engine` to expose any
+/// VirtualPlayer (an engine, or a remote human using HumanPlayer).
+/// When the player is dropped the `quit` request is sent to the peer.
+pub struct NetworkPlayer {
+    peer: String,
+    io: RefCell<NetworkIo>,
+    last_error: RefCell<Option<String>>,
+}
+
+struct NetworkIo {
+    reader: BufReader<TcpStream>,
+    writer: TcpStream,
+}
+
+impl NetworkPlayer {
+    /// Connects to the given remote peer.
+    pub fn connect<A: ToSocketAddrs>(address: A) -> Result<Self, String> {
+        let stream = TcpStream::connect(address)
+            .map_err(|error| format!("unable to connect to the remote player : {}", error))?;
+        Self::from_stream(stream)
+    }
+
+    /// Uses an already established connection.
+    pub fn from_stream(stream: TcpStream) -> Result<Self, String> {
+        let writer = stream
+            .try_clone()
+            .map_err(|error| format!("unable to use the connection : {}", error))?;
+        let peer = stream
+            .peer_addr()
+            .map(|address| address.to_string())
+            .unwrap_or_else(|_| "unknown".to_string());
+
+        Ok(Self {
+            peer,
+            io: RefCell::new(NetworkIo {
+                reader: BufReader::new(stream),
+                writer,
+            }),
+            last_error: RefCell::new(None),
+        })
+    }
+
+    /// Returns the error which occurred during the last `compute_move` call,
+    /// if any.
+    pub fn last_error(&self) -> Option<String> {
+        self.last_error.borrow().clone()
+    }
+}
+
+impl EngineInfo for NetworkPlayer {
+    fn name(&self) -> String {
+        "Network".to_string()
+    }
+
+    fn version(&self) -> String {
+        "unknown".to_string()
+    }
+
+    fn author(&self) -> String {
+        "unknown".to_string()
+    }
+
+    fn options(&self) -> String {
+        format!("peer={}", self.peer)
+    }
+}
+
+impl VirtualPlayer for NetworkPlayer {
+    fn move_count(&self) -> u32 {
+        0
+    }
+
+    fn compute_move(&self, board: &Board, me: Player) -> Option<(u8, u8)> {
+        let mut io = self.io.borrow_mut();
+        let NetworkIo { reader, writer } = &mut *io;
+        match request_move(reader, writer, board, me) {
+            Ok(position) => {
+                *self.last_error.borrow_mut() = None;
+                position
+            }
+            Err(error) => {
+                *self.last_error.borrow_mut() = Some(error);
+                None
+            }
+        }
+    }
+}
+
+impl Drop for NetworkPlayer {
+    fn drop(&mut self) {
+        let _ = send_quit(&mut self.io.get_mut().writer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::TcpListener;
+    use std::thread;
+
+    #[test]
+    fn network_player_plays_the_remote_moves() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        let server = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut writer = stream.try_clone().unwrap();
+            let mut reader = BufReader::new(stream);
+            let player = AlphaBeta::new(1);
+            serve_engine(&mut reader, &mut writer, &player)
+        });
+
+        let player = NetworkPlayer::connect(address).unwrap();
+        let board = Board::new_start();
+        let (x, y) = player.compute_move(&board, Player::Black).unwrap();
+        assert!(board.is_move_valid(Player::Black, x, y).unwrap());
+        assert!(player.last_error().is_none());
+        drop(player);
+        assert_eq!(server.join().unwrap(), Ok(()));
+    }
+
+    #[test]
+    fn connect_fails_without_peer() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        drop(listener);
+        assert!(NetworkPlayer::connect(address).is_err());
+    }
+}
@@ -0,0 +1,194 @@
+use std::collections::HashMap;
+
+use super::board::*;
+use super::virtual_player::*;
+
+/// Opening book : gives the move to play for positions reached while following
+/// well known opening lines.
+pub struct OpeningBook {
+    // known positions (board and player to move), and the move to play.
+    entries: HashMap<(Board, Player), (u8, u8)>,
+}
+
+impl OpeningBook {
+    /// Some classic opening lines, in standard notation, Black playing first.
+    const STANDARD_LINES: [&'static str; 8] = [
+        // Tiger
+        "f5d6c3d3c4f4f6f3e6e7",
+        // Buffalo
+        "f5d6c3d3c4f4c5b3c2",
+        // Heath
+        "f5d6c3d3c4f4f6b4",
+        // Rose
+        "f5d6c5f4e3f6g5e6e7",
+        // Cow
+        "f5d6c5f4e3c6d3f6e6d7",
+        // Chimney
+        "f5f6e6f4e3c5g5",
+        // Tamenori
+        "f5f6e6f4g5e7f7",
+        // Parallel
+        "f5f4e3f6d3",
+    ];
+
+    /// Creates an empty book.
+    pub fn new() -> Self {
+        Self {
+            entries: HashMap::new(),
+        }
+    }
+
+    /// Creates a book containing some classic openings.
+    pub fn standard() -> Self {
+        let mut book = Self::new();
+        for line in Self::STANDARD_LINES.iter() {
+            book.add_line(line)
+                .expect("Unexpected invalid line in the standard book.");
+        }
+        book
+    }
+
+    /// Adds a line of moves played from the start, in standard notation
+    /// (ie "f5d6c3"). When a position is already known, the move given by the
+    /// first line added is kept.
+    pub fn add_line(&mut self, line: &str) -> Result<(), String> {
+        let mut board = Board::new_start();
+        let mut player = Player::Black;
+        let mut new_entries = Vec::new();
+        for (x, y) in parse_line(line)? {
+            if !board.has_legal_move(player) {
+                player = player.opponent();
+            }
+            let board_after_move = board
+                .play(player, x, y)
+                .map_err(|error| error.to_string())?
+                .ok_or_else(|| format!("invalid move in line {} : {:?}", line, (x, y)))?;
+            new_entries.push(((board, player), (x, y)));
+            board = board_after_move;
+            player = player.opponent();
+        }
+        for (key, position) in new_entries {
+            self.entries.entry(key).or_insert(position);
+        }
+
+        Ok(())
+    }
+
+    /// Returns the book move for the given board and player, if any.
+    pub fn lookup(&self, board: &Board, player: Player) -> Option<(u8, u8)> {
+        self.entries.get(&(*board, player)).copied()
+    }
+
+    /// Returns the count of known positions.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Is the book empty ?
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// Parses a line of moves in standard notation (ie "f5d6c3").
+pub(crate) fn parse_line(line: &str) -> Result<Vec<(u8, u8)>, String> {
+    let chars: Vec<char> = line.chars().collect();
+    chars.chunks(2).map(parse_notation).collect()
+}
+
+/// Formats a line of moves in standard notation (ie "f5d6c3").
+pub(crate) fn format_line(moves: &[(u8, u8)]) -> String {
+    moves
+        .iter()
+        .map(|&(x, y)| format!("{}{}", (b'a' + x) as char, y + 1))
+        .collect()
+}
+
+fn parse_notation(notation: &[char]) -> Result<(u8, u8), String> {
+    let invalid = || format!("invalid move notation : {:?}", notation);
+    if notation.len() != 2 {
+        return Err(invalid());
+    }
+    let letter = notation[0].to_ascii_lowercase();
+    let digit = notation[1];
+    if !('a'..='h').contains(&letter) || !('1'..='8').contains(&digit) {
+        return Err(invalid());
+    }
+
+    Ok((letter as u8 - b'a', digit as u8 - b'1'))
+}
+
+impl Default for OpeningBook {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EngineInfo for OpeningBook {
+    fn name(&self) -> String {
+        "OpeningBook".to_string()
+    }
+
+    fn options(&self) -> String {
+        format!("positions={}", self.len())
+    }
+}
+
+impl VirtualPlayer for OpeningBook {
+    fn move_count(&self) -> u32 {
+        0
+    }
+
+    fn compute_move(&self, board: &Board, me: Player) -> Option<(u8, u8)> {
+        self.lookup(board, me)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn standard_book_contains_the_first_move() {
+        let book = OpeningBook::standard();
+        let position = book.lookup(&Board::new_start(), Player::Black);
+        assert_eq!(position, Some((5, 4)));
+    }
+
+    #[test]
+    fn standard_book_follows_lines() {
+        let book = OpeningBook::standard();
+        let board = Board::new_start()
+            .play(Player::Black, 5, 4)
+            .unwrap()
+            .unwrap();
+        assert_eq!(book.lookup(&board, Player::White), Some((3, 5)));
+        assert_eq!(book.lookup(&board, Player::Black), None);
+    }
+
+    #[test]
+    fn lookup_returns_none_for_unknown_positions() {
+        let book = OpeningBook::standard();
+        let board = Board::new_start()
+            .play(Player::Black, 4, 5)
+            .unwrap()
+            .unwrap();
+        assert_eq!(book.lookup(&board, Player::White), None);
+    }
+
+    #[test]
+    fn add_line_keeps_known_positions() {
+        let mut book = OpeningBook::new();
+        book.add_line("f5d6").unwrap();
+        book.add_line("f5f6").unwrap();
+        assert_eq!(book.len(), 2);
+    }
+
+    #[test]
+    fn add_line_rejects_invalid_moves() {
+        let mut book = OpeningBook::new();
+        assert!(book.add_line("a1").is_err());
+        assert!(book.add_line("f5z9").is_err());
+        assert!(book.is_empty());
+    }
+}
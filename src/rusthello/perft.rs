@@ -0,0 +1,116 @@
+use alloc::{vec, vec::Vec};
+
+use super::board::*;
+use super::moves::*;
+
+/// Counts the leaves of the game tree explored from the given position up to
+/// the given depth, to check the move generation against known counts (ie
+/// 4, 12, 56, 244, 1396, 8200 and 55092 from the start for depths 1 to 7).
+/// Passes count as moves, and finished games are leaves whatever their
+/// depth.
+pub fn perft<const N: usize>(board: &Board<N>, player: Player, depth: u8) -> u64 {
+    let mut board = *board;
+    count_leaves(&mut board, player, depth)
+}
+
+/// Like `perft`, but returns the count of leaves below each move of the
+/// player, in grid order (a single pass if the player can't move). It helps
+/// to find where two move generators diverge.
+pub fn perft_divide<const N: usize>(
+    board: &Board<N>,
+    player: Player,
+    depth: u8,
+) -> Vec<(Move, u64)> {
+    if depth == 0 {
+        return Vec::new();
+    }
+
+    let mut board = *board;
+    let moves = board.legal_moves(player);
+    if moves.is_empty() {
+        if !board.has_legal_move(player.opponent()) {
+            return Vec::new();
+        }
+        return vec![(
+            Move::Pass,
+            count_leaves(&mut board, player.opponent(), depth - 1),
+        )];
+    }
+
+    moves
+        .into_iter()
+        .map(|(x, y)| {
+            let undo = board
+                .apply_move(player, x, y)
+                .expect("Unexpected invalid move while counting leaves.");
+            let leaves = count_leaves(&mut board, player.opponent(), depth - 1);
+            board.undo_move(undo);
+            (Move::Place { x, y }, leaves)
+        })
+        .collect()
+}
+
+/// Implements `perft`, playing the moves in place.
+fn count_leaves<const N: usize>(board: &mut Board<N>, player: Player, depth: u8) -> u64 {
+    if depth == 0 {
+        return 1;
+    }
+
+    let moves = board.legal_moves(player);
+    if moves.is_empty() {
+        return if board.has_legal_move(player.opponent()) {
+            // the player passes.
+            count_leaves(board, player.opponent(), depth - 1)
+        } else {
+            // the game is over.
+            1
+        };
+    }
+
+    let mut leaves = 0;
+    for (x, y) in moves {
+        let undo = board
+            .apply_move(player, x, y)
+            .expect("Unexpected invalid move while counting leaves.");
+        leaves += count_leaves(board, player.opponent(), depth - 1);
+        board.undo_move(undo);
+    }
+
+    leaves
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn perft_matches_known_counts() {
+        let board = Board::new_start();
+        let counts: Vec<u64> = (0..=6)
+            .map(|depth| perft(&board, Player::Black, depth))
+            .collect();
+        assert_eq!(counts, vec![1, 4, 12, 56, 244, 1396, 8200]);
+    }
+
+    #[test]
+    fn perft_divide_counts_each_move() {
+        let board = Board::new_start();
+        let divide = perft_divide(&board, Player::Black, 3);
+        assert_eq!(divide.len(), 4);
+        assert!(divide.iter().all(|&(_, leaves)| leaves == 14));
+        assert_eq!(divide[0].0, Move::Place { x: 3, y: 2 });
+    }
+
+    #[test]
+    fn perft_counts_passes_and_finished_games() {
+        // White can't move, Black plays at (2, 0) and the game is over.
+        let mut board = Board::new();
+        board.set_piece(0, 0, Some(Player::Black)).unwrap();
+        board.set_piece(1, 0, Some(Player::White)).unwrap();
+        assert_eq!(
+            perft_divide(&board, Player::White, 3),
+            vec![(Move::Pass, 1)]
+        );
+        assert_eq!(perft(&board, Player::Black, 5), 1);
+    }
+}
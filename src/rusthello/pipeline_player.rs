@@ -0,0 +1,188 @@
+use std::cell::Cell;
+
+use super::board::*;
+use super::endgame_solver::*;
+use super::opening_book::*;
+use super::time_control::*;
+use super::virtual_player::*;
+
+/// The stages of a PipelinePlayer.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum PipelineStage {
+    Book,
+    Search,
+    Solver,
+}
+
+/// Composite virtual player, using the classic architecture of strong
+/// Othello programs :
+/// * the opening book while the position is known,
+/// * the given search algorithm during the midgame,
+/// * the exact endgame solver when few empty cells remain.
+pub struct PipelinePlayer {
+    book: OpeningBook,
+    search: Box<dyn VirtualPlayer>,
+    solver: EndgameSolver,
+    solver_empty_cells: u8,
+    last_stage: Cell<Option<PipelineStage>>,
+}
+
+impl PipelinePlayer {
+    /// Default count of empty cells from which the solver is used.
+    pub const DEFAULT_SOLVER_EMPTY_CELLS: u8 = 10;
+
+    /// Creates a new PipelinePlayer using the given search algorithm for the
+    /// midgame, the standard opening book, and the endgame solver.
+    pub fn new(search: Box<dyn VirtualPlayer>) -> Self {
+        Self {
+            book: OpeningBook::standard(),
+            search,
+            solver: EndgameSolver::new(),
+            solver_empty_cells: Self::DEFAULT_SOLVER_EMPTY_CELLS,
+            last_stage: Cell::new(None),
+        }
+    }
+
+    /// Replaces the opening book.
+    pub fn with_book(mut self, book: OpeningBook) -> Self {
+        self.book = book;
+        self
+    }
+
+    /// Replaces the endgame solver.
+    pub fn with_solver(mut self, solver: EndgameSolver) -> Self {
+        self.solver = solver;
+        self
+    }
+
+    /// Changes the count of empty cells from which the solver is used.
+    pub fn with_solver_empty_cells(mut self, empty_cells: u8) -> Self {
+        self.solver_empty_cells = empty_cells;
+        self
+    }
+
+    /// Returns the stage used by the last `compute_move` call, if any.
+    pub fn last_stage(&self) -> Option<PipelineStage> {
+        self.last_stage.get()
+    }
+
+    fn select_stage(&self, board: &Board, me: Player) -> PipelineStage {
+        if board.empties() <= self.solver_empty_cells {
+            PipelineStage::Solver
+        } else if self.book.lookup(board, me).is_some() {
+            PipelineStage::Book
+        } else {
+            PipelineStage::Search
+        }
+    }
+}
+
+impl EngineInfo for PipelinePlayer {
+    fn name(&self) -> String {
+        "Pipeline".to_string()
+    }
+
+    fn options(&self) -> String {
+        format!(
+            "book={}, search={}, solver_empty_cells={}",
+            self.book.len(),
+            self.search.description(),
+            self.solver_empty_cells
+        )
+    }
+}
+
+impl VirtualPlayer for PipelinePlayer {
+    fn move_count(&self) -> u32 {
+        self.search.move_count() + self.solver.move_count()
+    }
+
+    fn wants_to_resign(&self) -> bool {
+        match self.last_stage.get() {
+            Some(PipelineStage::Search) => self.search.wants_to_resign(),
+            Some(PipelineStage::Solver) => self.solver.wants_to_resign(),
+            _ => false,
+        }
+    }
+
+    fn compute_move(&self, board: &Board, me: Player) -> Option<(u8, u8)> {
+        self.compute_move_full(board, me)
+            .map(|analysis| analysis.position)
+    }
+
+    fn compute_move_full(&self, board: &Board, me: Player) -> Option<MoveAnalysis> {
+        let stage = self.select_stage(board, me);
+        self.last_stage.set(Some(stage));
+        match stage {
+            PipelineStage::Book => self.book.compute_move_full(board, me),
+            PipelineStage::Search => self.search.compute_move_full(board, me),
+            PipelineStage::Solver => self.solver.compute_move_full(board, me),
+        }
+    }
+
+    /// Only the search uses the clock, the book and the solver being fast.
+    fn compute_move_timed(&self, board: &Board, me: Player, clock: &Clock) -> Option<(u8, u8)> {
+        let stage = self.select_stage(board, me);
+        self.last_stage.set(Some(stage));
+        match stage {
+            PipelineStage::Book => self.book.compute_move(board, me),
+            PipelineStage::Search => self.search.compute_move_timed(board, me, clock),
+            PipelineStage::Solver => self.solver.compute_move(board, me),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pipeline() -> PipelinePlayer {
+        PipelinePlayer::new(Box::new(AlphaBeta::new(2)))
+    }
+
+    #[test]
+    fn engine_info_describes_the_stages() {
+        let player = pipeline().with_solver_empty_cells(8);
+        let options = player.options();
+        assert!(options.contains("search=AlphaBeta (depth=2)"));
+        assert!(options.ends_with("solver_empty_cells=8"));
+    }
+
+    #[test]
+    fn pipeline_uses_the_book_first() {
+        let player = pipeline();
+        let position = player.compute_move(&Board::new_start(), Player::Black);
+        assert_eq!(position, Some((5, 4)));
+        assert_eq!(player.last_stage(), Some(PipelineStage::Book));
+        assert_eq!(player.move_count(), 0);
+    }
+
+    #[test]
+    fn pipeline_uses_the_search_out_of_book() {
+        let player = pipeline();
+        let board = Board::new_start()
+            .play(Player::Black, 4, 5)
+            .unwrap()
+            .unwrap();
+        assert!(player.compute_move(&board, Player::White).is_some());
+        assert_eq!(player.last_stage(), Some(PipelineStage::Search));
+        assert!(player.move_count() > 0);
+        let analysis = player.compute_move_full(&board, Player::White).unwrap();
+        assert_eq!(analysis.variation.len(), 2);
+    }
+
+    #[test]
+    fn pipeline_uses_the_solver_near_the_end() {
+        let mut board = Board::new();
+        for (x, y) in GridIterator::new() {
+            if y < 6 {
+                board.set_piece(x, y, Some(Player::Black)).unwrap();
+            } else if y == 6 {
+                board.set_piece(x, y, Some(Player::White)).unwrap();
+            }
+        }
+        let player = pipeline();
+        assert!(player.compute_move(&board, Player::Black).is_some());
+        assert_eq!(player.last_stage(), Some(PipelineStage::Solver));
+    }
+}
@@ -0,0 +1,223 @@
+use std::time::Duration;
+
+use super::beginner_player::*;
+use super::endgame_solver::*;
+use super::external_player::*;
+use super::greedy_player::*;
+use super::network_player::*;
+use super::pipeline_player::*;
+use super::random_player::*;
+use super::timed_player::*;
+use super::virtual_player::*;
+
+/// Function building a VirtualPlayer from the (optional) parameter of a
+/// specification.
+pub type PlayerBuilder = dyn Fn(Option<&str>) -> Result<Box<dyn VirtualPlayer>, String>;
+
+/// Registry building virtual players from specification strings, made of a
+/// kind and an optional parameter separated by a colon (ie `alphabeta:8`, or
+/// `alphabeta:2000ms` for a time per move).
+/// It allows the CLI or a tournament to select players at runtime.
+pub struct PlayerFactory {
+    builders: Vec<(String, Box<PlayerBuilder>)>,
+}
+
+impl PlayerFactory {
+    /// Creates an empty factory.
+    pub fn new() -> Self {
+        Self {
+            builders: Vec::new(),
+        }
+    }
+
+    /// Creates a factory knowing all bundled players :
+    /// * `random`, `greedy`, `beginner`, `solver`,
+    /// * `minimax:<depth>`, `alphabeta:<depth>`,
+    /// * `pipeline:<depth>` (book, alphabeta search and solver),
+    /// * `alphabeta:<milliseconds>ms`, `pipeline:<milliseconds>ms`, searching
+    ///   as deep as the time per move allows (see `TimedPlayer`),
+    /// * `external:<program>`, `network:<host:port>`.
+    pub fn standard() -> Self {
+        let mut factory = Self::new();
+        factory.register("random", |parameter| {
+            no_parameter("random", parameter)?;
+            Ok(Box::new(RandomPlayer::new()))
+        });
+        factory.register("greedy", |parameter| {
+            no_parameter("greedy", parameter)?;
+            Ok(Box::new(GreedyPlayer::new()))
+        });
+        factory.register("beginner", |parameter| {
+            no_parameter("beginner", parameter)?;
+            Ok(Box::new(BeginnerPlayer::new()))
+        });
+        factory.register("solver", |parameter| {
+            no_parameter("solver", parameter)?;
+            Ok(Box::new(EndgameSolver::new()))
+        });
+        factory.register("minimax", |parameter| {
+            Ok(Box::new(Minimax::new(depth_parameter(parameter)?)))
+        });
+        factory.register("alphabeta", |parameter| match time_parameter(parameter)? {
+            Some(budget) => Ok(Box::new(TimedPlayer::new(
+                Box::new(AlphaBeta::new(TIMED_SEARCH_DEPTH)),
+                budget,
+            ))),
+            None => Ok(Box::new(AlphaBeta::new(depth_parameter(parameter)?))),
+        });
+        factory.register("pipeline", |parameter| match time_parameter(parameter)? {
+            Some(budget) => {
+                let search = Box::new(AlphaBeta::new(TIMED_SEARCH_DEPTH));
+                Ok(Box::new(TimedPlayer::new(
+                    Box::new(PipelinePlayer::new(search)),
+                    budget,
+                )))
+            }
+            None => {
+                let search = Box::new(AlphaBeta::new(depth_parameter(parameter)?));
+                Ok(Box::new(PipelinePlayer::new(search)))
+            }
+        });
+        factory.register("external", |parameter| {
+            let program = parameter.ok_or_else(|| "missing program for external".to_string())?;
+            Ok(Box::new(ExternalEnginePlayer::spawn(program, &[])?))
+        });
+        factory.register("network", |parameter| {
+            let address = parameter.ok_or_else(|| "missing address for network".to_string())?;
+            Ok(Box::new(NetworkPlayer::connect(address)?))
+        });
+        factory
+    }
+
+    /// Registers a new kind of player, replacing any previous one having the
+    /// same name.
+    pub fn register<F>(&mut self, name: &str, builder: F)
+    where
+        F: Fn(Option<&str>) -> Result<Box<dyn VirtualPlayer>, String> + 'static,
+    {
+        self.builders.retain(|(known_name, _)| known_name != name);
+        self.builders.push((name.to_string(), Box::new(builder)));
+    }
+
+    /// Returns the names of the known kinds of players.
+    pub fn names(&self) -> Vec<&str> {
+        self.builders
+            .iter()
+            .map(|(name, _)| name.as_str())
+            .collect()
+    }
+
+    /// Builds a player from the given specification.
+    pub fn build(&self, spec: &str) -> Result<Box<dyn VirtualPlayer>, String> {
+        let spec = spec.trim();
+        let (name, parameter) = match spec.find(':') {
+            Some(index) => (&spec[..index], Some(&spec[index + 1..])),
+            None => (spec, None),
+        };
+        let name = name.to_ascii_lowercase();
+        let (_, builder) = self
+            .builders
+            .iter()
+            .find(|(known_name, _)| *known_name == name)
+            .ok_or_else(|| format!("unknown player kind : {}", name))?;
+
+        builder(parameter)
+    }
+}
+
+impl Default for PlayerFactory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Maximum depth of the searches limited by a time per move, deep enough to
+/// never be reached.
+const TIMED_SEARCH_DEPTH: u8 = 60;
+
+fn no_parameter(name: &str, parameter: Option<&str>) -> Result<(), String> {
+    match parameter {
+        None => Ok(()),
+        Some(parameter) => Err(format!("unexpected parameter for {} : {}", name, parameter)),
+    }
+}
+
+/// Reads a time per move in milliseconds (ie `2000ms`), None if the
+/// parameter isn't a time.
+fn time_parameter(parameter: Option<&str>) -> Result<Option<Duration>, String> {
+    let milliseconds = match parameter.and_then(|parameter| parameter.strip_suffix("ms")) {
+        Some(milliseconds) => milliseconds,
+        None => return Ok(None),
+    };
+    match milliseconds.parse::<u64>() {
+        Ok(milliseconds) if milliseconds > 0 => Ok(Some(Duration::from_millis(milliseconds))),
+        _ => Err(format!("invalid time : {}ms", milliseconds)),
+    }
+}
+
+fn depth_parameter(parameter: Option<&str>) -> Result<u8, String> {
+    let parameter = parameter.ok_or_else(|| "missing depth".to_string())?;
+    match parameter.parse::<u8>() {
+        Ok(depth) if depth > 0 => Ok(depth),
+        _ => Err(format!("invalid depth : {}", parameter)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::board::*;
+    use super::*;
+
+    #[test]
+    fn build_creates_players_from_specs() {
+        let factory = PlayerFactory::standard();
+        let board = Board::new_start();
+        for spec in [
+            "random",
+            "greedy",
+            "beginner",
+            "minimax:2",
+            "AlphaBeta:3",
+            "pipeline:2",
+            "alphabeta:20ms",
+            "pipeline:20ms",
+        ]
+        .iter()
+        {
+            let player = factory.build(spec).unwrap();
+            assert!(player.compute_move(&board, Player::Black).is_some());
+        }
+    }
+
+    #[test]
+    fn build_rejects_invalid_specs() {
+        let factory = PlayerFactory::standard();
+        for spec in [
+            "minimax",
+            "alphabeta:0",
+            "alphabeta:deep",
+            "random:3",
+            "alphabeta:0ms",
+            "alphabeta:fastms",
+        ]
+        .iter()
+        {
+            assert!(factory.build(spec).is_err());
+        }
+    }
+
+    #[test]
+    fn build_creates_players_searching_for_a_time() {
+        let factory = PlayerFactory::standard();
+        let player = factory.build("alphabeta:2000ms").unwrap();
+        assert_eq!(player.description(), "AlphaBeta (depth=60, time=2000ms)");
+    }
+
+    #[test]
+    fn register_adds_new_kinds() {
+        let mut factory = PlayerFactory::new();
+        factory.register("deep", |_| Ok(Box::new(AlphaBeta::new(8))));
+        assert_eq!(factory.names(), vec!["deep"]);
+        assert!(factory.build("deep").is_ok());
+    }
+}
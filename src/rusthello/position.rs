@@ -0,0 +1,132 @@
+use alloc::{
+    format,
+    string::{String, ToString},
+};
+use core::{fmt, str::FromStr};
+
+use super::board::*;
+
+/// A board and the player to move, with a single line text format similar
+/// to the one used by Edax or Zebra : the 64 cells from A1 to H8 row by row
+/// (`X` for Black, `O` for White, `-` for empty cells), a space, and the
+/// player to move (`X` or `O`). The position of a new game is :
+///
+/// `---------------------------OX------XO--------------------------- X`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Position {
+    pub board: Board,
+    pub player: Player,
+}
+
+impl Position {
+    /// Creates a new Position.
+    pub fn new(board: Board, player: Player) -> Self {
+        Self { board, player }
+    }
+
+    /// Creates the position of a new game.
+    pub fn new_start() -> Self {
+        Self::new(Board::new_start(), Player::Black)
+    }
+}
+
+impl fmt::Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (_, _, piece) in self.board.iter() {
+            let cell = match piece {
+                None => '-',
+                Some(player) => player_to_char(player),
+            };
+            write!(f, "{}", cell)?;
+        }
+        write!(f, " {}", player_to_char(self.player))
+    }
+}
+
+impl FromStr for Position {
+    type Err = String;
+
+    /// Parses a position. Lowercase letters are accepted, and `.` can be used
+    /// for empty cells.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("invalid position : {}", s);
+        let mut parts = s.split_whitespace();
+        let (cells, player) = match (parts.next(), parts.next(), parts.next()) {
+            (Some(cells), Some(player), None) => (cells, player),
+            _ => return Err(invalid()),
+        };
+        if cells.chars().count() != 64 {
+            return Err(invalid());
+        }
+
+        let mut board = Board::new();
+        for ((x, y), cell) in GridIterator::new().zip(cells.chars()) {
+            let piece = match cell {
+                '-' | '.' => None,
+                _ => Some(char_to_player(cell).ok_or_else(invalid)?),
+            };
+            board
+                .set_piece(x, y, piece)
+                .map_err(|error| error.to_string())?;
+        }
+        let mut player_chars = player.chars();
+        let player = match (player_chars.next(), player_chars.next()) {
+            (Some(player), None) => char_to_player(player).ok_or_else(invalid)?,
+            _ => return Err(invalid()),
+        };
+
+        Ok(Self::new(board, player))
+    }
+}
+
+fn player_to_char(player: Player) -> char {
+    match player {
+        Player::Black => 'X',
+        Player::White => 'O',
+    }
+}
+
+fn char_to_player(c: char) -> Option<Player> {
+    match c.to_ascii_uppercase() {
+        'X' => Some(Player::Black),
+        'O' => Some(Player::White),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const START: &str = "---------------------------OX------XO--------------------------- X";
+
+    #[test]
+    fn display_emits_the_text_format() {
+        assert_eq!(Position::new_start().to_string(), START);
+        let board = Board::new_start()
+            .play(Player::Black, 5, 4)
+            .unwrap()
+            .unwrap();
+        let position = Position::new(board, Player::White).to_string();
+        assert_eq!(&position[32..40], "---XXX--");
+        assert!(position.ends_with(" O"));
+    }
+
+    #[test]
+    fn from_str_parses_the_text_format() {
+        assert_eq!(START.parse::<Position>(), Ok(Position::new_start()));
+        let lowercase = START.to_lowercase().replace('-', ".");
+        assert_eq!(lowercase.parse::<Position>(), Ok(Position::new_start()));
+    }
+
+    #[test]
+    fn from_str_rejects_invalid_positions() {
+        assert!("".parse::<Position>().is_err());
+        assert!(START[..65].parse::<Position>().is_err());
+        assert!(START.replace(" X", " Z").parse::<Position>().is_err());
+        assert!(START.replace(" X", " XO").parse::<Position>().is_err());
+        assert!(START.replacen('-', "A", 1).parse::<Position>().is_err());
+        assert!(format!("-{}", START).parse::<Position>().is_err());
+    }
+}
@@ -0,0 +1,75 @@
+#[cfg(feature = "std")]
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Source of random numbers used by virtual players.
+/// Implementing it allows to inject deterministic sequences in tests.
+pub trait RandomGenerator {
+    /// Returns the next random number.
+    fn next_u64(&mut self) -> u64;
+
+    /// Returns a random number in the range 0..bound (bound must not be 0).
+    fn next_below(&mut self, bound: usize) -> usize {
+        (self.next_u64() % bound as u64) as usize
+    }
+}
+
+/// A small xorshift64* generator. It's fast and good enough for games,
+/// but must not be used for cryptographic purposes.
+#[derive(Debug, Clone)]
+pub struct XorShiftRng {
+    state: u64,
+}
+
+impl XorShiftRng {
+    /// Creates a generator from the given seed, the same seed always
+    /// producing the same sequence.
+    pub fn new(seed: u64) -> Self {
+        // the state must not be zero.
+        Self {
+            state: seed ^ 0x9E37_79B9_7F4A_7C15,
+        }
+    }
+
+    /// Creates a generator seeded from the system clock.
+    #[cfg(feature = "std")]
+    pub fn from_time() -> Self {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_nanos() as u64)
+            .unwrap_or(0);
+        Self::new(nanos)
+    }
+}
+
+impl RandomGenerator for XorShiftRng {
+    fn next_u64(&mut self) -> u64 {
+        let mut x = self.state;
+        x ^= x >> 12;
+        x ^= x << 25;
+        x ^= x >> 27;
+        self.state = x;
+        x.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_seed_produces_same_sequence() {
+        let mut rng_a = XorShiftRng::new(42);
+        let mut rng_b = XorShiftRng::new(42);
+        for _ in 0..10 {
+            assert_eq!(rng_a.next_u64(), rng_b.next_u64());
+        }
+    }
+
+    #[test]
+    fn next_below_stays_in_range() {
+        let mut rng = XorShiftRng::new(0);
+        for _ in 0..100 {
+            assert!(rng.next_below(7) < 7);
+        }
+    }
+}
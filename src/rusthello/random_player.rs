@@ -0,0 +1,101 @@
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+};
+use core::cell::RefCell;
+
+use super::board::*;
+use super::random::*;
+use super::virtual_player::*;
+
+/// A virtual player choosing uniformly among valid moves.
+/// It's a baseline opponent for tests and tournaments.
+pub struct RandomPlayer {
+    rng: RefCell<Box<dyn RandomGenerator>>,
+}
+
+impl RandomPlayer {
+    /// Creates a new RandomPlayer seeded from the system clock.
+    #[cfg(feature = "std")]
+    pub fn new() -> Self {
+        Self::with_rng(Box::new(XorShiftRng::from_time()))
+    }
+
+    /// Creates a new RandomPlayer using the given random generator.
+    pub fn with_rng(rng: Box<dyn RandomGenerator>) -> Self {
+        Self {
+            rng: RefCell::new(rng),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Default for RandomPlayer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EngineInfo for RandomPlayer {
+    fn name(&self) -> String {
+        "Random".to_string()
+    }
+}
+
+impl VirtualPlayer for RandomPlayer {
+    fn move_count(&self) -> u32 {
+        0
+    }
+
+    fn compute_move(&self, board: &Board, me: Player) -> Option<(u8, u8)> {
+        let valid_moves = board.legal_moves(me);
+        if valid_moves.is_empty() {
+            return None;
+        }
+
+        let index = self.rng.borrow_mut().next_below(valid_moves.len());
+        Some(valid_moves[index])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Generator always returning the same number.
+    struct ConstantRng(u64);
+
+    impl RandomGenerator for ConstantRng {
+        fn next_u64(&mut self) -> u64 {
+            self.0
+        }
+    }
+
+    #[test]
+    fn random_player_returns_valid_moves() {
+        let player = RandomPlayer::with_rng(Box::new(XorShiftRng::new(1)));
+        let board = Board::new_start();
+        for _ in 0..20 {
+            let (x, y) = player.compute_move(&board, Player::Black).unwrap();
+            assert!(board.is_move_valid(Player::Black, x, y).unwrap());
+        }
+    }
+
+    #[test]
+    fn random_player_uses_the_given_generator() {
+        let board = Board::new_start();
+        // valid moves for Black, in grid order : (3, 2), (2, 3), (5, 4), (4, 5)
+        let player = RandomPlayer::with_rng(Box::new(ConstantRng(2)));
+        assert_eq!(player.compute_move(&board, Player::Black), Some((5, 4)));
+        let player = RandomPlayer::with_rng(Box::new(ConstantRng(7)));
+        assert_eq!(player.compute_move(&board, Player::Black), Some((4, 5)));
+    }
+
+    #[test]
+    fn random_player_returns_none_if_it_cant_move() {
+        let player = RandomPlayer::with_rng(Box::new(XorShiftRng::new(0)));
+        let mut board = Board::new();
+        board.set_piece(0, 0, Some(Player::Black)).unwrap();
+        assert_eq!(player.compute_move(&board, Player::White), None);
+    }
+}
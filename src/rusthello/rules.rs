@@ -0,0 +1,234 @@
+use super::board::*;
+use super::game::*;
+use super::game_result::*;
+
+/// Rules of a variant of Othello, `Game` being generic over them. The moves
+/// always flip the pieces as in Othello, the rules giving the starting board
+/// (with its handicap or placement phase), the capturing moves allowed, how
+/// the blocked players pass and how a game played to the end is scored. The
+/// defaults are the standard rules.
+/// The variants changing the start or the passes of a game wrap other rules
+/// (ie `Reversi<AntiOthello>`), to be combined with any of them.
+pub trait Rules<const N: usize = 8>: Clone {
+    /// Returns the board at the start of a game.
+    fn start_board(&self) -> Board<N> {
+        Board::start()
+    }
+
+    /// Checks if the game starts with a placement phase : the central squares
+    /// of the starting board are empty, and the players alternately place a
+    /// piece on one of them, without capture, before the usual moves.
+    fn placement_phase(&self) -> bool {
+        false
+    }
+
+    /// Returns the handicap given at the start of a game, if any.
+    fn handicap(&self) -> Option<Handicap> {
+        None
+    }
+
+    /// Checks if the given player may play at the given square, the move
+    /// being valid by the rules of Othello (it flips pieces).
+    fn allows_move(&self, _board: &Board<N>, _player: Player, _x: u8, _y: u8) -> bool {
+        true
+    }
+
+    /// Checks if the blocked players pass explicitly, with `Game::pass`,
+    /// instead of passing automatically.
+    fn explicit_passes(&self) -> bool {
+        false
+    }
+
+    /// Returns the result of a game played to the end on the given board.
+    fn result(&self, board: &Board<N>) -> GameResult {
+        GameResult::from_board(board)
+    }
+
+    /// Checks if the game is played as standard Othello once started : every
+    /// move flipping pieces is allowed, and the player with the most pieces
+    /// wins. The engines and the endgame solver only play (and judge) such
+    /// games.
+    fn standard_play(&self) -> bool {
+        false
+    }
+}
+
+/// The standard rules of Othello.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Othello;
+
+impl<const N: usize> Rules<N> for Othello {
+    fn standard_play(&self) -> bool {
+        true
+    }
+}
+
+/// Anti-Othello (or Reversed Othello) : the moves are the usual ones, but the
+/// player with the fewest pieces wins.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AntiOthello;
+
+impl<const N: usize> Rules<N> for AntiOthello {
+    fn result(&self, board: &Board<N>) -> GameResult {
+        GameResult::from_board_reversed(board)
+    }
+}
+
+/// Reversi : the classic start of the given rules, with an empty center and
+/// a placement phase (see `Rules::placement_phase`).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Reversi<R = Othello>(pub R);
+
+impl<const N: usize, R: Rules<N>> Rules<N> for Reversi<R> {
+    fn start_board(&self) -> Board<N> {
+        let mut board = self.0.start_board();
+        for (x, y) in center_squares(board.size()) {
+            board
+                .set_piece(x, y, None)
+                .expect("Unexpected central square out of the board.");
+        }
+        board
+    }
+
+    fn placement_phase(&self) -> bool {
+        true
+    }
+
+    fn handicap(&self) -> Option<Handicap> {
+        self.0.handicap()
+    }
+
+    fn allows_move(&self, board: &Board<N>, player: Player, x: u8, y: u8) -> bool {
+        self.0.allows_move(board, player, x, y)
+    }
+
+    fn explicit_passes(&self) -> bool {
+        self.0.explicit_passes()
+    }
+
+    fn result(&self, board: &Board<N>) -> GameResult {
+        self.0.result(board)
+    }
+
+    fn standard_play(&self) -> bool {
+        self.0.standard_play()
+    }
+}
+
+/// The given rules, the blocked players passing explicitly with `Game::pass`
+/// instead of passing automatically.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExplicitPasses<R = Othello>(pub R);
+
+impl<const N: usize, R: Rules<N>> Rules<N> for ExplicitPasses<R> {
+    fn start_board(&self) -> Board<N> {
+        self.0.start_board()
+    }
+
+    fn placement_phase(&self) -> bool {
+        self.0.placement_phase()
+    }
+
+    fn handicap(&self) -> Option<Handicap> {
+        self.0.handicap()
+    }
+
+    fn allows_move(&self, board: &Board<N>, player: Player, x: u8, y: u8) -> bool {
+        self.0.allows_move(board, player, x, y)
+    }
+
+    fn explicit_passes(&self) -> bool {
+        true
+    }
+
+    fn result(&self, board: &Board<N>) -> GameResult {
+        self.0.result(board)
+    }
+
+    fn standard_play(&self) -> bool {
+        self.0.standard_play()
+    }
+}
+
+/// The given rules, with a handicap for the weaker player.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Handicapped<R = Othello> {
+    rules: R,
+    handicap: Handicap,
+}
+
+impl<R> Handicapped<R> {
+    /// Gives a handicap to the weaker player, who starts with pieces on the
+    /// given count of corners (see `Handicap`). A handicap gives 1 to 4
+    /// corners, other counts being rejected.
+    pub fn new(rules: R, player: Player, corners: u8) -> Result<Self, GameError> {
+        if !(1..=4).contains(&corners) {
+            return Err(GameError::InvalidHandicap { corners });
+        }
+        Ok(Self {
+            rules,
+            handicap: Handicap { player, corners },
+        })
+    }
+}
+
+impl<const N: usize, R: Rules<N>> Rules<N> for Handicapped<R> {
+    fn start_board(&self) -> Board<N> {
+        let mut board = self.rules.start_board();
+        for (x, y) in self.handicap.squares(board.size()) {
+            board
+                .set_piece(x, y, Some(self.handicap.player))
+                .expect("Unexpected corner out of the board.");
+        }
+        board
+    }
+
+    fn placement_phase(&self) -> bool {
+        self.rules.placement_phase()
+    }
+
+    fn handicap(&self) -> Option<Handicap> {
+        Some(self.handicap)
+    }
+
+    fn allows_move(&self, board: &Board<N>, player: Player, x: u8, y: u8) -> bool {
+        self.rules.allows_move(board, player, x, y)
+    }
+
+    fn explicit_passes(&self) -> bool {
+        self.rules.explicit_passes()
+    }
+
+    fn result(&self, board: &Board<N>) -> GameResult {
+        self.rules.result(board)
+    }
+
+    fn standard_play(&self) -> bool {
+        self.rules.standard_play()
+    }
+}
+
+/// A handicap : corners occupied by the pieces of the weaker player before
+/// the start of the game. The pieces are counted as any other piece.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Handicap {
+    pub player: Player,
+    pub corners: u8,
+}
+
+impl Handicap {
+    /// Returns the corners occupied by the handicap on a board of the given
+    /// size, in order : A1, the opposite corner, then the two others (H8,
+    /// H1 and A8 on a 8x8 board).
+    pub fn squares(&self, size: u8) -> impl Iterator<Item = (u8, u8)> {
+        let last = size - 1;
+        let corners = [(0, 0), (last, last), (last, 0), (0, last)];
+        IntoIterator::into_iter(corners).take(self.corners as usize)
+    }
+}
@@ -0,0 +1,239 @@
+use super::board::*;
+use super::opening_book::parse_line;
+use super::tournament::*;
+use super::virtual_player::*;
+
+/// Parameters of a sequential probability ratio test, comparing the
+/// hypothesis H0 "the first player is elo0 stronger than the second" to H1
+/// "the first player is elo1 stronger than the second".
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Sprt {
+    pub elo0: f64,
+    pub elo1: f64,
+    pub alpha: f64,
+    pub beta: f64,
+}
+
+/// Decision of a sequential probability ratio test.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SprtDecision {
+    /// More games are needed.
+    Continue,
+    /// The first player is not stronger than elo0.
+    AcceptH0,
+    /// The first player is at least elo1 stronger.
+    AcceptH1,
+}
+
+impl Sprt {
+    /// Creates a test with the usual 5% error rates.
+    pub fn new(elo0: f64, elo1: f64) -> Self {
+        Self {
+            elo0,
+            elo1,
+            alpha: 0.05,
+            beta: 0.05,
+        }
+    }
+
+    /// Changes the false positive (alpha) and false negative (beta) rates.
+    pub fn with_error_rates(mut self, alpha: f64, beta: f64) -> Self {
+        self.alpha = alpha;
+        self.beta = beta;
+        self
+    }
+
+    /// Returns the lower and upper bounds of the log-likelihood ratio.
+    pub fn bounds(&self) -> (f64, f64) {
+        (
+            (self.beta / (1.0 - self.alpha)).ln(),
+            ((1.0 - self.beta) / self.alpha).ln(),
+        )
+    }
+
+    /// Returns the log-likelihood ratio of the given results (from the first
+    /// player point of view), using the normal approximation of the score.
+    /// Half a game of each kind of result is added as a prior, keeping a
+    /// non-zero variance when all games end the same way.
+    pub fn llr(&self, wins: u32, draws: u32, losses: u32) -> f64 {
+        const PRIOR: f64 = 0.5;
+        let (wins, draws, losses) = (
+            wins as f64 + PRIOR,
+            draws as f64 + PRIOR,
+            losses as f64 + PRIOR,
+        );
+        let games = wins + draws + losses;
+        let score = (wins + draws / 2.0) / games;
+        let variance =
+            (wins * (1.0 - score).powi(2) + draws * (0.5 - score).powi(2) + losses * score.powi(2))
+                / games;
+        let score0 = expected_score(self.elo0);
+        let score1 = expected_score(self.elo1);
+
+        games * (score1 - score0) * (2.0 * score - score0 - score1) / (2.0 * variance)
+    }
+
+    /// Returns the decision for the given results.
+    pub fn decision(&self, wins: u32, draws: u32, losses: u32) -> SprtDecision {
+        let llr = self.llr(wins, draws, losses);
+        let (lower, upper) = self.bounds();
+        if llr >= upper {
+            SprtDecision::AcceptH1
+        } else if llr <= lower {
+            SprtDecision::AcceptH0
+        } else {
+            SprtDecision::Continue
+        }
+    }
+}
+
+fn expected_score(elo: f64) -> f64 {
+    1.0 / (1.0 + 10f64.powf(-elo / 400.0))
+}
+
+/// Results of a SPRT match, from the first player point of view.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SprtResult {
+    pub wins: u32,
+    pub draws: u32,
+    pub losses: u32,
+    pub llr: f64,
+    pub decision: SprtDecision,
+}
+
+/// A match between two virtual players, stopping as soon as the sequential
+/// probability ratio test reaches a decision (or after a maximum count of
+/// games). Colors alternate, and each opening (if any) is played twice,
+/// once with each color.
+pub struct SprtMatch {
+    first: Box<dyn VirtualPlayer>,
+    second: Box<dyn VirtualPlayer>,
+    sprt: Sprt,
+    max_games: u32,
+    openings: Vec<Vec<(u8, u8)>>,
+}
+
+impl SprtMatch {
+    /// Creates a new match.
+    pub fn new(
+        first: Box<dyn VirtualPlayer>,
+        second: Box<dyn VirtualPlayer>,
+        sprt: Sprt,
+        max_games: u32,
+    ) -> Self {
+        Self {
+            first,
+            second,
+            sprt,
+            max_games,
+            openings: Vec::new(),
+        }
+    }
+
+    /// Adds an opening, in standard notation (ie "f5d6c3").
+    pub fn add_opening(&mut self, line: &str) -> Result<(), String> {
+        let moves = parse_line(line)?;
+        opening_game(&moves)?;
+        self.openings.push(moves);
+        Ok(())
+    }
+
+    /// Plays the match.
+    pub fn run(&self) -> SprtResult {
+        let mut result = SprtResult {
+            wins: 0,
+            draws: 0,
+            losses: 0,
+            llr: 0.0,
+            decision: SprtDecision::Continue,
+        };
+
+        for index in 0..self.max_games {
+            let game = if self.openings.is_empty() {
+                opening_game(&[])
+            } else {
+                let opening = (index as usize / 2) % self.openings.len();
+                opening_game(&self.openings[opening])
+            }
+            .expect("Unexpected invalid opening.");
+            let first_color = if index % 2 == 0 {
+                Player::Black
+            } else {
+                Player::White
+            };
+            let game = match first_color {
+                Player::Black => play_game_from(game, self.first.as_ref(), self.second.as_ref()),
+                Player::White => play_game_from(game, self.second.as_ref(), self.first.as_ref()),
+            };
+            match game.winner() {
+                None => result.draws += 1,
+                Some(winner) if winner == first_color => result.wins += 1,
+                Some(_) => result.losses += 1,
+            }
+
+            result.llr = self.sprt.llr(result.wins, result.draws, result.losses);
+            result.decision = self.sprt.decision(result.wins, result.draws, result.losses);
+            if result.decision != SprtDecision::Continue {
+                break;
+            }
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::random::*;
+    use super::super::random_player::*;
+    use super::*;
+
+    #[test]
+    fn bounds_use_error_rates() {
+        let (lower, upper) = Sprt::new(0.0, 10.0).bounds();
+        assert!((lower + 2.944).abs() < 1e-3);
+        assert!((upper - 2.944).abs() < 1e-3);
+    }
+
+    #[test]
+    fn decision_accepts_h1_for_strong_results() {
+        let sprt = Sprt::new(0.0, 50.0);
+        assert_eq!(sprt.decision(60, 20, 20), SprtDecision::AcceptH1);
+        assert_eq!(sprt.decision(20, 20, 60), SprtDecision::AcceptH0);
+        assert_eq!(sprt.decision(2, 1, 1), SprtDecision::Continue);
+    }
+
+    #[test]
+    fn llr_is_finite_when_all_games_are_won() {
+        let llr = Sprt::new(0.0, 50.0).llr(10, 0, 0);
+        assert!(llr.is_finite() && llr > 0.0);
+    }
+
+    #[test]
+    fn match_stops_when_a_decision_is_reached() {
+        let random = RandomPlayer::with_rng(Box::new(XorShiftRng::new(7)));
+        let sprt_match = SprtMatch::new(
+            Box::new(AlphaBeta::new(2)),
+            Box::new(random),
+            Sprt::new(0.0, 100.0),
+            100,
+        );
+        let result = sprt_match.run();
+        assert_eq!(result.decision, SprtDecision::AcceptH1);
+        assert!(result.wins + result.draws + result.losses < 100);
+    }
+
+    #[test]
+    fn match_stops_after_max_games() {
+        let mut sprt_match = SprtMatch::new(
+            Box::new(AlphaBeta::new(1)),
+            Box::new(AlphaBeta::new(1)),
+            Sprt::new(0.0, 10.0),
+            4,
+        );
+        sprt_match.add_opening("f5d6").unwrap();
+        let result = sprt_match.run();
+        assert_eq!(result.wins + result.draws + result.losses, 4);
+        assert_eq!(result.decision, SprtDecision::Continue);
+    }
+}
@@ -0,0 +1,174 @@
+use alloc::string::ToString;
+use core::fmt;
+
+use super::board::*;
+use super::moves::*;
+
+/// A cell of a N x N board (8x8 by default), always within the board : its
+/// coordinates are checked once when it's built, so the board methods
+/// taking a Square don't fail.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Square<const N: usize = 8> {
+    x: u8,
+    y: u8,
+}
+
+impl<const N: usize> Square<N> {
+    /// Creates a Square, failing if the coordinates are out of the board.
+    pub fn new(x: u8, y: u8) -> Result<Self, BoardError> {
+        if x as usize >= N || y as usize >= N {
+            Err(BoardError::OutOfRange { x, y })
+        } else {
+            Ok(Self { x, y })
+        }
+    }
+
+    /// Creates a Square from its standard notation (ie "D3"), ignoring case.
+    pub fn from_notation(notation: &str) -> Result<Self, BoardError> {
+        let invalid = || BoardError::InvalidNotation(notation.to_string());
+        match notation.parse::<Move>() {
+            Ok(Move::Place { x, y }) => Self::new(x, y).map_err(|_| invalid()),
+            _ => Err(invalid()),
+        }
+    }
+
+    /// Returns the coordinates of the square.
+    pub fn coordinates(self) -> (u8, u8) {
+        (self.x, self.y)
+    }
+
+    pub fn x(self) -> u8 {
+        self.x
+    }
+
+    pub fn y(self) -> u8 {
+        self.y
+    }
+
+    /// Returns the letter of the column (ie 'D' for D3).
+    pub fn file(self) -> char {
+        (b'A' + self.x) as char
+    }
+
+    /// Returns the number of the row, starting from 1 (ie 3 for D3).
+    pub fn rank(self) -> u8 {
+        self.y + 1
+    }
+
+    /// Returns the kind of the square.
+    pub fn kind(self) -> SquareKind {
+        let last = N as u8 - 1;
+        let on_edge = |coordinate| coordinate == 0 || coordinate == last;
+        let next_to_edge = |coordinate| coordinate == 1 || coordinate == last - 1;
+        let (x, y) = (self.x, self.y);
+        if on_edge(x) && on_edge(y) {
+            SquareKind::Corner
+        } else if (on_edge(x) && next_to_edge(y)) || (next_to_edge(x) && on_edge(y)) {
+            SquareKind::CSquare
+        } else if next_to_edge(x) && next_to_edge(y) {
+            SquareKind::XSquare
+        } else if on_edge(x) || on_edge(y) {
+            SquareKind::Edge
+        } else {
+            SquareKind::Interior
+        }
+    }
+
+    /// Checks if the square is a corner.
+    pub fn is_corner(self) -> bool {
+        self.kind() == SquareKind::Corner
+    }
+
+    /// Checks if the square is on the border of the board, corners included.
+    pub fn is_on_edge(self) -> bool {
+        match self.kind() {
+            SquareKind::Corner | SquareKind::CSquare | SquareKind::Edge => true,
+            SquareKind::XSquare | SquareKind::Interior => false,
+        }
+    }
+}
+
+/// The kinds of squares, according to their strategic value.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum SquareKind {
+    /// A corner, never flipped once taken.
+    Corner,
+    /// A square on the border, next to a corner.
+    CSquare,
+    /// A square diagonally next to a corner, often giving it to the opponent.
+    XSquare,
+    /// Any other square on the border.
+    Edge,
+    /// Any other square.
+    Interior,
+}
+
+impl<const N: usize> From<Square<N>> for Move {
+    fn from(square: Square<N>) -> Self {
+        Move::Place {
+            x: square.x,
+            y: square.y,
+        }
+    }
+}
+
+impl<const N: usize> fmt::Display for Square<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}{}", self.file(), self.rank())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_checks_the_coordinates() {
+        assert_eq!(Square::<8>::new(7, 7).unwrap().coordinates(), (7, 7));
+        assert!(Square::<8>::new(8, 0).is_err());
+        assert!(Square::<8>::new(0, 8).is_err());
+        assert!(Square::<10>::new(9, 9).is_ok());
+    }
+
+    #[test]
+    fn from_notation_parses_squares_of_the_board() {
+        let square = Square::<8>::from_notation("d3").unwrap();
+        assert_eq!(square.coordinates(), (3, 2));
+        assert_eq!((square.file(), square.rank()), ('D', 3));
+        assert_eq!(square.to_string(), "D3");
+        assert!(Square::<8>::from_notation("J10").is_err());
+        assert!(Square::<10>::from_notation("J10").is_ok());
+        assert!(Square::<8>::from_notation("pass").is_err());
+    }
+
+    #[test]
+    fn kind_classifies_squares() {
+        let kind = |notation| Square::<8>::from_notation(notation).unwrap().kind();
+        assert_eq!(kind("A1"), SquareKind::Corner);
+        assert_eq!(kind("H8"), SquareKind::Corner);
+        assert_eq!(kind("B1"), SquareKind::CSquare);
+        assert_eq!(kind("H7"), SquareKind::CSquare);
+        assert_eq!(kind("B2"), SquareKind::XSquare);
+        assert_eq!(kind("G7"), SquareKind::XSquare);
+        assert_eq!(kind("D1"), SquareKind::Edge);
+        assert_eq!(kind("C2"), SquareKind::Interior);
+        assert_eq!(kind("D4"), SquareKind::Interior);
+        let square = Square::<6>::new(4, 5).unwrap();
+        assert_eq!(square.kind(), SquareKind::CSquare);
+        assert!(square.is_on_edge());
+        assert!(!square.is_corner());
+    }
+
+    #[test]
+    fn board_classifies_squares() {
+        let board = Board::new_start();
+        assert_eq!(board.square_kind(7, 0), Ok(SquareKind::Corner));
+        assert!(board.square_kind(8, 0).is_err());
+    }
+
+    #[test]
+    fn squares_convert_to_moves() {
+        let square = Square::<8>::new(5, 4).unwrap();
+        assert_eq!(Move::from(square), Move::Place { x: 5, y: 4 });
+    }
+}
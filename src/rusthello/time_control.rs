@@ -0,0 +1,169 @@
+use core::time::Duration;
+
+/// Time control of a player : a main time for the whole game, and an
+/// increment added after each move (Fischer clock by default).
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimeControl {
+    main_time: Duration,
+    increment: Duration,
+    increment_kind: IncrementKind,
+}
+
+/// The way the increment is given back to a player after a move.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum IncrementKind {
+    /// The whole increment is added, the remaining time can grow.
+    Fischer,
+    /// The time spent is given back up to the increment, the remaining time
+    /// never grows.
+    Bronstein,
+}
+
+impl TimeControl {
+    /// Creates a new TimeControl, using a Fischer increment.
+    pub fn new(main_time: Duration, increment: Duration) -> Self {
+        Self {
+            main_time,
+            increment,
+            increment_kind: IncrementKind::Fischer,
+        }
+    }
+
+    /// Changes the way the increment is given.
+    pub fn with_increment_kind(mut self, increment_kind: IncrementKind) -> Self {
+        self.increment_kind = increment_kind;
+        self
+    }
+
+    pub fn main_time(&self) -> Duration {
+        self.main_time
+    }
+
+    pub fn increment(&self) -> Duration {
+        self.increment
+    }
+
+    pub fn increment_kind(&self) -> IncrementKind {
+        self.increment_kind
+    }
+}
+
+/// The clock of a player during a game, running according to its time
+/// control.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Clock {
+    control: TimeControl,
+    remaining: Duration,
+    flagged: bool,
+}
+
+impl Clock {
+    /// Expected count of moves a player still has to play, used to share
+    /// the remaining time between moves.
+    const MOVES_TO_GO: u32 = 20;
+
+    /// Creates a new clock, having the whole main time.
+    pub fn new(control: TimeControl) -> Self {
+        Self {
+            control,
+            remaining: control.main_time,
+            flagged: false,
+        }
+    }
+
+    pub fn control(&self) -> TimeControl {
+        self.control
+    }
+
+    /// Returns the time remaining for the rest of the game.
+    pub fn remaining(&self) -> Duration {
+        self.remaining
+    }
+
+    /// Returns true if the player exceeded its time.
+    pub fn is_flagged(&self) -> bool {
+        self.flagged
+    }
+
+    /// Returns the time a player should spend on its next move : a share of
+    /// the remaining time plus the increment, without exceeding the
+    /// remaining time.
+    pub fn move_budget(&self) -> Duration {
+        let budget = self.remaining / Self::MOVES_TO_GO + self.control.increment;
+        budget.min(self.remaining)
+    }
+
+    /// Records the time spent by a move. Returns false, and the clock is
+    /// flagged, if the player exceeded its remaining time. Otherwise the
+    /// increment is added.
+    pub fn record_move(&mut self, elapsed: Duration) -> bool {
+        if self.flagged {
+            return false;
+        }
+        match self.remaining.checked_sub(elapsed) {
+            Some(remaining) => {
+                let increment = match self.control.increment_kind {
+                    IncrementKind::Fischer => self.control.increment,
+                    IncrementKind::Bronstein => elapsed.min(self.control.increment),
+                };
+                self.remaining = remaining + increment;
+                true
+            }
+            None => {
+                self.remaining = Duration::from_secs(0);
+                self.flagged = true;
+                false
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn control() -> TimeControl {
+        TimeControl::new(Duration::from_secs(60), Duration::from_secs(2))
+    }
+
+    #[test]
+    fn clock_adds_the_increment_after_each_move() {
+        let mut clock = Clock::new(control());
+        assert!(clock.record_move(Duration::from_secs(10)));
+        assert_eq!(clock.remaining(), Duration::from_secs(52));
+        assert!(!clock.is_flagged());
+    }
+
+    #[test]
+    fn bronstein_clock_gives_back_the_time_spent() {
+        let control = control().with_increment_kind(IncrementKind::Bronstein);
+        let mut clock = Clock::new(control);
+        assert!(clock.record_move(Duration::from_secs(1)));
+        assert_eq!(clock.remaining(), Duration::from_secs(60));
+        assert!(clock.record_move(Duration::from_secs(10)));
+        assert_eq!(clock.remaining(), Duration::from_secs(52));
+    }
+
+    #[test]
+    fn clock_flags_when_time_is_exceeded() {
+        let mut clock = Clock::new(control());
+        assert!(!clock.record_move(Duration::from_secs(61)));
+        assert!(clock.is_flagged());
+        assert_eq!(clock.remaining(), Duration::from_secs(0));
+        assert!(!clock.record_move(Duration::from_secs(0)));
+    }
+
+    #[test]
+    fn move_budget_shares_the_remaining_time() {
+        let clock = Clock::new(control());
+        assert_eq!(clock.move_budget(), Duration::from_secs(5));
+        let clock = Clock::new(TimeControl::new(
+            Duration::from_secs(1),
+            Duration::from_secs(2),
+        ));
+        assert_eq!(clock.move_budget(), Duration::from_secs(1));
+    }
+}
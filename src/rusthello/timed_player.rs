@@ -0,0 +1,102 @@
+use alloc::{boxed::Box, format, string::String};
+use core::time::Duration;
+
+use super::board::*;
+use super::time_control::*;
+use super::virtual_player::*;
+
+/// A virtual player spending a fixed time on each move : the wrapped player
+/// searches through `VirtualPlayer::compute_move_timed` with a clock giving
+/// it the budget, even in games without clocks. It only limits the players
+/// regulating their thinking time (ie `AlphaBeta`, searching as deep as the
+/// budget allows).
+pub struct TimedPlayer {
+    player: Box<dyn VirtualPlayer>,
+    budget: Duration,
+}
+
+impl TimedPlayer {
+    /// Creates a TimedPlayer giving the given time per move to the player.
+    pub fn new(player: Box<dyn VirtualPlayer>, budget: Duration) -> Self {
+        Self { player, budget }
+    }
+
+    /// Returns a clock whose move budget is exactly the budget of the
+    /// player, the increment giving back the time spent.
+    fn clock(&self) -> Clock {
+        Clock::new(TimeControl::new(self.budget, self.budget))
+    }
+}
+
+impl EngineInfo for TimedPlayer {
+    fn name(&self) -> String {
+        self.player.name()
+    }
+
+    fn version(&self) -> String {
+        self.player.version()
+    }
+
+    fn author(&self) -> String {
+        self.player.author()
+    }
+
+    fn options(&self) -> String {
+        let budget = format!("time={}ms", self.budget.as_millis());
+        let options = self.player.options();
+        if options.is_empty() {
+            budget
+        } else {
+            format!("{}, {}", options, budget)
+        }
+    }
+}
+
+impl VirtualPlayer for TimedPlayer {
+    fn move_count(&self) -> u32 {
+        self.player.move_count()
+    }
+
+    fn compute_move(&self, board: &Board, me: Player) -> Option<(u8, u8)> {
+        self.player.compute_move_timed(board, me, &self.clock())
+    }
+
+    /// Searches with the budget of the player, or the one of the given clock
+    /// if it's shorter.
+    fn compute_move_timed(&self, board: &Board, me: Player, clock: &Clock) -> Option<(u8, u8)> {
+        if clock.move_budget() < self.budget {
+            self.player.compute_move_timed(board, me, clock)
+        } else {
+            self.compute_move(board, me)
+        }
+    }
+
+    fn wants_to_resign(&self) -> bool {
+        self.player.wants_to_resign()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use std::time::Instant;
+
+    use super::*;
+
+    #[test]
+    fn clock_gives_the_budget_to_each_move() {
+        let budget = Duration::from_millis(300);
+        let player = TimedPlayer::new(Box::new(AlphaBeta::new(3)), budget);
+        assert_eq!(player.clock().move_budget(), budget);
+        assert_eq!(player.options(), "depth=3, time=300ms");
+    }
+
+    #[test]
+    fn timed_player_stops_its_search_in_time() {
+        let player = TimedPlayer::new(Box::new(AlphaBeta::new(60)), Duration::from_millis(50));
+        let start = Instant::now();
+        assert!(player
+            .compute_move(&Board::new_start(), Player::Black)
+            .is_some());
+        assert!(start.elapsed() < Duration::from_secs(5));
+    }
+}
@@ -0,0 +1,797 @@
+use std::{fmt, time::Instant};
+
+use super::board::*;
+use super::endgame_solver::*;
+use super::game::*;
+use super::game_result::*;
+use super::moves::*;
+use super::opening_book::parse_line;
+use super::time_control::*;
+use super::virtual_player::*;
+
+/// Plays a whole game between two virtual players, and returns the finished
+/// game. A player unable to produce a move when it has to play, or wanting
+/// to resign, loses by resignation.
+pub fn play_game(black: &dyn VirtualPlayer, white: &dyn VirtualPlayer) -> Game {
+    play_game_from(Game::new(), black, white)
+}
+
+/// Plays the given game until its end, see `play_game`.
+pub fn play_game_from(game: Game, black: &dyn VirtualPlayer, white: &dyn VirtualPlayer) -> Game {
+    play_until_the_end(game, black, white, None)
+}
+
+/// Plays a whole game between two virtual players, each one having its own
+/// time control. A player exceeding its time loses on time, see also
+/// `play_game`.
+pub fn play_timed_game(
+    black: &dyn VirtualPlayer,
+    black_control: TimeControl,
+    white: &dyn VirtualPlayer,
+    white_control: TimeControl,
+) -> Game {
+    play_timed_game_from(Game::new(), black, black_control, white, white_control)
+}
+
+/// Plays the given game until its end, see `play_timed_game`.
+pub fn play_timed_game_from(
+    game: Game,
+    black: &dyn VirtualPlayer,
+    black_control: TimeControl,
+    white: &dyn VirtualPlayer,
+    white_control: TimeControl,
+) -> Game {
+    play_until_the_end(
+        game.with_clocks(black_control, white_control),
+        black,
+        white,
+        None,
+    )
+}
+
+/// Plays the given game until its end, using its clocks if any. With an
+/// adjudication, the game is adjudicated once the given count of empty
+/// squares is reached.
+fn play_until_the_end(
+    mut game: Game,
+    black: &dyn VirtualPlayer,
+    white: &dyn VirtualPlayer,
+    adjudication_empties: Option<u8>,
+) -> Game {
+    while let Some(player) = game.player() {
+        if let Some(empties) = adjudication_empties {
+            if game.board().empties() <= empties {
+                game.adjudicate(&EndgameSolver::new())
+                    .expect("Unexpected adjudication error while the game is running.");
+                break;
+            }
+        }
+        let virtual_player = match player {
+            Player::Black => black,
+            Player::White => white,
+        };
+        let start = Instant::now();
+        let position = match game.clock(player) {
+            Some(clock) => virtual_player.compute_move_timed(game.board(), player, clock),
+            None => virtual_player.compute_move(game.board(), player),
+        };
+        let elapsed = start.elapsed();
+        if game.check_time(elapsed) {
+            break;
+        }
+        let played = match position {
+            Some((x, y)) if !virtual_player.wants_to_resign() => game
+                .play_timed(player, Move::Place { x, y }, elapsed)
+                .is_ok(),
+            _ => false,
+        };
+        if !played {
+            game.resign(player)
+                .expect("Unexpected resignation error while the game is running.");
+        }
+    }
+
+    game
+}
+
+/// Result of a game played during a tournament, participants and openings
+/// being identified by their index.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GameRecord {
+    pub black: usize,
+    pub white: usize,
+    pub opening: Option<usize>,
+    pub winner: Option<Player>,
+    pub black_pieces: u8,
+    pub white_pieces: u8,
+}
+
+impl GameRecord {
+    fn from_game(black: usize, white: usize, opening: Option<usize>, game: &Game) -> Self {
+        // the pieces of an adjudicated game are the proven ones.
+        let (black_pieces, white_pieces) = match game.result() {
+            Some(GameResult::Adjudicated { black, white }) => (black, white),
+            _ => game.count_pieces(),
+        };
+        Self {
+            black,
+            white,
+            opening,
+            winner: game.winner(),
+            black_pieces,
+            white_pieces,
+        }
+    }
+
+    /// Returns the points scored by the given participant : 1 for a win,
+    /// 0.5 for a draw, 0 for a loss (or if he didn't play the game).
+    pub fn score(&self, participant: usize) -> f64 {
+        let color = if participant == self.black {
+            Player::Black
+        } else if participant == self.white {
+            Player::White
+        } else {
+            return 0.0;
+        };
+        match self.winner {
+            None => 0.5,
+            Some(winner) if winner == color => 1.0,
+            Some(_) => 0.0,
+        }
+    }
+
+    /// Does the given participant play this game ?
+    pub fn involves(&self, participant: usize) -> bool {
+        participant == self.black || participant == self.white
+    }
+
+    /// Returns the disc difference of the game for the given participant.
+    pub fn disc_difference(&self, participant: usize) -> i32 {
+        let difference = self.black_pieces as i32 - self.white_pieces as i32;
+        if participant == self.black {
+            difference
+        } else if participant == self.white {
+            -difference
+        } else {
+            0
+        }
+    }
+}
+
+/// How participants are paired during a tournament.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Pairing {
+    /// Each participant plays against all others.
+    RoundRobin,
+    /// Each round, participants play against an opponent having a similar
+    /// score, avoiding rematches when possible. With an odd count of
+    /// participants, one of them gets a bye (and one point) each round.
+    Swiss { rounds: u32 },
+}
+
+/// A tournament between virtual players, alternating colors.
+pub struct Tournament {
+    participants: Vec<(String, Box<dyn VirtualPlayer>)>,
+    games_per_pair: u32,
+    pairing: Pairing,
+    openings: Vec<Vec<(u8, u8)>>,
+    time_control: Option<TimeControl>,
+    adjudication_empties: Option<u8>,
+}
+
+impl Tournament {
+    /// Creates a round-robin tournament where each pairing of participants
+    /// will play the given count of games (for each opening, if any).
+    pub fn new(games_per_pair: u32) -> Self {
+        Self {
+            participants: Vec::new(),
+            games_per_pair,
+            pairing: Pairing::RoundRobin,
+            openings: Vec::new(),
+            time_control: None,
+            adjudication_empties: None,
+        }
+    }
+
+    /// Changes the way participants are paired.
+    pub fn with_pairing(mut self, pairing: Pairing) -> Self {
+        self.pairing = pairing;
+        self
+    }
+
+    /// Plays all games with the given time control for both players.
+    pub fn with_time_control(mut self, time_control: TimeControl) -> Self {
+        self.time_control = Some(time_control);
+        self
+    }
+
+    /// Adjudicates the games with the endgame solver once the given count of
+    /// empty squares is reached, which keeps long tournaments fast.
+    pub fn with_adjudication(mut self, empties: u8) -> Self {
+        self.adjudication_empties = Some(empties);
+        self
+    }
+
+    /// Adds a participant to the tournament.
+    pub fn add_participant(&mut self, name: &str, player: Box<dyn VirtualPlayer>) {
+        self.participants.push((name.to_string(), player));
+    }
+
+    /// Adds a participant to the tournament, named after its description.
+    pub fn add_player(&mut self, player: Box<dyn VirtualPlayer>) {
+        let name = player.description();
+        self.add_participant(&name, player);
+    }
+
+    /// Adds a fixed opening, in standard notation (ie "f5d6c3"). Each pairing
+    /// will play its games starting from each opening.
+    pub fn add_opening(&mut self, line: &str) -> Result<(), String> {
+        let moves = parse_line(line)?;
+        opening_game(&moves)?;
+        self.openings.push(moves);
+        Ok(())
+    }
+
+    /// Plays all the games of the tournament.
+    pub fn run(&self) -> TournamentResult {
+        let mut result = TournamentResult {
+            names: self
+                .participants
+                .iter()
+                .map(|(name, _)| name.clone())
+                .collect(),
+            games: Vec::new(),
+            byes: Vec::new(),
+        };
+
+        match self.pairing {
+            Pairing::RoundRobin => {
+                for first in 0..self.participants.len() {
+                    for second in (first + 1)..self.participants.len() {
+                        self.play_pairing(first, second, &mut result);
+                    }
+                }
+            }
+            Pairing::Swiss { rounds } => {
+                for _ in 0..rounds {
+                    let (pairs, bye) = result.swiss_pairs();
+                    for (first, second) in pairs {
+                        self.play_pairing(first, second, &mut result);
+                    }
+                    result.byes.extend(bye);
+                }
+            }
+        }
+
+        result
+    }
+
+    /// Plays all the games between two participants, the one having played
+    /// less games with Black starting with Black.
+    fn play_pairing(&self, first: usize, second: usize, result: &mut TournamentResult) {
+        let (first, second) = if result.black_games(first) <= result.black_games(second) {
+            (first, second)
+        } else {
+            (second, first)
+        };
+        let openings: Vec<Option<usize>> = if self.openings.is_empty() {
+            vec![None]
+        } else {
+            (0..self.openings.len()).map(Some).collect()
+        };
+
+        for opening in openings {
+            for round in 0..self.games_per_pair {
+                let (black, white) = if round % 2 == 0 {
+                    (first, second)
+                } else {
+                    (second, first)
+                };
+                let game = match opening {
+                    Some(index) => {
+                        opening_game(&self.openings[index]).expect("Unexpected invalid opening.")
+                    }
+                    None => Game::new(),
+                };
+                let black_player = self.participants[black].1.as_ref();
+                let white_player = self.participants[white].1.as_ref();
+                let game = match self.time_control {
+                    Some(control) => game.with_clocks(control, control),
+                    None => game,
+                };
+                let game =
+                    play_until_the_end(game, black_player, white_player, self.adjudication_empties);
+                result
+                    .games
+                    .push(GameRecord::from_game(black, white, opening, &game));
+            }
+        }
+    }
+}
+
+/// Builds a game after playing the given opening moves.
+pub(crate) fn opening_game(moves: &[(u8, u8)]) -> Result<Game, String> {
+    let mut game = Game::new();
+    for &(x, y) in moves {
+        let player = game
+            .player()
+            .ok_or_else(|| "the opening ends the game".to_string())?;
+        game.play(player, x, y).map_err(|error| error.to_string())?;
+    }
+    if game.game_over() {
+        return Err("the opening ends the game".to_string());
+    }
+
+    Ok(game)
+}
+
+/// Standing of a participant at the end of a tournament.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Standing {
+    pub participant: usize,
+    pub name: String,
+    pub points: f64,
+    pub games: u32,
+    pub wins: u32,
+    pub draws: u32,
+    pub losses: u32,
+    pub byes: u32,
+    pub disc_difference: i32,
+}
+
+/// Elo rating, with the 95% confidence interval.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rating {
+    pub elo: f64,
+    pub error: f64,
+}
+
+/// Results of a tournament : all the games played, from which the
+/// cross-table and the ratings are computed.
+pub struct TournamentResult {
+    names: Vec<String>,
+    games: Vec<GameRecord>,
+    byes: Vec<usize>,
+}
+
+impl TournamentResult {
+    /// Returns the names of the participants.
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    /// Returns all the games played.
+    pub fn games(&self) -> &[GameRecord] {
+        &self.games
+    }
+
+    /// Returns the points scored by a participant against another one, and
+    /// the count of games they played together.
+    pub fn score_against(&self, participant: usize, opponent: usize) -> (f64, u32) {
+        self.games
+            .iter()
+            .filter(|game| game.involves(participant) && game.involves(opponent))
+            .fold((0.0, 0), |(points, count), game| {
+                (points + game.score(participant), count + 1)
+            })
+    }
+
+    /// Returns the participants having a bye, one per Swiss round.
+    pub fn byes(&self) -> &[usize] {
+        &self.byes
+    }
+
+    /// Returns the total points scored by a participant, including byes.
+    pub fn total_score(&self, participant: usize) -> f64 {
+        let games_points: f64 = self.games.iter().map(|game| game.score(participant)).sum();
+        games_points + self.bye_count(participant) as f64
+    }
+
+    /// Returns the standings, best participants first. Ties are broken
+    /// using the disc difference.
+    pub fn standings(&self) -> Vec<Standing> {
+        let mut standings: Vec<Standing> = self
+            .names
+            .iter()
+            .enumerate()
+            .map(|(participant, name)| {
+                let games: Vec<&GameRecord> = self
+                    .games
+                    .iter()
+                    .filter(|game| game.involves(participant))
+                    .collect();
+                let count_score = |score: f64| {
+                    games
+                        .iter()
+                        .filter(|game| game.score(participant) == score)
+                        .count() as u32
+                };
+                Standing {
+                    participant,
+                    name: name.clone(),
+                    points: self.total_score(participant),
+                    games: games.len() as u32,
+                    wins: count_score(1.0),
+                    draws: count_score(0.5),
+                    losses: count_score(0.0),
+                    byes: self.bye_count(participant),
+                    disc_difference: games
+                        .iter()
+                        .map(|game| game.disc_difference(participant))
+                        .sum(),
+                }
+            })
+            .collect();
+        standings.sort_by(|a, b| {
+            b.points
+                .partial_cmp(&a.points)
+                .unwrap()
+                .then(b.disc_difference.cmp(&a.disc_difference))
+        });
+
+        standings
+    }
+
+    fn bye_count(&self, participant: usize) -> u32 {
+        self.byes.iter().filter(|&&bye| bye == participant).count() as u32
+    }
+
+    fn black_games(&self, participant: usize) -> usize {
+        self.games
+            .iter()
+            .filter(|game| game.black == participant)
+            .count()
+    }
+
+    /// Pairs participants for the next Swiss round, returning the pairs and
+    /// the participant having a bye (if any).
+    fn swiss_pairs(&self) -> (Vec<(usize, usize)>, Option<usize>) {
+        let mut unpaired: Vec<usize> = self
+            .standings()
+            .iter()
+            .map(|standing| standing.participant)
+            .collect();
+
+        // the bye goes to the lowest ranked participant without a bye.
+        let mut bye = None;
+        if unpaired.len() % 2 == 1 {
+            let index = unpaired
+                .iter()
+                .rposition(|&participant| self.bye_count(participant) == 0)
+                .unwrap_or(unpaired.len() - 1);
+            bye = Some(unpaired.remove(index));
+        }
+
+        let mut pairs = Vec::new();
+        while !unpaired.is_empty() {
+            let first = unpaired.remove(0);
+            let index = unpaired
+                .iter()
+                .position(|&second| self.score_against(first, second).1 == 0)
+                .unwrap_or(0);
+            pairs.push((first, unpaired.remove(index)));
+        }
+
+        (pairs, bye)
+    }
+
+    /// Computes the Elo ratings of all participants, their average being 0.
+    /// A virtual draw is added between each pair of opponents, avoiding
+    /// infinite ratings for participants winning (or losing) all their games.
+    pub fn ratings(&self) -> Vec<Rating> {
+        const ITERATIONS: usize = 100;
+        let count = self.names.len();
+        let mut points = vec![vec![0.0; count]; count];
+        let mut games = vec![vec![0.0; count]; count];
+        for i in 0..count {
+            for j in 0..count {
+                let (score, played) = self.score_against(i, j);
+                if i != j && played > 0 {
+                    points[i][j] = score + 0.5;
+                    games[i][j] = played as f64 + 1.0;
+                }
+            }
+        }
+
+        let mut elos = vec![0.0; count];
+        for _ in 0..ITERATIONS {
+            for i in 0..count {
+                let (expected, information) = Self::expectation(&elos, &games, i);
+                if information > 0.0 {
+                    let actual: f64 = points[i].iter().sum();
+                    elos[i] += (actual - expected) / information;
+                }
+            }
+            let average = elos.iter().sum::<f64>() / count as f64;
+            elos.iter_mut().for_each(|elo| *elo -= average);
+        }
+
+        (0..count)
+            .map(|i| {
+                let (_, information) = Self::expectation(&elos, &games, i);
+                let error = if information > 0.0 {
+                    1.96 / (information * ELO_SLOPE).sqrt()
+                } else {
+                    f64::INFINITY
+                };
+                Rating {
+                    elo: elos[i],
+                    error,
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the expected points of a participant according to the given
+    /// ratings, and the derivative of the expectation (per Elo point).
+    fn expectation(elos: &[f64], games: &[Vec<f64>], participant: usize) -> (f64, f64) {
+        let mut expected = 0.0;
+        let mut derivative = 0.0;
+        for (opponent, &played) in games[participant].iter().enumerate() {
+            if played > 0.0 {
+                let probability = expected_score(elos[participant] - elos[opponent]);
+                expected += played * probability;
+                derivative += played * ELO_SLOPE * probability * (1.0 - probability);
+            }
+        }
+
+        (expected, derivative)
+    }
+}
+
+/// Derivative factor of the logistic Elo curve.
+const ELO_SLOPE: f64 = std::f64::consts::LN_10 / 400.0;
+
+/// Expected score for a given Elo difference.
+fn expected_score(difference: f64) -> f64 {
+    1.0 / (1.0 + 10f64.powf(-difference / 400.0))
+}
+
+impl fmt::Display for TournamentResult {
+    /// Builds the cross-table, with total scores and ratings.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let width = self.names.iter().map(|name| name.len()).max().unwrap_or(0);
+        write!(f, "{:>3} {:width$}", "", "", width = width)?;
+        for opponent in 0..self.names.len() {
+            write!(f, " {:>6}", opponent + 1)?;
+        }
+        writeln!(f, " {:>6} {:>12}", "Score", "Elo")?;
+
+        let ratings = self.ratings();
+        for (participant, name) in self.names.iter().enumerate() {
+            write!(f, "{:>3} {:width$}", participant + 1, name, width = width)?;
+            for opponent in 0..self.names.len() {
+                let (points, played) = self.score_against(participant, opponent);
+                if participant == opponent || played == 0 {
+                    write!(f, " {:>6}", "-")?;
+                } else {
+                    write!(f, " {:>6.1}", points)?;
+                }
+            }
+            let rating = ratings[participant];
+            writeln!(
+                f,
+                " {:>6.1} {:>+5.0} ± {:<4.0}",
+                self.total_score(participant),
+                rating.elo,
+                rating.error
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::random::*;
+    use super::super::xot::*;
+    use super::*;
+    use std::time::Duration;
+
+    fn result(games: Vec<GameRecord>) -> TournamentResult {
+        TournamentResult {
+            names: vec!["first".to_string(), "second".to_string()],
+            games,
+            byes: Vec::new(),
+        }
+    }
+
+    fn record(black: usize, white: usize, winner: Option<Player>) -> GameRecord {
+        GameRecord {
+            black,
+            white,
+            opening: None,
+            winner,
+            black_pieces: 32,
+            white_pieces: 32,
+        }
+    }
+
+    #[test]
+    fn play_game_plays_until_the_end() {
+        let game = play_game(&AlphaBeta::new(1), &AlphaBeta::new(2));
+        assert!(game.game_over());
+        assert!(game.resigned().is_none());
+    }
+
+    #[test]
+    fn play_game_handles_resignation() {
+        let resigning = AlphaBeta::new(1).with_resign_threshold(i32::MIN);
+        let game = play_game(&AlphaBeta::new(1), &resigning);
+        assert_eq!(game.resigned(), Some(Player::White));
+    }
+
+    #[test]
+    fn play_timed_game_reports_time_forfeits() {
+        let plenty = TimeControl::new(Duration::from_secs(3600), Duration::from_secs(0));
+        let none = TimeControl::new(Duration::from_secs(0), Duration::from_secs(0));
+        let game = play_timed_game(&AlphaBeta::new(1), plenty, &AlphaBeta::new(1), none);
+        assert_eq!(game.lost_on_time(), Some(Player::White));
+        assert_eq!(game.winner(), Some(Player::Black));
+        assert_eq!(game.count_pieces(), (4, 1));
+    }
+
+    #[test]
+    fn timed_tournament_plays_until_the_end() {
+        let control = TimeControl::new(Duration::from_secs(3600), Duration::from_secs(1));
+        let mut tournament = Tournament::new(1).with_time_control(control);
+        tournament.add_participant("one", Box::new(AlphaBeta::new(2)));
+        tournament.add_participant("two", Box::new(AlphaBeta::new(1)));
+        let result = tournament.run();
+        let record = result.games()[0];
+        assert!(record.black_pieces + record.white_pieces > 4);
+    }
+
+    #[test]
+    fn adjudicated_tournament_stops_the_games() {
+        let mut tournament = Tournament::new(1).with_adjudication(10);
+        tournament.add_participant("one", Box::new(AlphaBeta::new(1)));
+        tournament.add_participant("two", Box::new(AlphaBeta::new(2)));
+        let result = tournament.run();
+        let record = result.games()[0];
+        assert_eq!(record.black_pieces + record.white_pieces, 64);
+
+        // the game stops at the 10 empty squares, with the proven score.
+        let (one, two) = (AlphaBeta::new(1), AlphaBeta::new(2));
+        let game = play_until_the_end(Game::new(), &one, &two, Some(10));
+        assert_eq!(game.board().empties(), 10);
+        assert_eq!(
+            game.result(),
+            Some(GameResult::Adjudicated {
+                black: record.black_pieces,
+                white: record.white_pieces,
+            })
+        );
+    }
+
+    #[test]
+    fn run_plays_all_games_alternating_colors() {
+        let mut tournament = Tournament::new(2);
+        tournament.add_participant("one", Box::new(AlphaBeta::new(1)));
+        tournament.add_participant("two", Box::new(AlphaBeta::new(1)));
+        tournament.add_participant("three", Box::new(AlphaBeta::new(1)));
+        let result = tournament.run();
+        assert_eq!(result.games().len(), 6);
+        assert_eq!((result.games()[0].black, result.games()[0].white), (0, 1));
+        assert_eq!((result.games()[1].black, result.games()[1].white), (1, 0));
+        let total: f64 = (0..3)
+            .map(|participant| result.total_score(participant))
+            .sum();
+        assert_eq!(total, 6.0);
+    }
+
+    #[test]
+    fn add_player_uses_the_player_description() {
+        let mut tournament = Tournament::new(1);
+        tournament.add_player(Box::new(AlphaBeta::new(1)));
+        let result = tournament.run();
+        assert_eq!(result.names(), ["AlphaBeta (depth=1)".to_string()]);
+    }
+
+    #[test]
+    fn run_uses_fixed_openings() {
+        let mut tournament = Tournament::new(2);
+        tournament.add_participant("one", Box::new(AlphaBeta::new(1)));
+        tournament.add_participant("two", Box::new(AlphaBeta::new(1)));
+        tournament.add_opening("f5d6c3").unwrap();
+        tournament.add_opening("f5f6").unwrap();
+        let result = tournament.run();
+        let openings: Vec<Option<usize>> = result.games().iter().map(|game| game.opening).collect();
+        assert_eq!(openings, vec![Some(0), Some(0), Some(1), Some(1)]);
+    }
+
+    #[test]
+    fn run_differs_with_xot_openings() {
+        let generator = XotGenerator::new(Box::new(XorShiftRng::new(1))).with_search_depth(2);
+        let mut tournament = Tournament::new(1);
+        tournament.add_participant("one", Box::new(AlphaBeta::new(1)));
+        tournament.add_participant("two", Box::new(AlphaBeta::new(1)));
+        for line in generator.generate(4) {
+            tournament.add_opening(&line).unwrap();
+        }
+        let result = tournament.run();
+        let mut outcomes: Vec<(u8, u8)> = result
+            .games()
+            .iter()
+            .map(|game| (game.black_pieces, game.white_pieces))
+            .collect();
+        outcomes.dedup();
+        assert!(outcomes.len() > 1);
+    }
+
+    #[test]
+    fn add_opening_rejects_invalid_lines() {
+        let mut tournament = Tournament::new(2);
+        assert!(tournament.add_opening("f5f5").is_err());
+        assert!(tournament.add_opening("z9").is_err());
+    }
+
+    #[test]
+    fn swiss_tournament_plays_rounds_with_byes() {
+        let mut tournament = Tournament::new(1).with_pairing(Pairing::Swiss { rounds: 3 });
+        for name in ["one", "two", "three"].iter() {
+            tournament.add_participant(name, Box::new(AlphaBeta::new(1)));
+        }
+        let result = tournament.run();
+        assert_eq!(result.games().len(), 3);
+        let mut byes = result.byes().to_vec();
+        byes.sort_unstable();
+        assert_eq!(byes, vec![0, 1, 2]);
+        // no rematch : each pair played once.
+        for (first, second) in [(0, 1), (0, 2), (1, 2)].iter() {
+            assert_eq!(result.score_against(*first, *second).1, 1);
+        }
+    }
+
+    #[test]
+    fn standings_rank_participants() {
+        let result = result(vec![record(0, 1, Some(Player::White)), record(1, 0, None)]);
+        let standings = result.standings();
+        assert_eq!(standings[0].name, "second");
+        assert_eq!(standings[0].points, 1.5);
+        assert_eq!(
+            (standings[0].wins, standings[0].draws, standings[0].losses),
+            (1, 1, 0)
+        );
+        assert_eq!(standings[1].participant, 0);
+        assert_eq!(standings[1].games, 2);
+    }
+
+    #[test]
+    fn score_against_counts_points() {
+        let result = result(vec![record(0, 1, Some(Player::Black)), record(1, 0, None)]);
+        assert_eq!(result.score_against(0, 1), (1.5, 2));
+        assert_eq!(result.score_against(1, 0), (0.5, 2));
+    }
+
+    #[test]
+    fn ratings_favor_the_winner() {
+        let games = (0..10).map(|_| record(0, 1, Some(Player::Black))).collect();
+        let ratings = result(games).ratings();
+        assert!(ratings[0].elo > 200.0);
+        assert!((ratings[0].elo + ratings[1].elo).abs() < 1e-6);
+        assert!(ratings[0].error > 0.0 && ratings[0].error.is_finite());
+    }
+
+    #[test]
+    fn ratings_are_equal_for_equal_scores() {
+        let ratings = result(vec![
+            record(0, 1, Some(Player::Black)),
+            record(1, 0, Some(Player::Black)),
+        ])
+        .ratings();
+        assert!(ratings[0].elo.abs() < 1e-6);
+        assert!(ratings[1].elo.abs() < 1e-6);
+    }
+
+    #[test]
+    fn fmt_builds_a_cross_table() {
+        let table = format!("{}", result(vec![record(0, 1, Some(Player::White))]));
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[1].contains("first"));
+        assert!(lines[1].contains("0.0"));
+        assert!(lines[2].contains("1.0"));
+    }
+}
@@ -1,28 +1,161 @@
-use std::{cell::Cell, cmp};
+use alloc::{
+    boxed::Box,
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::{cell::Cell, cmp, fmt};
+#[cfg(feature = "std")]
+use std::time::Instant;
 
 use super::board::*;
 use super::game_status::*;
+use super::time_control::*;
+
+/// The EngineInfo trait describes a player, to identify it in tournaments,
+/// logs, protocols, ...
+pub trait EngineInfo {
+    /// Returns the name of the player.
+    fn name(&self) -> String;
+
+    /// Returns the version of the player.
+    fn version(&self) -> String {
+        env!("CARGO_PKG_VERSION").to_string()
+    }
+
+    /// Returns the author of the player.
+    fn author(&self) -> String {
+        env!("CARGO_PKG_AUTHORS").to_string()
+    }
+
+    /// Returns a summary of the player options (ie "depth=6"), or an empty
+    /// string if it has no option.
+    fn options(&self) -> String {
+        String::new()
+    }
+
+    /// Returns the name of the player followed by its options, if any.
+    fn description(&self) -> String {
+        let options = self.options();
+        if options.is_empty() {
+            self.name()
+        } else {
+            format!("{} ({})", self.name(), options)
+        }
+    }
+}
 
 /// The VirtualPlayer trait standardize the public interface of algorithms to
 /// find moves (virtual player, move suggestion, ...).
-pub trait VirtualPlayer {
+pub trait VirtualPlayer: EngineInfo {
     /// Returns the 'best move' the given board and player.
     fn compute_move(&self, board: &Board, me: Player) -> Option<(u8, u8)>;
 
     /// Returns the total count of move while exploring tree game.
     fn move_count(&self) -> u32;
+
+    /// Returns the 'best move' for the given board and player, with its
+    /// evaluation and the line the player expects to follow it.
+    /// Players without evaluation only return the move.
+    fn compute_move_full(&self, board: &Board, me: Player) -> Option<MoveAnalysis> {
+        self.compute_move(board, me).map(|position| MoveAnalysis {
+            position,
+            score: None,
+            variation: vec![position],
+        })
+    }
+
+    /// Returns the 'best move' for the given board and player, the player
+    /// having the given clock. Players able to regulate their thinking time
+    /// should spend around `clock.move_budget()`, others just compute their
+    /// move.
+    fn compute_move_timed(&self, board: &Board, me: Player, _clock: &Clock) -> Option<(u8, u8)> {
+        self.compute_move(board, me)
+    }
+
+    /// Returns true if the position given to the last `compute_move` call was
+    /// judged hopeless, and the player would rather resign than play on.
+    /// Players never resign unless they implement it.
+    fn wants_to_resign(&self) -> bool {
+        false
+    }
+}
+
+/// A move with the analysis which led to it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MoveAnalysis {
+    /// The chosen move.
+    pub position: (u8, u8),
+    /// The evaluation of the move, greater is better for the player, if the
+    /// player evaluates its moves. A proven win is scored `i32::MAX`, and a
+    /// proven loss `-i32::MAX`.
+    pub score: Option<i32>,
+    /// The expected line, starting with the chosen move. Passes are skipped.
+    pub variation: Vec<(u8, u8)>,
+}
+
+impl fmt::Display for MoveAnalysis {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (x, y) = self.position;
+        write!(f, "{}", notation(x, y))?;
+        match self.score {
+            Some(i32::MAX) => write!(f, ", expects a win")?,
+            Some(score) if score == -i32::MAX => write!(f, ", expects a loss")?,
+            Some(score) => write!(f, ", expects {:+}", score)?,
+            None => return Ok(()),
+        }
+        let line: Vec<String> = self.variation.iter().map(|&(x, y)| notation(x, y)).collect();
+        write!(f, " via {}", line.join(" "))
+    }
+}
+
+/// Progress of a search, reported each time a depth is fully explored, see
+/// `AlphaBeta::with_progress`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SearchProgress {
+    /// The depth fully explored.
+    pub depth: u8,
+    /// The moves explored since the start of the search.
+    pub nodes: u32,
+    /// The best move found at this depth, with its evaluation and line.
+    pub best: MoveAnalysis,
+}
+
+/// Callback following the progress of the searches, see
+/// `AlphaBeta::with_progress`.
+pub type ProgressCallback = dyn Fn(&SearchProgress);
+
+fn notation(x: u8, y: u8) -> String {
+    format!("{}{}", (b'a' + x) as char, y + 1)
 }
 
 /// Implementation of the MiniMax algorithm.
 pub struct Minimax {
     depth: u8,
     move_count: Cell<u32>,
+    resign_threshold: Option<i32>,
+    wants_to_resign: Cell<bool>,
 }
 
 impl Minimax {
     /// Creates a new MiniMax with, fixing its exploration depth.
     pub fn new(depth: u8) -> Self {
-        Self { depth, move_count: Cell::new(0) }
+        Self {
+            depth,
+            move_count: Cell::new(0),
+            resign_threshold: None,
+            wants_to_resign: Cell::new(false),
+        }
+    }
+
+    /// Makes the player resign when the evaluation of its best move is at or
+    /// below -threshold (from its point of view). Using `i32::MAX` as threshold
+    /// makes it resign only when the exploration proves that the game is lost,
+    /// and `i32::MIN` makes it always resign.
+    pub fn with_resign_threshold(mut self, threshold: i32) -> Self {
+        self.resign_threshold = Some(threshold);
+        self
     }
 
     /// Minimax implementation.
@@ -46,16 +179,16 @@ impl Minimax {
                     return BestMove::best_move_for_player(
                         current_player,
                         best_move,
-                        Some(BestMove { x, y, evaluation }),
+                        Some(BestMove::leaf(x, y, evaluation)),
                     );
                 }
 
                 // determine the next player, and check if the game is blocked.
-                let next_player = if board_after_move.can_player_move(current_player.opponent()) {
+                let next_player = if board_after_move.has_legal_move(current_player.opponent()) {
                     // the player changes.
                     current_player.opponent()
                 } else {
-                    if board_after_move.can_player_move(current_player) {
+                    if board_after_move.has_legal_move(current_player) {
                         // the game is not blocked, but the player does not change.
                         current_player
                     } else {
@@ -64,7 +197,7 @@ impl Minimax {
                         return BestMove::best_move_for_player(
                             current_player,
                             best_move,
-                            Some(BestMove { x, y, evaluation }),
+                            Some(BestMove::leaf(x, y, evaluation)),
                         );
                     }
                 };
@@ -72,15 +205,10 @@ impl Minimax {
                 let inner_best_move = self
                     .inner_compute_move(&board_after_move, next_player, depth + 1)
                     .unwrap();
-                let BestMove {
-                    x: _,
-                    y: _,
-                    evaluation,
-                } = inner_best_move;
                 return BestMove::best_move_for_player(
                     current_player,
                     best_move,
-                    Some(BestMove { x, y, evaluation }),
+                    Some(inner_best_move.preceded_by(x, y)),
                 );
             }
 
@@ -90,18 +218,38 @@ impl Minimax {
     }
 }
 
+impl EngineInfo for Minimax {
+    fn name(&self) -> String {
+        "Minimax".to_string()
+    }
+
+    fn options(&self) -> String {
+        search_options(self.depth, self.resign_threshold)
+    }
+}
+
 impl VirtualPlayer for Minimax {
     fn move_count(&self) -> u32 {
         self.move_count.get()
     }
 
+    fn wants_to_resign(&self) -> bool {
+        self.wants_to_resign.get()
+    }
+
     fn compute_move(&self, board: &Board, me: Player) -> Option<(u8, u8)> {
+        self.compute_move_full(board, me).map(|analysis| analysis.position)
+    }
+
+    fn compute_move_full(&self, board: &Board, me: Player) -> Option<MoveAnalysis> {
         let best_move = self.inner_compute_move(board, me, 1);
+        self.wants_to_resign.set(BestMove::is_hopeless(
+            best_move.as_ref(),
+            me,
+            self.resign_threshold,
+        ));
 
-        match best_move {
-            Some(move_found) => Some((move_found.x, move_found.y)),
-            None => None,
-        }
+        best_move.map(|move_found| move_found.into_analysis(me))
     }
 }
 
@@ -109,12 +257,77 @@ impl VirtualPlayer for Minimax {
 pub struct AlphaBeta {
     depth: u8,
     move_count: Cell<u32>,
+    resign_threshold: Option<i32>,
+    wants_to_resign: Cell<bool>,
+    progress: Option<Box<ProgressCallback>>,
 }
 
 impl AlphaBeta {
     /// Creates a new AlphaBeta with, fixing its exploration depth.
     pub fn new(depth: u8) -> Self {
-        Self { depth, move_count: Cell::new(0) }
+        Self {
+            depth,
+            move_count: Cell::new(0),
+            resign_threshold: None,
+            wants_to_resign: Cell::new(false),
+            progress: None,
+        }
+    }
+
+    /// Makes the player resign when the evaluation of its best move is at or
+    /// below -threshold (from its point of view). Using `i32::MAX` as threshold
+    /// makes it resign only when the exploration proves that the game is lost,
+    /// and `i32::MIN` makes it always resign.
+    pub fn with_resign_threshold(mut self, threshold: i32) -> Self {
+        self.resign_threshold = Some(threshold);
+        self
+    }
+
+    /// Reports the progress of the searches to the given callback, ie to
+    /// show the thinking of the player. The depth is then increased one by
+    /// one (iterative deepening), each explored depth being reported.
+    pub fn with_progress<F>(mut self, progress: F) -> Self
+    where
+        F: Fn(&SearchProgress) + 'static,
+    {
+        self.progress = Some(Box::new(progress));
+        self
+    }
+
+    /// Explores the game tree up to the given depth, reporting the best move
+    /// found if the progress is followed.
+    fn search(
+        &self,
+        board: &Board,
+        me: Player,
+        max_depth: u8,
+        nodes_before: u32,
+    ) -> Option<BestMove> {
+        let best_move = self.inner_compute_move(board, me, 1, max_depth, i32::MIN, i32::MAX);
+        if let (Some(progress), Some(best_move)) = (&self.progress, &best_move) {
+            progress(&SearchProgress {
+                depth: max_depth,
+                nodes: self.move_count().wrapping_sub(nodes_before),
+                best: best_move.clone().into_analysis(me),
+            });
+        }
+        best_move
+    }
+
+    /// Rough growth of the search time for each additional depth.
+    #[cfg(feature = "std")]
+    const BRANCHING_ESTIMATE: u32 = 4;
+
+    /// Updates the resignation state for the best move found, and converts it
+    /// to its analysis.
+    fn resign_or_analyse(&self, best_move: Option<BestMove>, me: Player) -> Option<MoveAnalysis> {
+        self.wants_to_resign.set(BestMove::is_hopeless(
+            best_move.as_ref(),
+            me,
+            self.resign_threshold,
+        ));
+
+        best_move.map(|move_found| move_found.into_analysis(me))
     }
 
     /// Alpha-Beta implementation.
@@ -123,9 +336,9 @@ impl AlphaBeta {
         board: &Board,
         current_player: Player,
         depth: u8,
+        max_depth: u8,
         alpha: i32,
         beta: i32
-
     ) -> Option<BestMove> {
         let mut best_move = None;
         let mut current_alpha = alpha;
@@ -138,23 +351,23 @@ impl AlphaBeta {
             // is the move valid ?
             if let Some(board_after_move) = opt_board_after_move {
                 self.move_count.set(self.move_count() + 1);
-                if depth == self.depth {
+                if depth == max_depth {
                     // max depth, just evaluate and returns
                     let evaluation = Evaluator::evaluate(&board_after_move, current_player);
                     best_move = BestMove::best_move_for_player(
                         current_player,
                         best_move,
-                        Some(BestMove { x, y, evaluation }),
+                        Some(BestMove::leaf(x, y, evaluation)),
                     );
                     continue;
                 }
 
                 // determine the next player, and check if the game is blocked.
-                let next_player = if board_after_move.can_player_move(current_player.opponent()) {
+                let next_player = if board_after_move.has_legal_move(current_player.opponent()) {
                     // the player changes.
                     current_player.opponent()
                 } else {
-                    if board_after_move.can_player_move(current_player) {
+                    if board_after_move.has_legal_move(current_player) {
                         // the game is not blocked, but the player does not change.
                         current_player
                     } else {
@@ -163,24 +376,19 @@ impl AlphaBeta {
                         best_move = BestMove::best_move_for_player(
                             current_player,
                             best_move,
-                            Some(BestMove { x, y, evaluation }),
+                            Some(BestMove::leaf(x, y, evaluation)),
                         );
                         continue;
                     }
                 };
 
                 let inner_best_move = self
-                    .inner_compute_move(&board_after_move, next_player, depth + 1, current_alpha, current_beta)
+                    .inner_compute_move(&board_after_move, next_player, depth + 1, max_depth, current_alpha, current_beta)
                     .unwrap();
-                let BestMove {
-                    x: _,
-                    y: _,
-                    evaluation,
-                } = inner_best_move;
                 best_move = BestMove::best_move_for_player(
                     current_player,
                     best_move,
-                    Some(BestMove { x, y, evaluation }),
+                    Some(inner_best_move.preceded_by(x, y)),
                 );
                 let best_eval = best_move.as_ref().unwrap().evaluation;
                 if current_player == Player::Black {
@@ -199,7 +407,17 @@ impl AlphaBeta {
             }
         }
 
-        return best_move;
+        best_move
+    }
+}
+
+impl EngineInfo for AlphaBeta {
+    fn name(&self) -> String {
+        "AlphaBeta".to_string()
+    }
+
+    fn options(&self) -> String {
+        search_options(self.depth, self.resign_threshold)
     }
 }
 
@@ -208,13 +426,50 @@ impl VirtualPlayer for AlphaBeta {
         self.move_count.get()
     }
 
+    fn wants_to_resign(&self) -> bool {
+        self.wants_to_resign.get()
+    }
+
     fn compute_move(&self, board: &Board, me: Player) -> Option<(u8, u8)> {
-        let best_move = self.inner_compute_move(board, me, 1, i32::MIN,i32::MAX);
+        self.compute_move_full(board, me).map(|analysis| analysis.position)
+    }
 
-        match best_move {
-            Some(move_found) => Some((move_found.x, move_found.y)),
-            None => None,
+    fn compute_move_full(&self, board: &Board, me: Player) -> Option<MoveAnalysis> {
+        let nodes_before = self.move_count();
+        let first_depth = if self.progress.is_some() { 1 } else { self.depth };
+        let mut best_move = None;
+        for max_depth in first_depth..=self.depth {
+            best_move = self.search(board, me, max_depth, nodes_before);
         }
+        self.resign_or_analyse(best_move, me)
+    }
+
+    /// Uses iterative deepening : the depth increases until the maximum
+    /// depth is reached, or until the next iteration would probably exceed
+    /// the move budget. Without the `std` feature there is no clock to measure
+    /// the search, and the whole depth is explored.
+    #[cfg(feature = "std")]
+    fn compute_move_timed(&self, board: &Board, me: Player, clock: &Clock) -> Option<(u8, u8)> {
+        let budget = clock.move_budget();
+        let start = Instant::now();
+        let nodes_before = self.move_count();
+        let mut best_move = None;
+        for max_depth in 1..=self.depth {
+            best_move = self.search(board, me, max_depth, nodes_before);
+            if start.elapsed() * Self::BRANCHING_ESTIMATE > budget {
+                break;
+            }
+        }
+
+        self.resign_or_analyse(best_move, me).map(|analysis| analysis.position)
+    }
+}
+
+/// Summary of the options shared by search algorithms.
+fn search_options(depth: u8, resign_threshold: Option<i32>) -> String {
+    match resign_threshold {
+        Some(threshold) => format!("depth={}, resign_threshold={}", depth, threshold),
+        None => format!("depth={}", depth),
     }
 }
 
@@ -250,20 +505,11 @@ impl Evaluator {
             };
         }
 
-        let mut corner = 0;
-        let mut border = 0;
-        let mut other = 0;
-        for (x, y, piece) in board.iter() {
-            if let Some(player) = piece {
-                if Self::corner(x, y) {
-                    corner += Self::sign_for_player(player, Self::SCORE_CORNER);
-                } else if Self::border(x, y) {
-                    border += Self::sign_for_player(player, Self::SCORE_BORDER);
-                } else {
-                    other += Self::sign_for_player(player, Self::SCORE_INSIDE);
-                }
-            }
-        }
+        let count = board.piece_count();
+        let (black, white) = (count.of(Player::Black), count.of(Player::White));
+        let corner = (black.corners as i32 - white.corners as i32) * Self::SCORE_CORNER;
+        let border = (black.edges as i32 - white.edges as i32) * Self::SCORE_BORDER;
+        let other = (black.interior as i32 - white.interior as i32) * Self::SCORE_INSIDE;
 
         let mut evaluation = corner + border + other;
 
@@ -282,25 +528,53 @@ impl Evaluator {
             Player::White => -evaluation,
         }
     }
-
-    fn corner(x: u8, y: u8) -> bool {
-        (x == 0 || x == 7) && (y == 0 || y == 7)
-    }
-
-    fn border(x: u8, y: u8) -> bool {
-        x == 0 || x == 7 || y == 0 || y == 7
-    }
 }
 
 /// BestMove is in internal structure to retuens best move found during
 /// game tree exploration.
+#[derive(Clone)]
 struct BestMove {
     x: u8,
     y: u8,
     evaluation: i32,
+    // the expected line, starting with this move.
+    variation: Vec<(u8, u8)>,
 }
 
 impl BestMove {
+    /// Creates a BestMove ending the exploration.
+    fn leaf(x: u8, y: u8, evaluation: i32) -> Self {
+        Self {
+            x,
+            y,
+            evaluation,
+            variation: vec![(x, y)],
+        }
+    }
+
+    /// Returns the best move found after the given move, as the outcome of
+    /// the given move.
+    fn preceded_by(self, x: u8, y: u8) -> Self {
+        let mut variation = Vec::with_capacity(self.variation.len() + 1);
+        variation.push((x, y));
+        variation.extend(self.variation);
+        Self {
+            x,
+            y,
+            evaluation: self.evaluation,
+            variation,
+        }
+    }
+
+    /// Converts the best move to the public analysis, for the given player.
+    fn into_analysis(self, player: Player) -> MoveAnalysis {
+        MoveAnalysis {
+            position: (self.x, self.y),
+            score: Some(self.normalized_evaluation(player)),
+            variation: self.variation,
+        }
+    }
+
     /// Choose the best move between the two given, for the given player.
     fn best_move_for_player(
         current_player: Player,
@@ -316,18 +590,30 @@ impl BestMove {
 
         let eval_a = move_a.as_ref().unwrap().normalized_evaluation(current_player);
         let eval_b = move_b.as_ref().unwrap().normalized_evaluation(current_player);
-        return if eval_a >= eval_b { move_a } else { move_b };
+        if eval_a >= eval_b { move_a } else { move_b }
     }
 
     /// Returns an evaluation, normalized to be 'greater is better' for the player.
     fn normalized_evaluation(&self, player: Player) -> i32 {
         Evaluator::sign_for_player(player, self.evaluation)
     }
+
+    /// Checks if the best move found is bad enough for the player to resign,
+    /// according to the given resign threshold (if any).
+    fn is_hopeless(best_move: Option<&BestMove>, player: Player, threshold: Option<i32>) -> bool {
+        match (best_move, threshold) {
+            (Some(best_move), Some(threshold)) => {
+                best_move.normalized_evaluation(player) <= threshold.saturating_neg()
+            }
+            _ => false,
+        }
+    }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use std::time::Duration;
 
     #[test]
     fn evaluate_returns_zero_for_equals_forces() {
@@ -391,6 +677,163 @@ mod test {
         assert_eq!(best_move, Some((5, 3)));
     }
 
+    #[test]
+    fn alphabeta_does_not_resign_by_default() {
+        let board = lost_board_for_white();
+        let alphabeta = AlphaBeta::new(2);
+        alphabeta.compute_move(&board, Player::White);
+        assert!(!alphabeta.wants_to_resign());
+    }
+
+    #[test]
+    fn alphabeta_resigns_when_loss_is_proven() {
+        let board = lost_board_for_white();
+        let alphabeta = AlphaBeta::new(2).with_resign_threshold(i32::MAX);
+        alphabeta.compute_move(&board, Player::White);
+        assert!(alphabeta.wants_to_resign());
+    }
+
+    #[test]
+    fn minimax_resigns_when_loss_is_proven() {
+        let board = lost_board_for_white();
+        let minimax = Minimax::new(2).with_resign_threshold(i32::MAX);
+        minimax.compute_move(&board, Player::White);
+        assert!(minimax.wants_to_resign());
+    }
+
+    #[test]
+    fn alphabeta_does_not_resign_when_above_threshold() {
+        let board = Board::new_start();
+        let alphabeta = AlphaBeta::new(2).with_resign_threshold(10);
+        alphabeta.compute_move(&board, Player::White);
+        assert!(!alphabeta.wants_to_resign());
+    }
+
+    /// Builds a board where White has a single possible move, after which
+    /// none of the players can move and Black wins.
+    fn lost_board_for_white() -> Board {
+        let mut board = Board::new();
+        board.set_piece(0, 0, Some(Player::White)).unwrap();
+        board.set_piece(1, 0, Some(Player::Black)).unwrap();
+        board.set_piece(2, 0, Some(Player::Black)).unwrap();
+        for x in 0..=7 {
+            board.set_piece(x, 2, Some(Player::Black)).unwrap();
+        }
+        board
+    }
+
+    #[test]
+    fn alphabeta_returns_its_expected_line() {
+        let board = Board::new_start();
+        let alphabeta = AlphaBeta::new(3);
+        let analysis = alphabeta.compute_move_full(&board, Player::Black).unwrap();
+        assert_eq!(analysis.variation.len(), 3);
+        assert_eq!(analysis.variation[0], analysis.position);
+        assert_eq!(
+            Some(analysis.position),
+            alphabeta.compute_move(&board, Player::Black)
+        );
+
+        // the line is playable, and leads to the announced evaluation.
+        let mut line_board = board;
+        let mut player = Player::Black;
+        for &(x, y) in analysis.variation.iter() {
+            line_board = line_board.play(player, x, y).unwrap().unwrap();
+            player = player.opponent();
+        }
+        let expected = Evaluator::evaluate(&line_board, player.opponent());
+        assert_eq!(analysis.score, Some(expected));
+    }
+
+    #[test]
+    fn alphabeta_reports_each_depth_explored() {
+        use alloc::rc::Rc;
+        use core::cell::RefCell;
+
+        let board = Board::new_start();
+        let reports = Rc::new(RefCell::new(Vec::new()));
+        let reported = Rc::clone(&reports);
+        let alphabeta = AlphaBeta::new(3).with_progress(move |progress| {
+            reported.borrow_mut().push(progress.clone())
+        });
+        let analysis = alphabeta.compute_move_full(&board, Player::Black).unwrap();
+        let reports = reports.borrow();
+        let depths: Vec<u8> = reports.iter().map(|progress| progress.depth).collect();
+        assert_eq!(depths, vec![1, 2, 3]);
+        assert!(reports.windows(2).all(|pair| pair[0].nodes < pair[1].nodes));
+        assert_eq!(reports[2].best, analysis);
+        assert_eq!(
+            Some(analysis),
+            AlphaBeta::new(3).compute_move_full(&board, Player::Black)
+        );
+    }
+
+    #[test]
+    fn minimax_and_alphabeta_agree_on_the_analysis() {
+        let board = Board::new_start()
+            .play(Player::Black, 4, 5)
+            .unwrap()
+            .unwrap();
+        let minimax = Minimax::new(3).compute_move_full(&board, Player::White);
+        let alphabeta = AlphaBeta::new(3).compute_move_full(&board, Player::White);
+        assert_eq!(
+            minimax.map(|analysis| analysis.score),
+            alphabeta.map(|analysis| analysis.score)
+        );
+    }
+
+    #[test]
+    fn analysis_display_shows_the_expected_line() {
+        let analysis = MoveAnalysis {
+            position: (3, 2),
+            score: Some(6),
+            variation: vec![(3, 2), (2, 4), (5, 5)],
+        };
+        assert_eq!(analysis.to_string(), "d3, expects +6 via d3 c5 f6");
+        let analysis = MoveAnalysis {
+            position: (3, 2),
+            score: None,
+            variation: vec![(3, 2)],
+        };
+        assert_eq!(analysis.to_string(), "d3");
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn alphabeta_timed_search_respects_the_budget() {
+        let board = Board::new_start();
+        let alphabeta = AlphaBeta::new(30);
+        let clock = Clock::new(TimeControl::new(
+            Duration::from_millis(200),
+            Duration::from_millis(0),
+        ));
+        let start = Instant::now();
+        let best_move = alphabeta.compute_move_timed(&board, Player::Black, &clock);
+        assert!(best_move.is_some());
+        assert!(start.elapsed() < Duration::from_secs(5));
+    }
+
+    #[test]
+    fn alphabeta_timed_search_stops_at_its_depth() {
+        let board = Board::new_start();
+        let clock = Clock::new(TimeControl::new(
+            Duration::from_secs(3600),
+            Duration::from_secs(0),
+        ));
+        let timed = AlphaBeta::new(3).compute_move_timed(&board, Player::Black, &clock);
+        assert_eq!(timed, AlphaBeta::new(3).compute_move(&board, Player::Black));
+    }
+
+    #[test]
+    fn engine_info_describes_the_search() {
+        let alphabeta = AlphaBeta::new(6);
+        assert_eq!(alphabeta.name(), "AlphaBeta");
+        assert_eq!(alphabeta.description(), "AlphaBeta (depth=6)");
+        assert_eq!(alphabeta.version(), env!("CARGO_PKG_VERSION"));
+        let minimax = Minimax::new(3).with_resign_threshold(100);
+        assert_eq!(minimax.options(), "depth=3, resign_threshold=100");
+    }
+
     /// This test take more time and is only done when the feature flag is activated.
     /// Disabling capture show each 'best' move found, and the move counts per
     /// algorithms.
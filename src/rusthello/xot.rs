@@ -0,0 +1,136 @@
+use std::cell::RefCell;
+
+use super::board::*;
+use super::opening_book::format_line;
+use super::random::*;
+use super::virtual_player::*;
+
+/// Generator of XOT style openings : random lines of a few moves from the
+/// start, kept only if the reached position is balanced according to a
+/// short search. Starting matches from such openings prevents deterministic
+/// engines from playing the same game again and again.
+pub struct XotGenerator {
+    rng: RefCell<Box<dyn RandomGenerator>>,
+    moves: u8,
+    max_imbalance: i32,
+    search_depth: u8,
+}
+
+impl XotGenerator {
+    /// Default count of moves of an opening (as for the XOT openings).
+    pub const DEFAULT_MOVES: u8 = 8;
+    /// Default maximal evaluation (in absolute value) of a balanced opening.
+    pub const DEFAULT_MAX_IMBALANCE: i32 = 6;
+    /// Default depth of the search evaluating openings.
+    pub const DEFAULT_SEARCH_DEPTH: u8 = 4;
+
+    // Count of random lines tried per requested opening before giving up.
+    const ATTEMPTS_PER_OPENING: usize = 100;
+
+    /// Creates a generator using the given random generator.
+    pub fn new(rng: Box<dyn RandomGenerator>) -> Self {
+        Self {
+            rng: RefCell::new(rng),
+            moves: Self::DEFAULT_MOVES,
+            max_imbalance: Self::DEFAULT_MAX_IMBALANCE,
+            search_depth: Self::DEFAULT_SEARCH_DEPTH,
+        }
+    }
+
+    /// Changes the count of moves of the openings.
+    pub fn with_moves(mut self, moves: u8) -> Self {
+        self.moves = moves;
+        self
+    }
+
+    /// Changes the maximal evaluation (in absolute value) of the openings.
+    pub fn with_max_imbalance(mut self, max_imbalance: i32) -> Self {
+        self.max_imbalance = max_imbalance;
+        self
+    }
+
+    /// Changes the depth of the search evaluating openings.
+    pub fn with_search_depth(mut self, search_depth: u8) -> Self {
+        self.search_depth = search_depth;
+        self
+    }
+
+    /// Generates up to `count` distinct balanced openings, in standard
+    /// notation (ie "f5d6c3d3c4f4f6f3"). Less openings are returned if
+    /// the balanced ones are too rare with the current settings.
+    pub fn generate(&self, count: usize) -> Vec<String> {
+        let mut openings: Vec<String> = Vec::new();
+        for _ in 0..count * Self::ATTEMPTS_PER_OPENING {
+            if openings.len() == count {
+                break;
+            }
+            if let Some(line) = self.balanced_line() {
+                let line = format_line(&line);
+                if !openings.contains(&line) {
+                    openings.push(line);
+                }
+            }
+        }
+
+        openings
+    }
+
+    /// Plays a random line, and returns it if the position is balanced.
+    fn balanced_line(&self) -> Option<Vec<(u8, u8)>> {
+        let mut board = Board::new_start();
+        let mut player = Player::Black;
+        let mut line = Vec::new();
+        for _ in 0..self.moves {
+            // openings end before any pass.
+            let valid_moves = board.legal_moves(player);
+            if valid_moves.is_empty() {
+                return None;
+            }
+            let index = self.rng.borrow_mut().next_below(valid_moves.len());
+            let (x, y) = valid_moves[index];
+            board = board
+                .play(player, x, y)
+                .expect("Unexpected error while generating an opening.")
+                .expect("Unexpected invalid move while generating an opening.");
+            line.push((x, y));
+            player = player.opponent();
+        }
+
+        let analysis = AlphaBeta::new(self.search_depth).compute_move_full(&board, player)?;
+        match analysis.score {
+            Some(score) if score.abs() <= self.max_imbalance => Some(line),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::opening_book::parse_line;
+    use super::*;
+
+    fn generator(seed: u64) -> XotGenerator {
+        XotGenerator::new(Box::new(XorShiftRng::new(seed))).with_search_depth(2)
+    }
+
+    #[test]
+    fn generate_returns_distinct_openings_of_the_given_length() {
+        let openings = generator(1).with_moves(6).generate(5);
+        assert_eq!(openings.len(), 5);
+        for (index, opening) in openings.iter().enumerate() {
+            assert_eq!(parse_line(opening).unwrap().len(), 6);
+            assert!(!openings[..index].contains(opening));
+        }
+    }
+
+    #[test]
+    fn generate_is_reproducible_with_the_same_seed() {
+        assert_eq!(generator(7).generate(3), generator(7).generate(3));
+    }
+
+    #[test]
+    fn generate_keeps_balanced_openings_only() {
+        let openings = generator(3).with_max_imbalance(-1).generate(2);
+        assert!(openings.is_empty());
+    }
+}
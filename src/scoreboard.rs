@@ -0,0 +1,94 @@
+use rusthello::{GameResult, Player};
+
+use crate::i18n::{fill, Messages};
+
+/// Running score of the games of a session, from the point of view of the
+/// human.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Scoreboard {
+    pub wins: u32,
+    pub draws: u32,
+    pub losses: u32,
+    /// Sum of the differences of the official pieces counts, positive when
+    /// the human has more pieces. The games ended by a resignation or a
+    /// timeout have no pieces counts, and don't change it.
+    pub disc_difference: i32,
+}
+
+impl Scoreboard {
+    /// Records the result of a game where the human had the given color.
+    pub fn record(&mut self, result: &GameResult, human: Player) {
+        match result.winner() {
+            Some(winner) if winner == human => self.wins += 1,
+            Some(_) => self.losses += 1,
+            None => self.draws += 1,
+        }
+        if let Some((black, white)) = result.score() {
+            let difference = black as i32 - white as i32;
+            self.disc_difference += match human {
+                Player::Black => difference,
+                Player::White => -difference,
+            };
+        }
+    }
+
+    /// Describes the score of the session, the human and its opponent being
+    /// given by their names.
+    pub fn summary(&self, human: &str, opponent: &str, messages: &Messages) -> String {
+        fill(
+            messages.scoreboard,
+            &[
+                &human,
+                &opponent,
+                &self.wins,
+                &self.draws,
+                &self.losses,
+                &format!("{:+}", self.disc_difference),
+            ],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::i18n::Language;
+
+    #[test]
+    fn record_counts_the_results_of_the_human() {
+        let mut scoreboard = Scoreboard::default();
+        scoreboard.record(
+            &GameResult::BoardFull {
+                black: 40,
+                white: 24,
+            },
+            Player::White,
+        );
+        scoreboard.record(
+            &GameResult::Resignation {
+                player: Player::Black,
+            },
+            Player::White,
+        );
+        scoreboard.record(
+            &GameResult::BoardFull {
+                black: 32,
+                white: 32,
+            },
+            Player::Black,
+        );
+        assert_eq!(
+            scoreboard,
+            Scoreboard {
+                wins: 1,
+                draws: 1,
+                losses: 1,
+                disc_difference: -16,
+            }
+        );
+        assert_eq!(
+            scoreboard.summary("Alice", "AlphaBeta", Language::English.messages()),
+            "Session score, Alice against AlphaBeta : 1 won, 1 drawn, 1 lost, disc difference -16"
+        );
+    }
+}
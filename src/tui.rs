@@ -0,0 +1,486 @@
+use std::{cell::RefCell, io, process, rc::Rc, time::Duration};
+
+use ratatui::{
+    crossterm::{
+        event::{
+            self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEventKind,
+            MouseButton, MouseEventKind,
+        },
+        execute,
+    },
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, Paragraph},
+    DefaultTerminal, Frame,
+};
+use rusthello::{
+    Board, BoardTheme, Game, GameSession, HumanPlayer, Move, Player, SessionEvent, VirtualPlayer,
+};
+
+use crate::config::{Colors, Config};
+
+/// The interface and the state of the game it draws, shared by the session
+/// listener and the input of the human.
+struct Ui<const N: usize = 8> {
+    terminal: DefaultTerminal,
+    game: Game<N>,
+    human: Player,
+    config: Config,
+    cursor: (u8, u8),
+    /// Square under the mouse, highlighted when it's a legal move.
+    hover: Option<(u8, u8)>,
+    /// Area of the board in the last drawing, to find the clicked squares.
+    board_area: Rect,
+    message: String,
+}
+
+/// Input of the human : a key press, or the mouse moved over (or clicked
+/// on) a square of the board, None when it's outside of the board.
+enum Input {
+    Key(KeyCode),
+    Hover(Option<(u8, u8)>),
+    Click(Option<(u8, u8)>),
+}
+
+/// Plays a game in a full-screen terminal interface between the human, with
+/// the given color, and the given computer. The board is drawn as a grid.
+/// The human moves a cursor with the arrow keys and plays with Enter, or
+/// clicks on the square of its move, the legal squares being highlighted
+/// under the mouse. The side panels give the score, the clocks and the
+/// history of the game. The colors of the board and the names of the players
+/// are configured.
+pub fn run(human: Player, computer: Box<dyn VirtualPlayer>, config: &Config) {
+    let game = Game::new().with_statistics(true);
+    let ui = Rc::new(RefCell::new(Ui {
+        terminal: init_terminal(),
+        game: game.fork(),
+        human,
+        config: config.clone(),
+        cursor: (3, 2),
+        hover: None,
+        board_area: Rect::default(),
+        message: String::new(),
+    }));
+
+    let input_ui = Rc::clone(&ui);
+    let human_player = Box::new(HumanPlayer::new(move |board, player| {
+        input_ui.borrow_mut().read_move(board, player)
+    })) as Box<dyn VirtualPlayer>;
+    let (black, white) = match human {
+        Player::Black => (human_player, computer),
+        Player::White => (computer, human_player),
+    };
+
+    let mut session = GameSession::new(game, black, white);
+    let listener_ui = Rc::clone(&ui);
+    session.add_listener(move |game, event| {
+        let mut ui = listener_ui.borrow_mut();
+        ui.game = game.fork();
+        ui.message = match event {
+            SessionEvent::Thinking { player } if *player == human => "Your turn.".to_string(),
+            SessionEvent::Thinking { .. } => "Computer is thinking...".to_string(),
+            SessionEvent::Played { player, analysis } if *player != human => {
+                let (x, y) = analysis.position;
+                format!("Computer played {}.", Move::Place { x, y })
+            }
+            SessionEvent::Passed { player } => format!("{} passes.", player),
+            SessionEvent::Resigned { player } => format!("{} resigns.", player),
+            SessionEvent::TimeForfeit { player } => format!("{} lost on time.", player),
+            SessionEvent::GameOver { result } => format!("{}. Press any key.", result),
+            _ => ui.message.clone(),
+        };
+        ui.draw();
+    });
+    session.run();
+
+    let mut ui = ui.borrow_mut();
+    ui.draw();
+    wait_key();
+    restore_terminal();
+}
+
+impl<const N: usize> Ui<N> {
+    /// Lets the human choose its move with the cursor or the mouse, None if
+    /// it resigns.
+    fn read_move(&mut self, board: &Board<N>, player: Player) -> Option<(u8, u8)> {
+        loop {
+            self.draw();
+            let code = match wait_input(self.board_area, board.size()) {
+                Input::Key(code) => code,
+                Input::Hover(square) => {
+                    self.hover = square;
+                    continue;
+                }
+                Input::Click(Some(square)) => {
+                    self.cursor = square;
+                    KeyCode::Enter
+                }
+                Input::Click(None) => continue,
+            };
+            let (x, y) = self.cursor;
+            let last = board.size() - 1;
+            match code {
+                KeyCode::Left => self.cursor.0 = x.saturating_sub(1),
+                KeyCode::Right => self.cursor.0 = (x + 1).min(last),
+                KeyCode::Up => self.cursor.1 = y.saturating_sub(1),
+                KeyCode::Down => self.cursor.1 = (y + 1).min(last),
+                KeyCode::Enter | KeyCode::Char(' ') => match board.check_move(player, x, y) {
+                    Ok(()) => {
+                        self.hover = None;
+                        return Some((x, y));
+                    }
+                    Err(error) => self.message = format!("Invalid move, {}.", error),
+                },
+                KeyCode::Char('r') | KeyCode::Char('R') => return None,
+                KeyCode::Char('q') | KeyCode::Char('Q') | KeyCode::Esc => {
+                    restore_terminal();
+                    process::exit(0);
+                }
+                _ => (),
+            }
+        }
+    }
+
+    fn draw(&mut self) {
+        let Ui {
+            terminal,
+            game,
+            human,
+            config,
+            cursor,
+            hover,
+            board_area,
+            message,
+        } = self;
+        terminal
+            .draw(|frame| {
+                *board_area = render(frame, game, *human, config, *cursor, *hover, message)
+            })
+            .expect("Unable to draw the terminal.");
+    }
+}
+
+/// Starts the full-screen interface, with the mouse events.
+fn init_terminal() -> DefaultTerminal {
+    let terminal = ratatui::init();
+    execute!(io::stdout(), EnableMouseCapture).expect("Unable to capture the mouse.");
+    terminal
+}
+
+/// Restores the terminal as it was before the full-screen interface.
+fn restore_terminal() {
+    // the terminal is restored even if the mouse can't be released.
+    let _ = execute!(io::stdout(), DisableMouseCapture);
+    ratatui::restore();
+}
+
+/// Waits for a key press, and returns its code.
+fn wait_key() -> KeyCode {
+    loop {
+        if let Input::Key(code) = wait_input(Rect::default(), 0) {
+            return code;
+        }
+    }
+}
+
+/// Waits for a key press, a move of the mouse or a left click, the squares
+/// being found in the given area of the board of the given size.
+fn wait_input(board_area: Rect, size: u8) -> Input {
+    loop {
+        match event::read().expect("Unable to read the terminal events.") {
+            Event::Key(key) if key.kind == KeyEventKind::Press => return Input::Key(key.code),
+            Event::Mouse(mouse) => {
+                let square = square_at(board_area, size, mouse.column, mouse.row);
+                match mouse.kind {
+                    MouseEventKind::Moved => return Input::Hover(square),
+                    MouseEventKind::Down(MouseButton::Left) => return Input::Click(square),
+                    _ => (),
+                }
+            }
+            _ => (),
+        }
+    }
+}
+
+/// Returns the square of the board of the given size drawn in the given
+/// area at the given position of the terminal, None outside of the squares.
+/// Inside the border, the first line holds the letters of the columns and
+/// each line starts with its number, the squares being 3 characters wide.
+fn square_at(board_area: Rect, size: u8, column: u16, row: u16) -> Option<(u8, u8)> {
+    let x = column.checked_sub(board_area.x + 1 + row_label_width(size))? / 3;
+    let y = row.checked_sub(board_area.y + 2)?;
+    if x < size as u16 && y < size as u16 {
+        Some((x as u8, y as u8))
+    } else {
+        None
+    }
+}
+
+/// Draws the whole interface for the given game, returning the area of the
+/// board.
+fn render<const N: usize>(
+    frame: &mut Frame,
+    game: &Game<N>,
+    human: Player,
+    config: &Config,
+    cursor: (u8, u8),
+    hover: Option<(u8, u8)>,
+    message: &str,
+) -> Rect {
+    // the board is drawn inside a border, with the letters of the columns
+    // above it and the numbers of the rows on its left.
+    let size = game.board().size() as u16;
+    let [main, status] =
+        Layout::vertical([Constraint::Min(size + 4), Constraint::Length(3)]).areas(frame.area());
+    let board_width = row_label_width(size as u8) + 3 * size + 4;
+    let [board_area, side] =
+        Layout::horizontal([Constraint::Length(board_width), Constraint::Min(24)]).areas(main);
+    let [score_area, clocks_area, history_area] = Layout::vertical([
+        Constraint::Length(5),
+        Constraint::Length(4),
+        Constraint::Min(3),
+    ])
+    .areas(side);
+
+    let show_cursor = game.player() == Some(human);
+    frame.render_widget(
+        board_widget(
+            game,
+            config,
+            show_cursor.then_some(cursor),
+            hover.filter(|_| show_cursor),
+        ),
+        board_area,
+    );
+    frame.render_widget(score_widget(game, human, config), score_area);
+    frame.render_widget(clocks_widget(game), clocks_area);
+    frame.render_widget(history_widget(game, history_area), history_area);
+    let help = "Arrows : move the cursor, Enter or click : play, R : resign, Q : quit";
+    frame.render_widget(
+        Paragraph::new(vec![
+            Line::from(message.to_string()),
+            Line::from(help).dim(),
+        ])
+        .block(Block::bordered()),
+        status,
+    );
+    board_area
+}
+
+/// Draws the board, the legal moves of the current player being marked, and
+/// highlighted under the mouse. With the color-blind theme, the sides are
+/// told apart by the shape and the brightness of the discs on a neutral
+/// board, the cursor being reversed and the hovered move underlined.
+fn board_widget<const N: usize>(
+    game: &Game<N>,
+    config: &Config,
+    cursor: Option<(u8, u8)>,
+    hover: Option<(u8, u8)>,
+) -> Paragraph<'static> {
+    let colorblind = config.theme == BoardTheme::COLORBLIND;
+    let background = match config.colors {
+        _ if colorblind => Color::Gray,
+        Colors::Green => Color::Green,
+        Colors::Blue => Color::Blue,
+        Colors::Gray => Color::DarkGray,
+    };
+    let (black_disc, white_disc, hint) = if colorblind {
+        let theme = &config.theme;
+        (theme.black, theme.white, theme.hint)
+    } else {
+        ('●', '●', '·')
+    };
+    let board = game.board();
+    let size = board.size();
+    let number_width = row_label_width(size) as usize - 2;
+    let legal_moves = game.legal_moves();
+    let mut letters = format!(" {:width$} ", "", width = number_width);
+    for x in 0..size {
+        letters.push_str(&format!(" {} ", (b'A' + x) as char));
+    }
+    let mut lines = vec![Line::from(letters)];
+    for y in 0..size {
+        let mut spans = vec![Span::raw(format!(
+            " {:>width$} ",
+            y + 1,
+            width = number_width
+        ))];
+        for x in 0..size {
+            let (cell, color) = match board.get_piece(x, y).ok().flatten() {
+                Some(Player::Black) => (black_disc, Color::Black),
+                Some(Player::White) => (white_disc, Color::White),
+                None if legal_moves.contains(&(x, y)) => (hint, Color::DarkGray),
+                None => (' ', Color::Gray),
+            };
+            let mut style = Style::new().fg(color).bg(background);
+            if colorblind && color == Color::White {
+                style = style.bold();
+            }
+            if hover == Some((x, y)) && legal_moves.contains(&(x, y)) {
+                style = if colorblind {
+                    style.underlined()
+                } else {
+                    style.bg(Color::LightGreen)
+                };
+            }
+            if cursor == Some((x, y)) {
+                style = if colorblind {
+                    style.reversed()
+                } else {
+                    style.bg(Color::Yellow)
+                };
+            }
+            spans.push(Span::styled(format!(" {} ", cell), style));
+        }
+        lines.push(Line::from(spans));
+    }
+    Paragraph::new(lines).block(Block::bordered().title(" Board "))
+}
+
+/// Returns the width of the numbers of the rows of a board of the given
+/// size, with the spaces around them.
+fn row_label_width(size: u8) -> u16 {
+    size.to_string().len() as u16 + 2
+}
+
+fn score_widget<const N: usize>(
+    game: &Game<N>,
+    human: Player,
+    config: &Config,
+) -> Paragraph<'static> {
+    let (black, white) = game.count_pieces();
+    let who = |player: Player| {
+        let name = match player {
+            Player::Black => config.black_name.as_deref(),
+            Player::White => config.white_name.as_deref(),
+        };
+        match name {
+            Some(name) => name,
+            None if player == human => "you",
+            None => "computer",
+        }
+    };
+    let turn = match game.player() {
+        Some(player) => format!("Turn of {}, move {}", player, game.move_number()),
+        None => "The game is over".to_string(),
+    };
+    Paragraph::new(vec![
+        Line::from(format!("Black ({}) : {}", who(Player::Black), black)),
+        Line::from(format!("White ({}) : {}", who(Player::White), white)),
+        Line::from(turn).bold(),
+    ])
+    .block(Block::bordered().title(" Score "))
+}
+
+/// Gives the remaining time of timed games, the time spent otherwise.
+fn clocks_widget<const N: usize>(game: &Game<N>) -> Paragraph<'static> {
+    let time = |player: Player| match game.clock(player) {
+        Some(clock) => format!("{} left", format_duration(clock.remaining())),
+        None => {
+            let spent = game
+                .statistics()
+                .map_or(Duration::ZERO, |statistics| statistics.time_spent(player));
+            format!("{} spent", format_duration(spent))
+        }
+    };
+    Paragraph::new(vec![
+        Line::from(format!("Black : {}", time(Player::Black))),
+        Line::from(format!("White : {}", time(Player::White))),
+    ])
+    .block(Block::bordered().title(" Clocks "))
+}
+
+/// Lists the last moves and passes fitting in the given area.
+fn history_widget<const N: usize>(game: &Game<N>, area: Rect) -> Paragraph<'static> {
+    let visible = area.height.saturating_sub(2) as usize;
+    let history = game.history();
+    let lines: Vec<Line> = history[history.len().saturating_sub(visible)..]
+        .iter()
+        .map(|entry| {
+            Line::from(format!(
+                "{:>3}. {} {}",
+                entry.turn, entry.player, entry.played
+            ))
+        })
+        .collect();
+    Paragraph::new(lines).block(Block::bordered().title(" History "))
+}
+
+fn format_duration(duration: Duration) -> String {
+    let seconds = duration.as_secs();
+    format!("{}:{:02}", seconds / 60, seconds % 60)
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::{backend::TestBackend, Terminal};
+
+    use super::*;
+
+    /// Renders the interface for the given game, returning the lines drawn
+    /// and the area of the board.
+    fn render_lines<const N: usize>(game: &Game<N>, width: u16) -> (Vec<String>, Rect) {
+        let mut terminal = Terminal::new(TestBackend::new(width, 24)).unwrap();
+        let mut board_area = Rect::default();
+        terminal
+            .draw(|frame| {
+                board_area = render(
+                    frame,
+                    game,
+                    Player::Black,
+                    &Config::default(),
+                    (0, 0),
+                    None,
+                    "",
+                )
+            })
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        let lines = (0..buffer.area.height)
+            .map(|y| {
+                (0..buffer.area.width)
+                    .map(|x| buffer[(x, y)].symbol())
+                    .collect()
+            })
+            .collect();
+        (lines, board_area)
+    }
+
+    #[test]
+    fn square_at_finds_the_clicked_squares() {
+        let area = Rect::new(0, 0, 31, 12);
+        assert_eq!(square_at(area, 8, 4, 2), Some((0, 0)));
+        assert_eq!(square_at(area, 8, 6, 2), Some((0, 0)));
+        assert_eq!(square_at(area, 8, 7, 3), Some((1, 1)));
+        assert_eq!(square_at(area, 8, 27, 9), Some((7, 7)));
+        assert_eq!(square_at(area, 8, 3, 2), None);
+        assert_eq!(square_at(area, 8, 28, 2), None);
+        assert_eq!(square_at(area, 8, 4, 1), None);
+        assert_eq!(square_at(area, 8, 4, 10), None);
+        assert_eq!(square_at(Rect::new(10, 5, 31, 12), 8, 14, 7), Some((0, 0)));
+        let area = Rect::new(0, 0, 37, 14);
+        assert_eq!(square_at(area, 10, 5, 2), Some((0, 0)));
+        assert_eq!(square_at(area, 10, 34, 11), Some((9, 9)));
+        assert_eq!(square_at(area, 10, 35, 11), None);
+    }
+
+    #[test]
+    fn render_draws_boards_of_other_sizes() {
+        let mut game = Game::<6>::default();
+        game.play_notation("E4").unwrap();
+        let (lines, board_area) = render_lines(&game, 60);
+        // the six columns and rows, inside the border.
+        assert_eq!(board_area.width, 3 + 3 * 6 + 4);
+        assert!(lines[1].contains(" A  B  C  D  E  F "));
+        assert!(!lines[1].contains(" G "));
+        assert!(lines[7].starts_with("│ 6 "));
+        assert!(!lines[8].contains(" 7 "));
+        // the pieces after E4, the score and the history.
+        assert_eq!(lines[5].matches('●').count(), 3);
+        assert!(lines.iter().any(|line| line.contains("Black (you) : 4")));
+        assert!(lines.iter().any(|line| line.contains("1. Black E4")));
+        assert_eq!(square_at(board_area, 6, 4, 2), Some((0, 0)));
+        assert_eq!(square_at(board_area, 6, 19, 7), Some((5, 5)));
+    }
+}